@@ -11,7 +11,7 @@ use crate::{
   primitive::Primitive,
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
-    create_id::create_id,
+    use_id::{create_id, create_part_id},
     Attributes,
   },
   Direction, Orientation,
@@ -415,6 +415,7 @@ struct AccordionItemContextValue {
 
 #[component]
 pub fn AccordionItem(
+  #[prop(optional, into)] id: MaybeProp<String>,
   #[prop(optional, into)] disabled: MaybeSignal<bool>,
   #[prop(into)] value: MaybeSignal<String>,
 
@@ -429,7 +430,8 @@ pub fn AccordionItem(
   let value_context = use_context::<AccordionContextValue>()
     .expect("AccordionItem must be in an AccordionRoot component");
 
-  let trigger_id = create_id();
+  let base_id = create_id(id.clone());
+  let trigger_id = create_part_id(base_id, "trigger");
   let is_open_value = value.clone();
   let is_open = Signal::derive(move || {
     value_context
@@ -463,6 +465,7 @@ pub fn AccordionItem(
   let open_value = value.clone();
   view! {
     <CollapsibleRoot
+      id=id
       open=is_open
       disabled=is_disabled
       on_open_change=Callback::new(move |open| {
@@ -0,0 +1,241 @@
+use leptos::{html::AnyElement, *};
+use web_sys::{Event, KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::{
+  dialog::{
+    DialogClose, DialogContent, DialogDescription, DialogOverlay, DialogPortal, DialogPortalProps,
+    DialogRoot, DialogTitle, DialogTrigger,
+  },
+  portal::PortalContainer,
+  util::Attributes,
+};
+
+#[derive(Clone)]
+struct AlertDialogContentContextValue {
+  cancel_ref: NodeRef<AnyElement>,
+}
+
+#[component]
+pub fn AlertDialogRoot(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  view! {
+    <DialogRoot
+      id=id
+      open=open
+      default_open=default_open
+      modal=true
+      on_open_change=on_open_change
+    >
+      {children()}
+    </DialogRoot>
+  }
+}
+
+#[component]
+pub fn AlertDialogTrigger(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <DialogTrigger
+      on_click=on_click
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogTrigger>
+  }
+}
+
+#[component]
+pub fn AlertDialogPortal(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  DialogPortal(DialogPortalProps {
+    force_mount,
+    container,
+    children,
+  })
+}
+
+#[component]
+pub fn AlertDialogOverlay(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let children = StoredValue::new(children);
+
+  view! {
+    <DialogOverlay
+      force_mount=force_mount
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </DialogOverlay>
+  }
+}
+
+#[component]
+pub fn AlertDialogContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let cancel_ref = NodeRef::<AnyElement>::new();
+
+  provide_context(AlertDialogContentContextValue { cancel_ref });
+
+  let mut merged_attrs = vec![("role", "alertdialog".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <DialogContent
+      force_mount=force_mount
+      on_open_auto_focus=Callback::new(move |ev: Event| {
+        on_open_auto_focus.call(ev.clone());
+
+        if ev.default_prevented() {
+          return;
+        }
+
+        let Some(cancel) = cancel_ref.get_untracked() else {
+          return;
+        };
+
+        if cancel.focus().is_ok() && document().active_element().as_deref() == Some(&cancel) {
+          ev.prevent_default();
+        }
+      })
+      on_close_auto_focus=on_close_auto_focus
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=Callback::new(|ev: PointerEvent| ev.prevent_default())
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogContent>
+  }
+}
+
+#[component]
+pub fn AlertDialogTitle(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <DialogTitle
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogTitle>
+  }
+}
+
+#[component]
+pub fn AlertDialogDescription(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <DialogDescription
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogDescription>
+  }
+}
+
+#[component]
+pub fn AlertDialogAction(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <DialogClose
+      on_click=on_click
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogClose>
+  }
+}
+
+#[component]
+pub fn AlertDialogCancel(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let AlertDialogContentContextValue { cancel_ref } =
+    use_context().expect("AlertDialogCancel must be used in an AlertDialogContent component");
+
+  Effect::new(move |_| {
+    if let Some(node) = cancel_ref.get() {
+      node_ref.load(&node);
+    }
+  });
+
+  view! {
+    <DialogClose
+      on_click=on_click
+      node_ref=cancel_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </DialogClose>
+  }
+}
@@ -11,21 +11,37 @@ use web_sys::{
 };
 
 use crate::{
+  form::{use_form_control_attrs, use_form_field_name},
   presence::create_presence,
   primitive::Primitive,
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
     create_previous::create_previous,
+    form_data::{FormDataError, FromFormData},
     Attributes,
   },
 };
 
+const INDETERMINATE_VALUE: &str = "indeterminate";
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum CheckedState {
   Checked(bool),
   Indeterminate,
 }
 
+impl FromFormData for CheckedState {
+  fn from_form_values(_: &str, values: &[String]) -> Result<Self, FormDataError> {
+    Ok(if values.is_empty() {
+      CheckedState::Checked(false)
+    } else if values.iter().any(|value| value == INDETERMINATE_VALUE) {
+      CheckedState::Indeterminate
+    } else {
+      CheckedState::Checked(true)
+    })
+  }
+}
+
 #[derive(Clone)]
 struct CheckboxValueContext {
   state: Signal<CheckedState>,
@@ -34,6 +50,8 @@ struct CheckboxValueContext {
 
 #[component]
 pub fn CheckboxRoot(
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] value: MaybeProp<String>,
   #[prop(optional, into)] required: MaybeSignal<bool>,
   #[prop(optional, into)] disabled: MaybeSignal<bool>,
   #[prop(optional, into)] checked: MaybeProp<CheckedState>,
@@ -150,8 +168,13 @@ pub fn CheckboxRoot(
     ),
   ];
 
+  merged_attrs.extend(use_form_control_attrs());
   merged_attrs.extend(attrs);
 
+  let field_name = use_form_field_name();
+  let name = Signal::derive(move || name.get().or_else(|| field_name.map(|name| name.get())));
+  let value = Signal::derive(move || value.get());
+
   let bubble_ref = NodeRef::<Input>::new();
 
   Effect::new(move |_| {
@@ -197,6 +220,10 @@ pub fn CheckboxRoot(
       <BubbleInput
           checked=Signal::derive(move || checked.get().unwrap_or(CheckedState::Checked(false)))
           bubbles=Signal::derive(move || false)
+          name=name
+          value=value
+          disabled=Signal::derive(move || disabled.get())
+          required=Signal::derive(move || required.get())
           control=node_ref
           node_ref=bubble_ref
       />
@@ -266,6 +293,10 @@ fn BubbleInput(
   checked: Signal<CheckedState>,
   control: NodeRef<AnyElement>,
   bubbles: Signal<bool>,
+  name: Signal<Option<String>>,
+  value: Signal<Option<String>>,
+  disabled: Signal<bool>,
+  required: Signal<bool>,
 
   node_ref: NodeRef<Input>,
   #[prop(attrs)] attrs: Attributes,
@@ -326,6 +357,10 @@ fn BubbleInput(
       checked=(move || match checked.get() { CheckedState::Checked(checked) => checked, CheckedState::Indeterminate => false }).into_attribute()
       tabindex=(-1).into_attribute()
       node_ref=node_ref
+      name=name.into_attribute()
+      value=Signal::derive(move || value.get().unwrap_or("on".into())).into_attribute()
+      disabled=disabled.into_attribute()
+      required=required.into_attribute()
       style:position="absolute"
       style:pointer-events="none"
       style:opacity="0"
@@ -333,5 +368,12 @@ fn BubbleInput(
       style:width=move || format!("{}px", width.get())
       style:height=move || format!("{}px", height.get())
     />
+    <Show when=move || checked.get() == CheckedState::Indeterminate && !disabled.get()>
+      <input
+        type="hidden"
+        name=name.into_attribute()
+        value=INDETERMINATE_VALUE
+      />
+    </Show>
   }
 }
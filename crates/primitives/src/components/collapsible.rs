@@ -6,7 +6,7 @@ use crate::{
   primitive::Primitive,
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
-    create_id::create_id,
+    use_id::{create_id, create_part_id},
     Attributes,
   },
 };
@@ -23,6 +23,7 @@ struct CollapsibleContextValue {
 
 #[component]
 pub fn CollapsibleRoot(
+  #[prop(optional, into)] id: MaybeProp<String>,
   #[prop(optional, into)] open: MaybeSignal<bool>,
   #[prop(optional, into)] default_open: MaybeSignal<bool>,
   #[prop(optional, into)] disabled: MaybeSignal<bool>,
@@ -44,16 +45,19 @@ pub fn CollapsibleRoot(
     }),
   });
 
+  let base_id = create_id(id.clone());
+
   provide_context(CollapsibleContextValue {
     open: Signal::derive(move || open.get().unwrap_or(false)),
     disabled: Signal::derive(move || disabled.get()),
-    content_id: create_id(),
+    content_id: create_part_id(base_id, "content"),
     on_open_toggle: Callback::new(move |_| {
       set_open.update(|open| *open = Some(!open.unwrap_or(false)))
     }),
   });
 
   let mut merged_attrs = vec![
+    ("id", id.into_attribute()),
     (
       "data-state",
       (move || {
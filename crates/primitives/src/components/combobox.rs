@@ -0,0 +1,1074 @@
+use std::collections::HashMap;
+
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsCast;
+use web_sys::{
+  Event, FocusEvent, HtmlElement, HtmlInputElement, KeyboardEvent, MouseEvent, Node, PointerEvent,
+};
+
+use crate::{
+  dismissable_layer::DismissableLayer,
+  popper::{
+    Align, PopperAnchor, PopperArrow, PopperArrowProps, PopperContent, PopperRoot, Side, Sticky,
+  },
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    form_data::get_form_field_name,
+    use_id::{create_id, create_part_id},
+    Attributes,
+  },
+};
+
+pub enum ComboboxKind {
+  Single {
+    value: MaybeProp<String>,
+    default_value: MaybeProp<String>,
+    on_value_change: Option<Callback<String>>,
+  },
+  Multiple {
+    value: MaybeProp<Vec<String>>,
+    default_value: MaybeProp<Vec<String>>,
+    on_value_change: Option<Callback<Vec<String>>>,
+  },
+}
+
+pub struct ComboboxSingle;
+pub struct ComboboxMultiple;
+
+impl ComboboxSingle {
+  pub fn none() -> Option<String> {
+    None
+  }
+}
+
+impl ComboboxMultiple {
+  pub fn none() -> Option<Vec<String>> {
+    None
+  }
+}
+
+#[derive(Clone)]
+struct ComboboxItemEntry {
+  text: String,
+  group_id: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+struct ComboboxContextValue {
+  input_ref: NodeRef<AnyElement>,
+  trigger_ref: NodeRef<AnyElement>,
+  content_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  open: Signal<bool>,
+  on_open_change: Callback<bool>,
+  input_value: Signal<String>,
+  on_input_value_change: Callback<String>,
+  search: RwSignal<String>,
+  value: Signal<Vec<String>>,
+  on_item_select: Callback<(String, String)>,
+  multiple: bool,
+  highlighted_id: RwSignal<Option<String>>,
+  filter: Callback<(String, String), bool>,
+  should_filter: Signal<bool>,
+  loading: Signal<bool>,
+  disabled: Signal<bool>,
+  items: RwSignal<HashMap<String, ComboboxItemEntry>>,
+}
+
+impl ComboboxContextValue {
+  fn matches(&self, text: &str) -> bool {
+    let search = self.search.get();
+
+    !self.should_filter.get() || search.is_empty() || self.filter.call((text.to_string(), search))
+  }
+
+  fn visible_item_count(&self, group_id: Option<&str>) -> usize {
+    self.items.with(|items| {
+      items
+        .values()
+        .filter(|item| group_id.is_none() || item.group_id.as_deref() == group_id)
+        .filter(|item| self.matches(&item.text))
+        .count()
+    })
+  }
+}
+
+#[component]
+pub fn ComboboxRoot(
+  kind: ComboboxKind,
+
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] input_value: MaybeProp<String>,
+  #[prop(optional, into)] default_input_value: MaybeProp<String>,
+  #[prop(optional, into)] filter: Option<Callback<(String, String), bool>>,
+  #[prop(default=true.into(), into)] should_filter: MaybeSignal<bool>,
+  #[prop(optional, into)] allow_custom_value: MaybeSignal<bool>,
+  #[prop(optional, into)] loading: MaybeSignal<bool>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] name: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+  #[prop(default=(|_|{}).into(), into)] on_input_value_change: Callback<String>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let (input_value, set_input_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || input_value.get()),
+    default_value: Signal::derive(move || default_input_value.get()),
+    on_change: on_input_value_change,
+  });
+
+  let (value, set_value, multiple) = match kind {
+    ComboboxKind::Single {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      (
+        Signal::derive(move || value.get().into_iter().collect::<Vec<_>>()),
+        Callback::new(move |values: Vec<String>| {
+          set_value.set(values.into_iter().next().unwrap_or_default());
+        }),
+        false,
+      )
+    }
+    ComboboxKind::Multiple {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      (
+        Signal::derive(move || value.get().unwrap_or_default()),
+        Callback::new(move |values: Vec<String>| {
+          set_value.set(values);
+        }),
+        true,
+      )
+    }
+  };
+
+  let id = create_id(id);
+  let search = RwSignal::new(String::new());
+  let item_texts = StoredValue::new(HashMap::<String, String>::new());
+  let input_value = Signal::derive(move || input_value.get().unwrap_or_default());
+  let on_input_value_change = Callback::new(move |value: String| {
+    set_input_value.set(value);
+  });
+
+  let on_open_change = Callback::new(move |is_open: bool| {
+    set_open.set(is_open);
+
+    if is_open {
+      return;
+    }
+
+    search.set(String::new());
+
+    if multiple {
+      on_input_value_change.call(String::new());
+    } else if !allow_custom_value.get_untracked() {
+      let selected_text = value.with_untracked(|value| {
+        value.first().map(|value| {
+          item_texts
+            .with_value(|texts| texts.get(value).cloned())
+            .unwrap_or_else(|| value.clone())
+        })
+      });
+
+      on_input_value_change.call(selected_text.unwrap_or_default());
+    }
+  });
+
+  let items = RwSignal::new(HashMap::<String, ComboboxItemEntry>::new());
+
+  provide_context(ComboboxContextValue {
+    input_ref: NodeRef::new(),
+    trigger_ref: NodeRef::new(),
+    content_ref: NodeRef::new(),
+    content_id: create_part_id(id, "content"),
+    open: Signal::derive(move || open.get().unwrap_or(false)),
+    on_open_change,
+    input_value,
+    on_input_value_change,
+    search,
+    value,
+    on_item_select: Callback::new(move |(item_value, text): (String, String)| {
+      item_texts.update_value(|texts| {
+        texts.insert(item_value.clone(), text.clone());
+      });
+
+      if multiple {
+        let mut values = value.get_untracked();
+
+        if let Some(index) = values.iter().position(|value| *value == item_value) {
+          values.remove(index);
+        } else {
+          values.push(item_value);
+        }
+
+        set_value.call(values);
+        search.set(String::new());
+        on_input_value_change.call(String::new());
+      } else {
+        set_value.call(vec![item_value]);
+        search.set(String::new());
+        on_input_value_change.call(text);
+        set_open.set(false);
+      }
+    }),
+    multiple,
+    highlighted_id: RwSignal::new(None),
+    filter: filter.unwrap_or(Callback::new(|(text, search): (String, String)| {
+      default_filter(&text, &search)
+    })),
+    should_filter: Signal::derive(move || should_filter.get()),
+    loading: Signal::derive(move || loading.get()),
+    disabled: Signal::derive(move || disabled.get()),
+    items,
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+
+    {move || {
+      name.get().map(|name| {
+        let name = get_form_field_name(&name, multiple);
+
+        value
+          .get()
+          .into_iter()
+          .map(|value| view! {
+            <input
+              type="hidden"
+              name=name.clone()
+              value=value
+              disabled=disabled.get()
+            />
+          })
+          .collect_view()
+      })
+    }}
+  }
+}
+
+#[component]
+pub fn ComboboxInput(
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<ComboboxContextValue>()
+    .expect("ComboboxInput must be used in a ComboboxRoot component");
+  let ComboboxContextValue {
+    input_ref,
+    content_ref,
+    content_id,
+    open,
+    on_open_change,
+    input_value,
+    on_input_value_change,
+    search,
+    highlighted_id,
+    disabled,
+    ..
+  } = context;
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      input_ref.load(&node);
+    }
+  });
+
+  Effect::new(move |_| {
+    let value = input_value.get();
+
+    if let Some(input) = node_ref
+      .get()
+      .and_then(|node| node.dyn_ref::<HtmlInputElement>().cloned())
+    {
+      if input.value() != value {
+        input.set_value(&value);
+      }
+    }
+  });
+
+  let highlight = move |direction: HighlightDirection| {
+    let Some(content) = content_ref.get_untracked() else {
+      return;
+    };
+
+    let options = get_enabled_options(&content);
+    let current = highlighted_id
+      .get_untracked()
+      .and_then(|id| options.iter().position(|option| option.id() == id));
+
+    let next = match (direction, current) {
+      (HighlightDirection::First, _) | (HighlightDirection::Next, None) => options.first(),
+      (HighlightDirection::Last, _) | (HighlightDirection::Previous, None) => options.last(),
+      (HighlightDirection::Next, Some(index)) => options.get(index + 1).or(options.last()),
+      (HighlightDirection::Previous, Some(index)) => {
+        options.get(index.saturating_sub(1)).or(options.first())
+      }
+    };
+
+    if let Some(next) = next {
+      highlighted_id.set(Some(next.id()));
+      scroll_into_view(&content, next);
+    }
+  };
+
+  let mut merged_attrs = vec![
+    ("type", "text".into_attribute()),
+    ("role", "combobox".into_attribute()),
+    ("autocomplete", "off".into_attribute()),
+    ("aria-autocomplete", "list".into_attribute()),
+    ("aria-expanded", (move || open.get()).into_attribute()),
+    ("aria-controls", (move || content_id.get()).into_attribute()),
+    (
+      "aria-activedescendant",
+      (move || open.get().then(|| highlighted_id.get()).flatten()).into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || get_state(open.get())).into_attribute(),
+    ),
+    ("disabled", (move || disabled.get()).into_attribute()),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <PopperAnchor as_child=true>
+      <Primitive
+        element=html::input
+        on:input=move |ev: Event| {
+          let value = event_target_value(&ev);
+
+          search.set(value.clone());
+          on_input_value_change.call(value);
+
+          if !open.get_untracked() {
+            on_open_change.call(true);
+          }
+        }
+        on:keydown=move |ev: KeyboardEvent| {
+          on_key_down.call(ev.clone());
+
+          if ev.default_prevented() || disabled.get_untracked() {
+            return;
+          }
+
+          match ev.key().as_str() {
+            "ArrowDown" | "ArrowUp" => {
+              ev.prevent_default();
+
+              let direction = match (ev.key().as_str(), ev.alt_key()) {
+                (_, true) => None,
+                ("ArrowDown", _) => Some(HighlightDirection::Next),
+                _ => Some(HighlightDirection::Previous),
+              };
+
+              if !open.get_untracked() {
+                on_open_change.call(true);
+              } else if let Some(direction) = direction {
+                highlight(direction);
+              }
+            }
+            "Home" | "End" if open.get_untracked() && ev.ctrl_key() => {
+              ev.prevent_default();
+
+              highlight(if ev.key() == "Home" {
+                HighlightDirection::First
+              } else {
+                HighlightDirection::Last
+              });
+            }
+            "Enter" => {
+              if !open.get_untracked() {
+                return;
+              }
+
+              let highlighted = highlighted_id.get_untracked().and_then(|id| {
+                document()
+                  .get_element_by_id(&id)
+                  .and_then(|el| el.dyn_into::<HtmlElement>().ok())
+              });
+
+              if let Some(highlighted) = highlighted {
+                ev.prevent_default();
+                highlighted.click();
+              }
+            }
+            "Escape" if !open.get_untracked() => {
+              ev.prevent_default();
+
+              search.set(String::new());
+              on_input_value_change.call(String::new());
+            }
+            _ => {}
+          }
+        }
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </Primitive>
+    </PopperAnchor>
+  }
+}
+
+#[component]
+pub fn ComboboxTrigger(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue {
+    input_ref,
+    trigger_ref,
+    content_id,
+    open,
+    on_open_change,
+    disabled,
+    ..
+  } = use_context().expect("ComboboxTrigger must be used in a ComboboxRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let mut merged_attrs = vec![
+    ("type", "button".into_attribute()),
+    ("tabindex", (-1).into_attribute()),
+    ("aria-label", "Show suggestions".into_attribute()),
+    ("aria-controls", (move || content_id.get()).into_attribute()),
+    ("aria-expanded", (move || open.get()).into_attribute()),
+    (
+      "data-state",
+      (move || get_state(open.get())).into_attribute(),
+    ),
+    ("disabled", (move || disabled.get()).into_attribute()),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::button
+      on:mousedown=move |ev: MouseEvent| {
+        ev.prevent_default();
+      }
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev.clone());
+
+        if ev.default_prevented() || disabled.get_untracked() {
+          return;
+        }
+
+        on_open_change.call(!open.get_untracked());
+
+        if let Some(input) = input_ref.get_untracked() {
+          _ = input.focus();
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ComboboxContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(default=Side::Bottom.into(), into)] side: MaybeSignal<Side>,
+  #[prop(default=4.0f64.into(), into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<ComboboxContextValue>()
+    .expect("ComboboxContent must be used in a ComboboxRoot component");
+  let ComboboxContextValue {
+    input_ref,
+    trigger_ref,
+    content_ref,
+    content_id,
+    open,
+    on_open_change,
+    search,
+    value,
+    multiple,
+    highlighted_id,
+    ..
+  } = context;
+
+  let is_present = Signal::derive(move || force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+  let layer_ref = NodeRef::<AnyElement>::new();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      content_ref.load(&node);
+    }
+  });
+
+  Effect::new(move |_| {
+    if !open.get() {
+      highlighted_id.set(None);
+      return;
+    }
+
+    search.track();
+    value.track();
+
+    request_animation_frame(move || {
+      let Some(content) = content_ref.get_untracked() else {
+        return;
+      };
+
+      let options = get_enabled_options(&content);
+      let selected_value = value.with_untracked(|value| value.first().cloned());
+
+      let next = options
+        .iter()
+        .find(|option| {
+          search.with_untracked(|search| search.is_empty())
+            && selected_value.is_some()
+            && option.get_attribute("data-value") == selected_value
+        })
+        .or(options.first());
+
+      if let Some(next) = next {
+        _ = highlighted_id.try_set(Some(next.id()));
+        scroll_into_view(&content, next);
+      } else {
+        _ = highlighted_id.try_set(None);
+      }
+    });
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-combobox-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-combobox-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-combobox-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-combobox-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-combobox-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let is_anchor_or_trigger = move |target: Option<web_sys::EventTarget>| {
+    let target = target.and_then(|target| target.dyn_into::<Node>().ok());
+
+    [input_ref, trigger_ref].iter().any(|node_ref| {
+      node_ref
+        .get_untracked()
+        .is_some_and(|node| node.contains(target.as_ref()))
+    })
+  };
+
+  let mut merged_attrs = vec![
+    ("role", "listbox".into_attribute()),
+    ("id", (move || content_id.get()).into_attribute()),
+    (
+      "aria-multiselectable",
+      multiple.then_some("true").into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || get_state(open.get())).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let collision_boundary = StoredValue::new(collision_boundary);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <DismissableLayer
+        as_child=true
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=Callback::new(move |ev: PointerEvent| {
+          on_pointer_down_outside.call(ev.clone());
+
+          if is_anchor_or_trigger(ev.target()) {
+            ev.prevent_default();
+          }
+        })
+        on_focus_outside=Callback::new(move |ev: FocusEvent| {
+          on_focus_outside.call(ev.clone());
+
+          if is_anchor_or_trigger(ev.target()) {
+            ev.prevent_default();
+          }
+        })
+        on_dismiss=Callback::new(move |_| on_open_change.call(false))
+        node_ref=layer_ref
+      >
+        <PopperContent
+          side=side
+          side_offset=side_offset
+          align=align
+          align_offset=align_offset
+          arrow_padding=arrow_padding
+          avoid_collisions=avoid_collisions
+          collision_boundary=collision_boundary.get_value()
+          collision_padding=collision_padding
+          sticky=sticky
+          hide_when_detached=hide_when_detached
+          node_ref=node_ref
+          attrs=merged_attrs.get_value()
+          as_child=as_child
+        >
+          {children.with_value(|children| children())}
+        </PopperContent>
+      </DismissableLayer>
+    </Show>
+  }
+}
+
+#[derive(Clone)]
+struct ComboboxGroupContextValue {
+  id: Signal<String>,
+  label_id: Signal<String>,
+}
+
+#[component]
+pub fn ComboboxItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] id: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<ComboboxContextValue>()
+    .expect("ComboboxItem must be used in a ComboboxRoot component");
+  let ComboboxContextValue {
+    value: selected_value,
+    on_item_select,
+    highlighted_id,
+    items,
+    disabled: root_disabled,
+    ..
+  } = context;
+  let group_id = use_context::<ComboboxGroupContextValue>().map(|group| group.id);
+
+  let value = Signal::derive(move || value.get());
+  let text = Signal::derive(move || text_value.get().unwrap_or_else(|| value.get()));
+  let id = create_id(id);
+  let disabled = Signal::derive(move || disabled.get() || root_disabled.get());
+
+  Effect::new(move |_| {
+    let id = id.get();
+    let entry = ComboboxItemEntry {
+      text: text.get(),
+      group_id: group_id.map(|group_id| group_id.get()),
+    };
+
+    items.update(|items| {
+      items.insert(id, entry);
+    });
+  });
+
+  on_cleanup(move || {
+    if let Some(id) = id.try_get_untracked() {
+      _ = items.try_update(|items| {
+        items.remove(&id);
+      });
+    }
+  });
+
+  let is_visible = Signal::derive(move || text.with(|text| context.matches(text)));
+  let is_selected =
+    Signal::derive(move || selected_value.with(|values| values.contains(&value.get())));
+  let is_highlighted = Signal::derive(move || highlighted_id.get() == Some(id.get()));
+
+  let mut merged_attrs = vec![
+    ("role", "option".into_attribute()),
+    ("id", (move || id.get()).into_attribute()),
+    ("data-value", (move || value.get()).into_attribute()),
+    (
+      "aria-selected",
+      (move || is_selected.get()).into_attribute(),
+    ),
+    (
+      "aria-disabled",
+      (move || disabled.get().then_some("true")).into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || {
+        if is_selected.get() {
+          "checked"
+        } else {
+          "unchecked"
+        }
+      })
+      .into_attribute(),
+    ),
+    (
+      "data-highlighted",
+      (move || is_highlighted.get().then_some("")).into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+    ("hidden", (move || !is_visible.get()).into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      on:mousedown=move |ev: MouseEvent| {
+        ev.prevent_default();
+      }
+      on:pointermove=move |_: PointerEvent| {
+        if !disabled.get_untracked() && !is_highlighted.get_untracked() {
+          highlighted_id.set(Some(id.get_untracked()));
+        }
+      }
+      on:click=move |_: MouseEvent| {
+        if !disabled.get_untracked() {
+          on_item_select.call((value.get_untracked(), text.get_untracked()));
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ComboboxEmpty(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<ComboboxContextValue>()
+    .expect("ComboboxEmpty must be used in a ComboboxRoot component");
+
+  let is_empty =
+    Signal::derive(move || !context.loading.get() && context.visible_item_count(None) == 0);
+
+  let mut merged_attrs = vec![("role", "presentation".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || is_empty.get()>
+      <Primitive
+        element=html::div
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn ComboboxLoading(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ComboboxContextValue { loading, .. } =
+    use_context().expect("ComboboxLoading must be used in a ComboboxRoot component");
+
+  let mut merged_attrs = vec![
+    ("role", "status".into_attribute()),
+    ("aria-live", "polite".into_attribute()),
+  ];
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || loading.get()>
+      <Primitive
+        element=html::div
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn ComboboxGroup(
+  #[prop(optional, into)] id: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<ComboboxContextValue>()
+    .expect("ComboboxGroup must be used in a ComboboxRoot component");
+
+  let group_id = create_id(id.clone());
+  let label_id = create_part_id(group_id, "label");
+
+  provide_context(ComboboxGroupContextValue {
+    id: group_id,
+    label_id,
+  });
+
+  let is_visible = Signal::derive(move || context.visible_item_count(Some(&group_id.get())) > 0);
+
+  let mut merged_attrs = vec![
+    ("id", id.into_attribute()),
+    ("role", "group".into_attribute()),
+    ("aria-labelledby", (move || label_id.get()).into_attribute()),
+    ("hidden", (move || !is_visible.get()).into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ComboboxLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let label_id = use_context::<ComboboxGroupContextValue>().map(|group| group.label_id);
+
+  let mut merged_attrs = vec![(
+    "id",
+    (move || label_id.map(|label_id| label_id.get())).into_attribute(),
+  )];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ComboboxSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let mut merged_attrs = vec![("aria-hidden", "true".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ComboboxArrow(
+  #[prop(default=10.0f64.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0f64.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+  PopperArrow(PopperArrowProps {
+    width,
+    height,
+    attrs,
+    children,
+  })
+}
+
+#[derive(Clone, Copy)]
+enum HighlightDirection {
+  First,
+  Last,
+  Next,
+  Previous,
+}
+
+fn get_state(open: bool) -> &'static str {
+  if open {
+    "open"
+  } else {
+    "closed"
+  }
+}
+
+fn default_filter(text: &str, search: &str) -> bool {
+  text.to_lowercase().contains(&search.trim().to_lowercase())
+}
+
+fn get_enabled_options(content: &web_sys::Element) -> Vec<HtmlElement> {
+  let Ok(nodes) =
+    content.query_selector_all("[role=option]:not([hidden]):not([aria-disabled=true])")
+  else {
+    return vec![];
+  };
+
+  (0..nodes.length())
+    .filter_map(|index| nodes.item(index)?.dyn_into::<HtmlElement>().ok())
+    .filter(|option| option.closest("[hidden]").ok().flatten().is_none())
+    .collect()
+}
+
+fn scroll_into_view(container: &web_sys::Element, option: &HtmlElement) {
+  let container_rect = container.get_bounding_client_rect();
+  let option_rect = option.get_bounding_client_rect();
+
+  if option_rect.top() < container_rect.top() {
+    container.set_scroll_top(
+      container.scroll_top() - (container_rect.top() - option_rect.top()).ceil() as i32,
+    );
+  } else if option_rect.bottom() > container_rect.bottom() {
+    container.set_scroll_top(
+      container.scroll_top() + (option_rect.bottom() - container_rect.bottom()).ceil() as i32,
+    );
+  }
+}
+
+#[test]
+fn default_filter_is_case_insensitive_substring_match() {
+  assert!(default_filter("Apple", "app"));
+  assert!(default_filter("Pineapple", " APPLE "));
+  assert!(!default_filter("Banana", "apple"));
+  assert!(default_filter("Banana", ""));
+}
@@ -0,0 +1,905 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsCast;
+use web_sys::{
+  Element, Event, FocusEvent, HtmlElement, HtmlInputElement, KeyboardEvent, MouseEvent, Node,
+  PointerEvent, ScrollIntoViewOptions, ScrollLogicalPosition,
+};
+
+use crate::{
+  dialog::{DialogContent, DialogOverlay, DialogPortal, DialogRoot},
+  primitive::Primitive,
+  util::{
+    command_score::command_score,
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    use_id::{create_id, create_part_id},
+    Attributes,
+  },
+};
+
+const ITEM_SELECTOR: &str = "[data-command-item]";
+const GROUP_SELECTOR: &str = "[data-command-group]";
+const GROUP_ITEMS_SELECTOR: &str = "[data-command-group-items]";
+const LIST_SIZER_SELECTOR: &str = "[data-command-list-sizer]";
+const VALID_ITEM_SELECTOR: &str = "[data-command-item]:not([aria-disabled=true])";
+
+pub type CommandFilter = Callback<(String, String, Vec<String>), f64>;
+
+#[derive(Clone)]
+struct CommandItemEntry {
+  value: String,
+  keywords: Vec<String>,
+  group_id: Option<String>,
+  order: usize,
+}
+
+#[derive(Clone, Copy)]
+struct CommandContextValue {
+  list_ref: NodeRef<AnyElement>,
+  label: Signal<Option<String>>,
+  label_id: Signal<String>,
+  input_id: Signal<String>,
+  list_id: Signal<String>,
+  search: Signal<String>,
+  on_search_change: Callback<String>,
+  value: Signal<Option<String>>,
+  on_value_change: Callback<String>,
+  items: RwSignal<HashMap<String, CommandItemEntry>>,
+  groups: RwSignal<HashMap<String, usize>>,
+  scores: Memo<HashMap<String, f64>>,
+  next_order: StoredValue<usize>,
+}
+
+impl CommandContextValue {
+  fn register_order(&self) -> usize {
+    let order = self.next_order.get_value();
+    self.next_order.set_value(order + 1);
+
+    order
+  }
+
+  fn is_item_visible(&self, id: &str) -> bool {
+    self
+      .scores
+      .with(|scores| scores.get(id).is_some_and(|score| *score > 0.0))
+  }
+
+  fn visible_item_count(&self, group_id: Option<&str>) -> usize {
+    self.items.with(|items| {
+      items
+        .iter()
+        .filter(|(_, item)| group_id.is_none() || item.group_id.as_deref() == group_id)
+        .filter(|(id, _)| self.is_item_visible(id))
+        .count()
+    })
+  }
+
+  fn active_descendant(&self) -> Option<String> {
+    let value = self.value.get()?;
+
+    self.items.with(|items| {
+      items
+        .iter()
+        .filter(|(id, _)| self.is_item_visible(id))
+        .find(|(_, item)| item.value == value)
+        .map(|(id, _)| id.clone())
+    })
+  }
+}
+
+#[component]
+pub fn CommandRoot(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] label: MaybeProp<String>,
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+  #[prop(optional, into)] search: MaybeProp<String>,
+  #[prop(optional, into)] default_search: MaybeProp<String>,
+  #[prop(optional, into)] filter: Option<CommandFilter>,
+  #[prop(default=true.into(), into)] should_filter: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+  #[prop(default=(|_|{}).into(), into)] on_search_change: Callback<String>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let (search, set_search) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || search.get()),
+    default_value: Signal::derive(move || default_search.get()),
+    on_change: on_search_change,
+  });
+
+  let base_id = create_id(id);
+  let search = Signal::derive(move || search.get().unwrap_or_default());
+  let should_filter = Signal::derive(move || should_filter.get());
+  let filter = filter.unwrap_or_else(default_filter);
+
+  let items = RwSignal::new(HashMap::<String, CommandItemEntry>::new());
+  let groups = RwSignal::new(HashMap::<String, usize>::new());
+
+  let scores = Memo::new(move |_| {
+    let search = search.get();
+    let should_filter = should_filter.get();
+
+    items.with(|items| {
+      items
+        .iter()
+        .map(|(id, item)| {
+          let score = if !should_filter || search.is_empty() {
+            1.0
+          } else {
+            filter.call((item.value.clone(), search.clone(), item.keywords.clone()))
+          };
+
+          (id.clone(), score)
+        })
+        .collect::<HashMap<_, _>>()
+    })
+  });
+
+  let context = CommandContextValue {
+    list_ref: NodeRef::new(),
+    label: Signal::derive(move || label.get()),
+    label_id: create_part_id(base_id, "label"),
+    input_id: create_part_id(base_id, "input"),
+    list_id: create_part_id(base_id, "list"),
+    search,
+    on_search_change: Callback::new(move |search: String| {
+      set_search.set(search);
+    }),
+    value: Signal::derive(move || value.get()),
+    on_value_change: Callback::new(move |value: String| {
+      set_value.set(value);
+    }),
+    items,
+    groups,
+    scores,
+    next_order: StoredValue::new(0),
+  };
+
+  provide_context(context);
+
+  let list_ref = context.list_ref;
+  let is_sorted = StoredValue::new(false);
+  let previous_search = StoredValue::new(None::<String>);
+
+  let select_item = move |item: &Element| {
+    if let Some(value) = item.get_attribute("data-value") {
+      context.on_value_change.call(value);
+    }
+
+    let mut options = ScrollIntoViewOptions::new();
+    options.block(ScrollLogicalPosition::Nearest);
+
+    item.scroll_into_view_with_scroll_into_view_options(&options);
+  };
+
+  Effect::new(move |_| {
+    scores.track();
+    _ = list_ref.get();
+
+    request_animation_frame(move || {
+      let Some(list) = list_ref.get_untracked() else {
+        return;
+      };
+
+      let search = search.get_untracked();
+
+      if should_filter.get_untracked() && (!search.is_empty() || is_sorted.get_value()) {
+        sort_list(&list, &context);
+        is_sorted.set_value(!search.is_empty());
+      }
+
+      let search_changed = previous_search.get_value().as_ref() != Some(&search);
+      previous_search.set_value(Some(search));
+
+      let items = get_valid_items(&list);
+      let has_valid_value = value.get_untracked().is_some_and(|value| {
+        items
+          .iter()
+          .any(|item| item.get_attribute("data-value").as_ref() == Some(&value))
+      });
+
+      if search_changed || !has_valid_value {
+        if let Some(first) = items.first() {
+          select_item(first);
+        }
+      }
+    });
+  });
+
+  let move_selection = move |direction: SelectionDirection| {
+    let Some(list) = list_ref.get_untracked() else {
+      return;
+    };
+
+    let items = get_valid_items(&list);
+    let current = value.get_untracked().and_then(|value| {
+      items
+        .iter()
+        .position(|item| item.get_attribute("data-value").as_ref() == Some(&value))
+    });
+
+    let next = match (direction, current) {
+      (SelectionDirection::First, _) | (SelectionDirection::Next, None) => items.first(),
+      (SelectionDirection::Last, _) | (SelectionDirection::Previous, None) => items.last(),
+      (SelectionDirection::Next, Some(index)) => items
+        .get(index + 1)
+        .or_else(|| should_loop.get_untracked().then(|| items.first()).flatten()),
+      (SelectionDirection::Previous, Some(index)) => index
+        .checked_sub(1)
+        .and_then(|index| items.get(index))
+        .or_else(|| should_loop.get_untracked().then(|| items.last()).flatten()),
+    };
+
+    if let Some(next) = next {
+      select_item(next);
+    }
+  };
+
+  let mut merged_attrs = vec![("data-command-root", "".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      on:keydown=move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        if ev.default_prevented() || ev.is_composing() {
+          return;
+        }
+
+        let direction = match ev.key().as_str() {
+          "ArrowDown" => Some(SelectionDirection::Next),
+          "ArrowUp" => Some(SelectionDirection::Previous),
+          "Home" => Some(SelectionDirection::First),
+          "End" => Some(SelectionDirection::Last),
+          "Enter" => {
+            let highlighted = context.active_descendant().and_then(|id| {
+              document()
+                .get_element_by_id(&id)
+                .and_then(|item| item.dyn_into::<HtmlElement>().ok())
+            });
+
+            if let Some(highlighted) = highlighted {
+              ev.prevent_default();
+              highlighted.click();
+            }
+
+            None
+          }
+          _ => None,
+        };
+
+        if let Some(direction) = direction {
+          ev.prevent_default();
+          move_selection(direction);
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      <label
+        id=move || context.label_id.get()
+        for=move || context.input_id.get()
+        style="position: absolute; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; word-wrap: normal;"
+      >
+        {move || context.label.get()}
+      </label>
+
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandInput(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<CommandContextValue>()
+    .expect("CommandInput must be used in a CommandRoot component");
+  let CommandContextValue {
+    input_id,
+    label_id,
+    list_id,
+    search,
+    on_search_change,
+    ..
+  } = context;
+
+  Effect::new(move |_| {
+    let search = search.get();
+
+    if let Some(input) = node_ref
+      .get()
+      .and_then(|node| node.dyn_ref::<HtmlInputElement>().cloned())
+    {
+      if input.value() != search {
+        input.set_value(&search);
+      }
+    }
+  });
+
+  let mut merged_attrs = vec![
+    ("id", (move || input_id.get()).into_attribute()),
+    ("type", "text".into_attribute()),
+    ("role", "combobox".into_attribute()),
+    ("autocomplete", "off".into_attribute()),
+    ("autocorrect", "off".into_attribute()),
+    ("spellcheck", "false".into_attribute()),
+    ("aria-autocomplete", "list".into_attribute()),
+    ("aria-expanded", "true".into_attribute()),
+    ("aria-controls", (move || list_id.get()).into_attribute()),
+    ("aria-labelledby", (move || label_id.get()).into_attribute()),
+    (
+      "aria-activedescendant",
+      (move || context.active_descendant()).into_attribute(),
+    ),
+    ("data-command-input", "".into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::input
+      on:input=move |ev: Event| {
+        on_search_change.call(event_target_value(&ev));
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandList(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CommandContextValue {
+    list_ref,
+    label,
+    list_id,
+    ..
+  } = use_context().expect("CommandList must be used in a CommandRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      list_ref.load(&node);
+    }
+  });
+
+  let mut merged_attrs = vec![
+    ("id", (move || list_id.get()).into_attribute()),
+    ("role", "listbox".into_attribute()),
+    ("tabindex", (-1).into_attribute()),
+    ("aria-label", (move || label.get()).into_attribute()),
+    ("data-command-list", "".into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      <div data-command-list-sizer="">
+        {children()}
+      </div>
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandEmpty(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<CommandContextValue>()
+    .expect("CommandEmpty must be used in a CommandRoot component");
+
+  let mut merged_attrs = vec![
+    ("role", "presentation".into_attribute()),
+    ("data-command-empty", "".into_attribute()),
+  ];
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || context.visible_item_count(None) == 0>
+      <Primitive
+        element=html::div
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn CommandLoading(
+  #[prop(optional, into)] progress: MaybeProp<f64>,
+  #[prop(default="Loading...".to_string().into(), into)] label: MaybeSignal<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let mut merged_attrs = vec![
+    ("role", "progressbar".into_attribute()),
+    ("aria-valuemin", 0.into_attribute()),
+    ("aria-valuemax", 100.into_attribute()),
+    ("aria-valuenow", (move || progress.get()).into_attribute()),
+    ("aria-label", (move || label.get()).into_attribute()),
+    ("data-command-loading", "".into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      <div aria-hidden="true">
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </div>
+    </Primitive>
+  }
+}
+
+#[derive(Clone, Copy)]
+struct CommandGroupContextValue {
+  id: Signal<String>,
+}
+
+#[component]
+pub fn CommandGroup(
+  #[prop(optional, into)] heading: MaybeProp<String>,
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] id: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<CommandContextValue>()
+    .expect("CommandGroup must be used in a CommandRoot component");
+
+  let heading = Signal::derive(move || heading.get());
+  let group_id = create_id(id);
+  let heading_id = create_part_id(group_id, "heading");
+  let order = context.register_order();
+
+  Effect::new(move |_| {
+    let id = group_id.get();
+
+    context.groups.update(|groups| {
+      groups.insert(id, order);
+    });
+  });
+
+  on_cleanup(move || {
+    if let Some(id) = group_id.try_get_untracked() {
+      _ = context.groups.try_update(|groups| {
+        groups.remove(&id);
+      });
+    }
+  });
+
+  provide_context(CommandGroupContextValue { id: group_id });
+
+  let is_visible = Signal::derive(move || {
+    force_mount.get() || context.visible_item_count(Some(&group_id.get())) > 0
+  });
+
+  let mut merged_attrs = vec![
+    ("id", (move || group_id.get()).into_attribute()),
+    ("role", "presentation".into_attribute()),
+    ("data-value", (move || value.get()).into_attribute()),
+    ("data-command-group", "".into_attribute()),
+    ("hidden", (move || !is_visible.get()).into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {move || heading.get().map(|heading| view! {
+        <div id=move || heading_id.get() aria-hidden="true" data-command-group-heading="">
+          {heading}
+        </div>
+      })}
+
+      <div
+        role="group"
+        aria-labelledby=move || heading.get().map(|_| heading_id.get())
+        data-command-group-items=""
+      >
+        {children()}
+      </div>
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] keywords: MaybeProp<Vec<String>>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] id: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<CommandContextValue>()
+    .expect("CommandItem must be used in a CommandRoot component");
+  let group_id = use_context::<CommandGroupContextValue>().map(|group| group.id);
+
+  let item_id = create_id(id);
+  let order = context.register_order();
+  let value = Signal::derive(move || value.get().trim().to_string());
+
+  Effect::new(move |_| {
+    let id = item_id.get();
+    let entry = CommandItemEntry {
+      value: value.get(),
+      keywords: keywords.get().unwrap_or_default(),
+      group_id: group_id.map(|group_id| group_id.get()),
+      order,
+    };
+
+    context.items.update(|items| {
+      items.insert(id, entry);
+    });
+  });
+
+  on_cleanup(move || {
+    if let Some(id) = item_id.try_get_untracked() {
+      _ = context.items.try_update(|items| {
+        items.remove(&id);
+      });
+    }
+  });
+
+  let is_visible =
+    Signal::derive(move || force_mount.get() || context.is_item_visible(&item_id.get()));
+  let is_selected = Signal::derive(move || context.value.get() == Some(value.get()));
+
+  let mut merged_attrs = vec![
+    ("id", (move || item_id.get()).into_attribute()),
+    ("role", "option".into_attribute()),
+    ("data-value", (move || value.get()).into_attribute()),
+    (
+      "aria-selected",
+      (move || is_selected.get()).into_attribute(),
+    ),
+    (
+      "aria-disabled",
+      (move || disabled.get().then_some("true")).into_attribute(),
+    ),
+    (
+      "data-selected",
+      (move || is_selected.get().then_some("")).into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+    ("data-command-item", "".into_attribute()),
+    ("hidden", (move || !is_visible.get()).into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      on:pointermove=move |_: PointerEvent| {
+        if !disabled.get_untracked() && !is_selected.get_untracked() {
+          context.on_value_change.call(value.get_untracked());
+        }
+      }
+      on:click=move |_: MouseEvent| {
+        if !disabled.get_untracked() {
+          on_select.call(value.get_untracked());
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandSeparator(
+  #[prop(optional, into)] always_render: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let CommandContextValue { search, .. } =
+    use_context().expect("CommandSeparator must be used in a CommandRoot component");
+
+  let mut merged_attrs = vec![
+    ("role", "separator".into_attribute()),
+    ("data-command-separator", "".into_attribute()),
+    (
+      "hidden",
+      (move || !always_render.get() && !search.with(|search| search.is_empty())).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn CommandDialog(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] label: MaybeProp<String>,
+  #[prop(optional, into)] filter: Option<CommandFilter>,
+  #[prop(default=true.into(), into)] should_filter: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+) -> impl IntoView {
+  let label = Signal::derive(move || label.get());
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+  let id = StoredValue::new(id);
+
+  view! {
+    <DialogRoot open=open default_open=default_open on_open_change=on_open_change>
+      <DialogPortal>
+        <DialogOverlay attrs=vec![("data-command-overlay", "".into_attribute())] />
+
+        <DialogContent
+          on_escape_key_down=on_escape_key_down
+          on_pointer_down_outside=on_pointer_down_outside
+          on_focus_outside=on_focus_outside
+          attrs=vec![
+            ("data-command-dialog", "".into_attribute()),
+            ("aria-label", (move || label.get()).into_attribute()),
+          ]
+        >
+          <CommandRoot
+            id=id.get_value()
+            label=label
+            filter=filter.unwrap_or_else(default_filter)
+            should_filter=should_filter
+            should_loop=should_loop
+            attrs=attrs.get_value()
+          >
+            {children.with_value(|children| children())}
+          </CommandRoot>
+        </DialogContent>
+      </DialogPortal>
+    </DialogRoot>
+  }
+}
+
+fn default_filter() -> CommandFilter {
+  Callback::new(|(value, search, keywords): (String, String, Vec<String>)| {
+    command_score(&value, &search, &keywords)
+  })
+}
+
+#[derive(Clone, Copy)]
+enum SelectionDirection {
+  First,
+  Last,
+  Next,
+  Previous,
+}
+
+fn get_valid_items(list: &Element) -> Vec<Element> {
+  let Ok(nodes) = list.query_selector_all(VALID_ITEM_SELECTOR) else {
+    return vec![];
+  };
+
+  (0..nodes.length())
+    .filter_map(|index| nodes.item(index)?.dyn_into::<Element>().ok())
+    .filter(|item| item.closest("[hidden]").ok().flatten().is_none())
+    .collect()
+}
+
+fn sort_list(list: &Element, context: &CommandContextValue) {
+  let scores = context.scores.get_untracked();
+  let items = context.items.get_untracked();
+  let groups = context.groups.get_untracked();
+
+  let item_key = |id: &str| {
+    let score = scores.get(id).copied().unwrap_or(0.0);
+    let order = items.get(id).map(|item| item.order).unwrap_or(usize::MAX);
+
+    (score, order)
+  };
+
+  let group_key = |id: &str| {
+    let score = items
+      .iter()
+      .filter(|(_, item)| item.group_id.as_deref() == Some(id))
+      .map(|(item_id, _)| scores.get(item_id).copied().unwrap_or(0.0))
+      .fold(0.0, f64::max);
+    let order = groups.get(id).copied().unwrap_or(usize::MAX);
+
+    (score, order)
+  };
+
+  let key = |element: &Element| {
+    let target = if element.matches(ITEM_SELECTOR).unwrap_or(false)
+      || element.matches(GROUP_SELECTOR).unwrap_or(false)
+    {
+      Some(element.clone())
+    } else {
+      element
+        .query_selector(&format!("{GROUP_SELECTOR}, {ITEM_SELECTOR}"))
+        .ok()
+        .flatten()
+    }?;
+
+    Some(if target.has_attribute("data-command-group") {
+      group_key(&target.id())
+    } else {
+      item_key(&target.id())
+    })
+  };
+
+  let containers = list
+    .query_selector(LIST_SIZER_SELECTOR)
+    .ok()
+    .flatten()
+    .into_iter()
+    .chain(
+      list
+        .query_selector_all(GROUP_ITEMS_SELECTOR)
+        .ok()
+        .map(|nodes| {
+          (0..nodes.length())
+            .filter_map(|index| nodes.item(index)?.dyn_into::<Element>().ok())
+            .collect::<Vec<_>>()
+        })
+        .unwrap_or_default(),
+    );
+
+  for container in containers {
+    reorder_children(&container, key);
+  }
+}
+
+fn reorder_children(container: &Element, key: impl Fn(&Element) -> Option<(f64, usize)>) {
+  let nodes = container.child_nodes();
+  let nodes = (0..nodes.length())
+    .filter_map(|index| nodes.item(index))
+    .collect::<Vec<_>>();
+
+  let sortable = nodes
+    .iter()
+    .filter_map(|node| {
+      let element = node.dyn_ref::<Element>()?;
+      key(element).map(|key| (node.clone(), key))
+    })
+    .collect::<Vec<_>>();
+
+  let mut sorted = sortable.clone();
+  sorted.sort_by(|(_, (a_score, a_order)), (_, (b_score, b_order))| {
+    b_score
+      .partial_cmp(a_score)
+      .unwrap_or(Ordering::Equal)
+      .then(a_order.cmp(b_order))
+  });
+
+  if sorted
+    .iter()
+    .zip(sortable.iter())
+    .all(|((a, _), (b, _))| a.is_same_node(Some(b)))
+  {
+    return;
+  }
+
+  let mut sorted = sorted.into_iter().map(|(node, _)| node);
+  let ordered = nodes
+    .iter()
+    .map(|node| {
+      if sortable
+        .iter()
+        .any(|(sortable, _)| sortable.is_same_node(Some(node)))
+      {
+        sorted.next().unwrap_or_else(|| node.clone())
+      } else {
+        node.clone()
+      }
+    })
+    .collect::<Vec<Node>>();
+
+  for node in ordered {
+    _ = container.append_child(&node);
+  }
+}
@@ -0,0 +1,665 @@
+use std::time::Duration;
+
+use leptos::{html::AnyElement, leptos_dom::helpers::TimeoutHandle, *};
+use web_sys::{Event, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::{
+  checkbox::CheckedState,
+  menu::{
+    Menu, MenuAnchor, MenuArrow, MenuArrowProps, MenuCheckboxItem, MenuContent, MenuGroup,
+    MenuItem, MenuItemIndicator, MenuLabel, MenuPortal, MenuPortalProps, MenuRadioGroup,
+    MenuRadioItem, MenuSeparator, MenuSub, MenuSubContent, MenuSubTrigger,
+  },
+  popper::{Align, Rect, Side, Sticky},
+  portal::PortalContainer,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    Attributes,
+  },
+  Direction,
+};
+
+#[derive(Clone)]
+struct ContextMenuContextValue {
+  open: Signal<bool>,
+  on_open_change: Callback<bool>,
+}
+
+#[component]
+pub fn ContextMenuRoot(
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(default=true.into(), into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_signal(false);
+  let modal = Signal::derive(move || modal.get());
+
+  let handle_open_change = Callback::new(move |open| {
+    set_open.set(open);
+    on_open_change.call(open);
+  });
+
+  provide_context(ContextMenuContextValue {
+    open: open.into(),
+    on_open_change: handle_open_change,
+  });
+
+  view! {
+    <Menu
+      open=open
+      direction=direction
+      modal=modal
+      on_open_change=handle_open_change
+    >
+      {children()}
+    </Menu>
+  }
+}
+
+#[component]
+pub fn ContextMenuTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(default=700.into(), into)] long_press_duration: MaybeSignal<u64>,
+
+  #[prop(default=(|_|{}).into(), into)] on_context_menu: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_move: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_cancel: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_up: Callback<PointerEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ContextMenuContextValue {
+    open,
+    on_open_change,
+    ..
+  } = use_context().expect("ContextMenuTrigger must be used in a ContextMenuRoot component");
+
+  let anchor = RwSignal::new(Rect::default());
+  let long_press_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  let clear_long_press = move || {
+    if let Some(Some(handle)) = long_press_timer.try_get_value() {
+      handle.clear();
+      long_press_timer.set_value(None);
+    }
+  };
+
+  on_cleanup(clear_long_press);
+
+  let handle_open = move |rect: Rect| {
+    anchor.set(rect);
+    on_open_change.call(true);
+  };
+
+  let handle_keyboard_open = move || {
+    let focused = document()
+      .active_element()
+      .filter(|focused| {
+        node_ref
+          .get_untracked()
+          .map(|node| node.contains(Some(focused)))
+          .unwrap_or(false)
+      })
+      .or_else(|| node_ref.get_untracked().map(|node| (*node).clone().into()));
+
+    if let Some(focused) = focused {
+      handle_open(focused.get_bounding_client_rect().into());
+    }
+  };
+
+  let mut merged_attrs = vec![
+    (
+      "data-state",
+      (move || if open.get() { "open" } else { "closed" }).into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+    ("style", "-webkit-touch-callout: none;".into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <MenuAnchor virtual_ref=Signal::derive(move || Some(anchor.get())) />
+    <Primitive
+      element=html::span
+      on:contextmenu=move |ev: MouseEvent| {
+        on_context_menu.call(ev.clone());
+
+        if disabled.get_untracked() || ev.default_prevented() {
+          return;
+        }
+
+        clear_long_press();
+        ev.prevent_default();
+
+        if ev.client_x() == 0 && ev.client_y() == 0 {
+          handle_keyboard_open();
+        } else {
+          handle_open(Rect::new(ev.client_x() as f64, ev.client_y() as f64, 0., 0.));
+        }
+      }
+      on:keydown=move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        if disabled.get_untracked() || ev.default_prevented() {
+          return;
+        }
+
+        if ev.key() == "ContextMenu" || (ev.shift_key() && ev.key() == "F10") {
+          ev.prevent_default();
+          handle_keyboard_open();
+        }
+      }
+      on:pointerdown=move |ev: PointerEvent| {
+        on_pointer_down.call(ev.clone());
+
+        if disabled.get_untracked() || ev.default_prevented() || ev.pointer_type() == "mouse" {
+          return;
+        }
+
+        clear_long_press();
+
+        let rect = Rect::new(ev.client_x() as f64, ev.client_y() as f64, 0., 0.);
+
+        if let Ok(handle) = set_timeout_with_handle(
+          move || handle_open(rect),
+          Duration::from_millis(long_press_duration.get_untracked()),
+        ) {
+          long_press_timer.set_value(Some(handle));
+        }
+      }
+      on:pointermove=move |ev: PointerEvent| {
+        on_pointer_move.call(ev.clone());
+
+        if ev.pointer_type() != "mouse" {
+          clear_long_press();
+        }
+      }
+      on:pointercancel=move |ev: PointerEvent| {
+        on_pointer_cancel.call(ev.clone());
+
+        if ev.pointer_type() != "mouse" {
+          clear_long_press();
+        }
+      }
+      on:pointerup=move |ev: PointerEvent| {
+        on_pointer_up.call(ev.clone());
+
+        if ev.pointer_type() != "mouse" {
+          clear_long_press();
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ContextMenuPortal(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  MenuPortal(MenuPortalProps {
+    force_mount,
+    container,
+    children,
+  })
+}
+
+#[component]
+pub fn ContextMenuContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(default=2.0f64.into(), into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let has_interacted_outside = StoredValue::new(false);
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-context-menu-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-context-menu-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-context-menu-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-context-menu-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-context-menu-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  view! {
+    <MenuContent
+      force_mount=force_mount
+      should_loop=should_loop
+      side=Side::Right
+      side_offset=side_offset
+      align=Align::Start
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_close_auto_focus=Callback::new(move |ev: Event| {
+        on_close_auto_focus.call(ev.clone());
+
+        if !ev.default_prevented() && has_interacted_outside.get_value() {
+          ev.prevent_default();
+        }
+
+        has_interacted_outside.set_value(false);
+      })
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=Callback::new(move |ev: PointerEvent| {
+        on_pointer_down_outside.call(ev.clone());
+
+        if !ev.default_prevented() {
+          has_interacted_outside.set_value(true);
+        }
+      })
+      on_focus_outside=Callback::new(move |ev: FocusEvent| {
+        on_focus_outside.call(ev.clone());
+
+        if !ev.default_prevented() {
+          has_interacted_outside.set_value(true);
+        }
+      })
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuContent>
+  }
+}
+
+#[component]
+pub fn ContextMenuGroup(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuGroup
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuGroup>
+  }
+}
+
+#[component]
+pub fn ContextMenuLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuLabel
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuLabel>
+  }
+}
+
+#[component]
+pub fn ContextMenuItem(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuItem
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      on_click=on_click
+      on_key_down=on_key_down
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuItem>
+  }
+}
+
+#[component]
+pub fn ContextMenuCheckboxItem(
+  #[prop(optional, into)] checked: MaybeProp<CheckedState>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_checked_change: Callback<CheckedState>,
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuCheckboxItem
+      checked=checked
+      disabled=disabled
+      text_value=text_value
+      on_checked_change=on_checked_change
+      on_select=on_select
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuCheckboxItem>
+  }
+}
+
+#[component]
+pub fn ContextMenuRadioGroup(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioGroup
+      value=value
+      on_value_change=on_value_change
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioGroup>
+  }
+}
+
+#[component]
+pub fn ContextMenuRadioItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioItem
+      value=value
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioItem>
+  }
+}
+
+#[component]
+pub fn ContextMenuItemIndicator(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuItemIndicator
+      force_mount=force_mount
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuItemIndicator>
+  }
+}
+
+#[component]
+pub fn ContextMenuSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSeparator
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    />
+  }
+}
+
+#[component]
+pub fn ContextMenuArrow(
+  #[prop(default=10.0f64.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0f64.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+  MenuArrow(MenuArrowProps {
+    width,
+    height,
+    attrs,
+    children,
+  })
+}
+
+#[component]
+pub fn ContextMenuSub(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  view! {
+    <MenuSub
+      id=id
+      open=Signal::derive(move || open.get().unwrap_or(false))
+      on_open_change=Callback::new(move |open| {
+        set_open.set(open);
+      })
+    >
+      {children()}
+    </MenuSub>
+  }
+}
+
+#[component]
+pub fn ContextMenuSubTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSubTrigger
+      disabled=disabled
+      text_value=text_value
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubTrigger>
+  }
+}
+
+#[component]
+pub fn ContextMenuSubContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-context-menu-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-context-menu-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-context-menu-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-context-menu-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-context-menu-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  view! {
+    <MenuSubContent
+      force_mount=force_mount
+      should_loop=should_loop
+      side_offset=side_offset
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=on_pointer_down_outside
+      on_focus_outside=on_focus_outside
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubContent>
+  }
+}
@@ -0,0 +1,497 @@
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsCast;
+use web_sys::{Event, FocusEvent, HtmlElement, KeyboardEvent, MouseEvent, Node, PointerEvent};
+
+use crate::{
+  dismissable_layer::DismissableLayer,
+  focus_scope::FocusScope,
+  portal::{Portal, PortalContainer, PortalProps},
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    hide_others::{create_hide_others, HideOthersMode},
+    scroll_lock::create_scroll_lock,
+    use_id::{create_id, create_part_id},
+    Attributes,
+  },
+};
+
+#[derive(Clone)]
+struct DialogContextValue {
+  trigger_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  title_id: Signal<String>,
+  description_id: Signal<String>,
+  open: Signal<bool>,
+  on_open_change: Callback<bool>,
+  on_open_toggle: Callback<()>,
+  modal: Signal<bool>,
+}
+
+#[component]
+pub fn DialogRoot(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(default=true.into(), into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let id = create_id(id);
+
+  provide_context(DialogContextValue {
+    trigger_ref: NodeRef::new(),
+    content_id: create_part_id(id, "content"),
+    title_id: create_part_id(id, "title"),
+    description_id: create_part_id(id, "description"),
+    open: Signal::derive(move || open.get().unwrap_or(false)),
+    on_open_change: Callback::new(move |open| {
+      set_open.set(open);
+    }),
+    on_open_toggle: Callback::new(move |_| {
+      set_open.update(|open| *open = Some(!open.unwrap_or(false)));
+    }),
+    modal: Signal::derive(move || modal.get()),
+  });
+
+  children()
+}
+
+#[component]
+pub fn DialogTrigger(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue {
+    trigger_ref,
+    content_id,
+    open,
+    on_open_toggle,
+    ..
+  } = use_context().expect("DialogTrigger must be used in a DialogRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let mut merged_attrs = vec![
+    ("type", "button".into_attribute()),
+    ("aria-haspopup", "dialog".into_attribute()),
+    ("aria-expanded", (move || open.get()).into_attribute()),
+    ("aria-controls", (move || content_id.get()).into_attribute()),
+    (
+      "data-state",
+      (move || get_state(open.get())).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::button
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev);
+        on_open_toggle.call(());
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[derive(Clone)]
+struct DialogPortalContextValue {
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub fn DialogPortal(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(DialogPortalContextValue {
+    force_mount: Signal::derive(move || force_mount.get()),
+  });
+
+  Portal(PortalProps {
+    container,
+    children,
+  })
+}
+
+#[component]
+pub fn DialogOverlay(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue { open, modal, .. } =
+    use_context().expect("DialogOverlay must be used in a DialogRoot component");
+  let portal_force_mount = use_context::<DialogPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  let is_present =
+    Signal::derive(move || force_mount.get() || portal_force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+
+  let mut merged_attrs = vec![
+    (
+      "data-state",
+      (move || get_state(open.get())).into_attribute(),
+    ),
+    ("style", "pointer-events: auto".into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || modal.get() && presence.get()>
+      <Primitive
+        element=html::div
+        node_ref=node_ref
+        attrs=merged_attrs.clone()
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn DialogContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue { open, .. } =
+    use_context().expect("DialogContent must be used in a DialogRoot component");
+  let portal_force_mount = use_context::<DialogPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  let is_present =
+    Signal::derive(move || force_mount.get() || portal_force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <DialogContentImpl
+        on_open_auto_focus=on_open_auto_focus
+        on_close_auto_focus=on_close_auto_focus
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        on_focus_outside=on_focus_outside
+        node_ref=node_ref
+        attrs=attrs.clone()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </DialogContentImpl>
+    </Show>
+  }
+}
+
+#[component]
+fn DialogContentImpl(
+  on_open_auto_focus: Callback<Event>,
+  on_close_auto_focus: Callback<Event>,
+  on_escape_key_down: Callback<KeyboardEvent>,
+  on_pointer_down_outside: Callback<PointerEvent>,
+  on_focus_outside: Callback<FocusEvent>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue {
+    trigger_ref,
+    content_id,
+    title_id,
+    description_id,
+    open,
+    on_open_change,
+    modal,
+    ..
+  } = use_context().expect("DialogContentImpl must be used in a DialogRoot component");
+
+  let has_interacted_outside = StoredValue::new(false);
+
+  let is_modal_open = Signal::derive(move || modal.get() && open.get());
+  create_scroll_lock(is_modal_open);
+  create_hide_others(node_ref, is_modal_open, HideOthersMode::AriaHidden);
+
+  let mut merged_attrs = vec![
+    ("role", "dialog".into_attribute()),
+    ("tabindex", (-1).into_attribute()),
+    ("id", (move || content_id.get()).into_attribute()),
+    ("aria-labelledby", (move || title_id.get()).into_attribute()),
+    (
+      "aria-describedby",
+      (move || description_id.get()).into_attribute(),
+    ),
+    (
+      "aria-modal",
+      (move || modal.get().then_some("true")).into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || get_state(open.get())).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.retain(|(name, _)| !attrs.iter().any(|(attr, _)| attr == name));
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <FocusScope
+      as_child=true
+      should_loop=true
+      trapped=is_modal_open
+      on_mount_auto_focus=on_open_auto_focus
+      on_unmount_auto_focus=Callback::new(move |ev: Event| {
+        on_close_auto_focus.call(ev.clone());
+
+        if ev.default_prevented() {
+          return;
+        }
+
+        if modal.get_untracked() || !has_interacted_outside.get_value() {
+          if let Some(trigger) = trigger_ref.get_untracked() {
+            _ = trigger.focus();
+          }
+        }
+
+        ev.prevent_default();
+      })
+      node_ref=node_ref
+    >
+      <DismissableLayer
+        as_child=true
+        disable_outside_pointer_events=modal
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=Callback::new(move |ev: PointerEvent| {
+          on_pointer_down_outside.call(ev.clone());
+
+          if modal.get_untracked() {
+            let is_right_click = ev.button() == 2 || (ev.button() == 0 && ev.ctrl_key());
+
+            if is_right_click {
+              ev.prevent_default();
+            }
+
+            return;
+          }
+
+          if !ev.default_prevented() {
+            has_interacted_outside.set_value(true);
+          }
+
+          if is_trigger(trigger_ref, ev.target()) {
+            ev.prevent_default();
+          }
+        })
+        on_focus_outside=Callback::new(move |ev: FocusEvent| {
+          on_focus_outside.call(ev.clone());
+
+          if modal.get_untracked() {
+            ev.prevent_default();
+            return;
+          }
+
+          if !ev.default_prevented() {
+            has_interacted_outside.set_value(true);
+          }
+
+          if is_trigger(trigger_ref, ev.target()) {
+            ev.prevent_default();
+          }
+        })
+        on_dismiss=Callback::new(move |_| on_open_change.call(false))
+        node_ref=node_ref
+      >
+        <Primitive
+          element=html::div
+          node_ref=node_ref
+          attrs=merged_attrs.get_value()
+          as_child=as_child
+        >
+          {children.with_value(|children| children())}
+        </Primitive>
+      </DismissableLayer>
+    </FocusScope>
+  }
+}
+
+#[component]
+pub fn DialogTitle(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue { title_id, .. } =
+    use_context().expect("DialogTitle must be used in a DialogRoot component");
+
+  let mut merged_attrs = vec![("id", (move || title_id.get()).into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::h2
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn DialogDescription(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue { description_id, .. } =
+    use_context().expect("DialogDescription must be used in a DialogRoot component");
+
+  let mut merged_attrs = vec![("id", (move || description_id.get()).into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::p
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn DialogClose(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DialogContextValue { on_open_change, .. } =
+    use_context().expect("DialogClose must be used in a DialogRoot component");
+
+  let mut merged_attrs = vec![("type", "button".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::button
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev);
+        on_open_change.call(false);
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+fn get_state(open: bool) -> &'static str {
+  if open {
+    "open"
+  } else {
+    "closed"
+  }
+}
+
+fn is_trigger(trigger_ref: NodeRef<AnyElement>, target: Option<web_sys::EventTarget>) -> bool {
+  let Some(trigger) = trigger_ref.get_untracked() else {
+    return false;
+  };
+
+  let target = target.and_then(|target| target.dyn_into::<Node>().ok());
+  let trigger: &HtmlElement = &trigger;
+
+  trigger.contains(target.as_ref())
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn dialog_content_is_focusable_on_the_server() {
+  let runtime = create_runtime();
+
+  let html = ssr::render_to_string(|| {
+    view! {
+      <DialogRoot default_open=true>
+        <DialogTrigger>"Open"</DialogTrigger>
+        <DialogContent>"Dialog"</DialogContent>
+      </DialogRoot>
+    }
+  })
+  .to_string();
+
+  let start = html
+    .find("role=\"dialog\"")
+    .expect("dialog content should render");
+  let tag = &html[html[..start].rfind('<').unwrap()..start + html[start..].find('>').unwrap()];
+  assert!(tag.contains("tabindex=\"-1\""));
+
+  runtime.dispose();
+}
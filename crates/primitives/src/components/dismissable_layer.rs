@@ -0,0 +1,347 @@
+use std::cell::RefCell;
+
+use leptos::{
+  ev::{focusin, keydown, pointerdown},
+  html::AnyElement,
+  *,
+};
+use leptos_use::{use_document, use_event_listener};
+use wasm_bindgen::JsCast;
+use web_sys::{Event, FocusEvent, HtmlElement, KeyboardEvent, Node, PointerEvent};
+
+use crate::{primitive::Primitive, util::Attributes};
+
+struct Layer {
+  id: usize,
+  element: HtmlElement,
+  disable_outside_pointer_events: bool,
+  original_pointer_events: Option<String>,
+}
+
+impl Layer {
+  fn set_pointer_events(&mut self, value: &str) {
+    let style = self.element.style();
+
+    if self.original_pointer_events.is_none() {
+      self.original_pointer_events = Some(
+        style
+          .get_property_value("pointer-events")
+          .unwrap_or_default(),
+      );
+    }
+
+    _ = style.set_property("pointer-events", value);
+  }
+
+  fn restore_pointer_events(&mut self) {
+    if let Some(original_pointer_events) = self.original_pointer_events.take() {
+      restore_style_property(&self.element, "pointer-events", &original_pointer_events);
+    }
+  }
+}
+
+#[derive(Default)]
+struct LayerStack {
+  next_id: usize,
+  layers: Vec<Layer>,
+  branches: Vec<(usize, HtmlElement)>,
+  original_body_pointer_events: Option<String>,
+}
+
+impl LayerStack {
+  fn next_id(&mut self) -> usize {
+    self.next_id += 1;
+    self.next_id
+  }
+
+  fn index_of(&self, id: usize) -> Option<usize> {
+    self.layers.iter().position(|layer| layer.id == id)
+  }
+
+  fn highest_layer_with_outside_pointer_events_disabled(&self) -> Option<usize> {
+    self
+      .layers
+      .iter()
+      .rposition(|layer| layer.disable_outside_pointer_events)
+  }
+
+  fn is_highest_layer(&self, id: usize) -> bool {
+    self.layers.last().is_some_and(|layer| layer.id == id)
+  }
+
+  fn is_pointer_events_enabled(&self, id: usize) -> bool {
+    match (
+      self.index_of(id),
+      self.highest_layer_with_outside_pointer_events_disabled(),
+    ) {
+      (Some(index), Some(highest_disabled_index)) => index >= highest_disabled_index,
+      (Some(_), None) => true,
+      (None, _) => false,
+    }
+  }
+
+  fn is_outside(&self, id: usize, target: Option<&Node>) -> bool {
+    let Some(index) = self.index_of(id) else {
+      return false;
+    };
+
+    let is_inside_branch = self
+      .branches
+      .iter()
+      .any(|(_, branch)| branch.contains(target));
+
+    let is_inside_layer_or_above = self.layers[index..]
+      .iter()
+      .any(|layer| layer.element.contains(target));
+
+    !is_inside_branch && !is_inside_layer_or_above
+  }
+
+  fn update_pointer_events(&mut self) {
+    let highest_disabled_index = self.highest_layer_with_outside_pointer_events_disabled();
+
+    if let Some(body) = document().body() {
+      let body_style = body.style();
+
+      match highest_disabled_index {
+        Some(_) => {
+          if self.original_body_pointer_events.is_none() {
+            self.original_body_pointer_events = Some(
+              body_style
+                .get_property_value("pointer-events")
+                .unwrap_or_default(),
+            );
+          }
+
+          _ = body_style.set_property("pointer-events", "none");
+        }
+        None => {
+          if let Some(original_pointer_events) = self.original_body_pointer_events.take() {
+            restore_style_property(&body, "pointer-events", &original_pointer_events);
+          }
+        }
+      }
+    }
+
+    for (index, layer) in self.layers.iter_mut().enumerate() {
+      match highest_disabled_index {
+        Some(highest_disabled_index) if index >= highest_disabled_index => {
+          layer.set_pointer_events("auto");
+        }
+        Some(_) => layer.set_pointer_events("none"),
+        None => layer.restore_pointer_events(),
+      }
+    }
+  }
+}
+
+fn restore_style_property(element: &HtmlElement, property: &str, original: &str) {
+  let style = element.style();
+
+  if original.is_empty() {
+    _ = style.remove_property(property);
+  } else {
+    _ = style.set_property(property, original);
+  }
+}
+
+thread_local! {
+  static LAYER_STACK: RefCell<LayerStack> = RefCell::new(LayerStack::default());
+}
+
+fn with_layer_stack<T>(f: impl FnOnce(&mut LayerStack) -> T) -> T {
+  LAYER_STACK.with(|stack| f(&mut stack.borrow_mut()))
+}
+
+#[component]
+pub fn DismissableLayer(
+  #[prop(optional, into)] disable_outside_pointer_events: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_interact_outside: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_dismiss: Callback<()>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let layer_id = StoredValue::<Option<usize>>::new(None);
+
+  Effect::new(move |_| {
+    let disable_outside_pointer_events = disable_outside_pointer_events.get();
+
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    let element: HtmlElement = (*node).clone();
+
+    with_layer_stack(|stack| {
+      match layer_id
+        .get_value()
+        .and_then(|id| stack.layers.iter_mut().find(|layer| layer.id == id))
+      {
+        Some(layer) => {
+          if layer.element != element {
+            layer.restore_pointer_events();
+            layer.element = element;
+          }
+
+          layer.disable_outside_pointer_events = disable_outside_pointer_events;
+        }
+        None => {
+          let id = stack.next_id();
+
+          stack.layers.push(Layer {
+            id,
+            element,
+            disable_outside_pointer_events,
+            original_pointer_events: None,
+          });
+          layer_id.set_value(Some(id));
+        }
+      }
+
+      stack.update_pointer_events();
+    });
+  });
+
+  on_cleanup(move || {
+    let Some(Some(id)) = layer_id.try_get_value() else {
+      return;
+    };
+
+    with_layer_stack(|stack| {
+      if let Some(index) = stack.index_of(id) {
+        stack.layers.remove(index).restore_pointer_events();
+      }
+
+      stack.update_pointer_events();
+    });
+  });
+
+  let is_highest_layer = move || {
+    layer_id
+      .get_value()
+      .is_some_and(|id| with_layer_stack(|stack| stack.is_highest_layer(id)))
+  };
+
+  let is_outside = move |target: Option<web_sys::EventTarget>| {
+    let target = target.and_then(|target| target.dyn_into::<Node>().ok());
+
+    layer_id
+      .get_value()
+      .is_some_and(|id| with_layer_stack(|stack| stack.is_outside(id, target.as_ref())))
+  };
+
+  let is_pointer_events_enabled = move || {
+    layer_id
+      .get_value()
+      .is_some_and(|id| with_layer_stack(|stack| stack.is_pointer_events_enabled(id)))
+  };
+
+  _ = use_event_listener(use_document(), keydown, move |ev: KeyboardEvent| {
+    if ev.key() != "Escape" || !is_highest_layer() {
+      return;
+    }
+
+    on_escape_key_down.call(ev.clone());
+
+    if !ev.default_prevented() {
+      ev.prevent_default();
+      on_dismiss.call(());
+    }
+  });
+
+  _ = use_event_listener(use_document(), pointerdown, move |ev: PointerEvent| {
+    if !is_pointer_events_enabled() || !is_outside(ev.target()) {
+      return;
+    }
+
+    on_pointer_down_outside.call(ev.clone());
+    on_interact_outside.call(ev.clone().into());
+
+    if !ev.default_prevented() {
+      on_dismiss.call(());
+    }
+  });
+
+  _ = use_event_listener(use_document(), focusin, move |ev: FocusEvent| {
+    if !is_outside(ev.target()) {
+      return;
+    }
+
+    on_focus_outside.call(ev.clone());
+    on_interact_outside.call(ev.clone().into());
+
+    if !ev.default_prevented() {
+      on_dismiss.call(());
+    }
+  });
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn DismissableLayerBranch(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let branch_id = StoredValue::<Option<usize>>::new(None);
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    let element: HtmlElement = (*node).clone();
+
+    with_layer_stack(|stack| {
+      if let Some(id) = branch_id.get_value() {
+        stack.branches.retain(|(branch_id, _)| *branch_id != id);
+      }
+
+      let id = stack.next_id();
+
+      stack.branches.push((id, element));
+      branch_id.set_value(Some(id));
+    });
+  });
+
+  on_cleanup(move || {
+    let Some(Some(id)) = branch_id.try_get_value() else {
+      return;
+    };
+
+    with_layer_stack(|stack| {
+      stack.branches.retain(|(branch_id, _)| *branch_id != id);
+    });
+  });
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
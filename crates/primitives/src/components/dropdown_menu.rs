@@ -0,0 +1,661 @@
+use leptos::{html::AnyElement, *};
+use web_sys::{Event, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent};
+
+use crate::{
+  checkbox::CheckedState,
+  menu::{
+    Menu, MenuAnchor, MenuArrow, MenuArrowProps, MenuCheckboxItem, MenuContent, MenuGroup,
+    MenuItem, MenuItemIndicator, MenuLabel, MenuPortal, MenuPortalProps, MenuRadioGroup,
+    MenuRadioItem, MenuSeparator, MenuSub, MenuSubContent, MenuSubTrigger,
+  },
+  popper::{Align, Side, Sticky},
+  portal::PortalContainer,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    use_id::{create_id, create_part_id},
+    Attributes,
+  },
+  Direction,
+};
+
+#[derive(Clone)]
+struct DropdownMenuContextValue {
+  trigger_id: Signal<String>,
+  trigger_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  open: Signal<bool>,
+  on_open_change: Callback<bool>,
+  on_open_toggle: Callback<()>,
+  modal: Signal<bool>,
+}
+
+#[component]
+pub fn DropdownMenuRoot(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(default=true.into(), into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let open = Signal::derive(move || open.get().unwrap_or(false));
+  let modal = Signal::derive(move || modal.get());
+
+  let id = create_id(id);
+
+  provide_context(DropdownMenuContextValue {
+    trigger_id: create_part_id(id, "trigger"),
+    trigger_ref: NodeRef::new(),
+    content_id: create_part_id(id, "content"),
+    open,
+    on_open_change: Callback::new(move |open| {
+      set_open.set(open);
+    }),
+    on_open_toggle: Callback::new(move |_| {
+      set_open.update(|open| *open = Some(!open.unwrap_or(false)));
+    }),
+    modal,
+  });
+
+  view! {
+    <Menu
+      open=open
+      direction=direction
+      modal=modal
+      on_open_change=Callback::new(move |open| {
+        set_open.set(open);
+      })
+    >
+      {children()}
+    </Menu>
+  }
+}
+
+#[component]
+pub fn DropdownMenuTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DropdownMenuContextValue {
+    trigger_id,
+    trigger_ref,
+    content_id,
+    open,
+    on_open_change,
+    on_open_toggle,
+    ..
+  } = use_context().expect("DropdownMenuTrigger must be used in a DropdownMenuRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let mut merged_attrs = vec![
+    ("type", "button".into_attribute()),
+    ("id", (move || trigger_id.get()).into_attribute()),
+    ("aria-haspopup", "menu".into_attribute()),
+    ("aria-expanded", (move || open.get()).into_attribute()),
+    (
+      "aria-controls",
+      (move || open.get().then(|| content_id.get())).into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || if open.get() { "open" } else { "closed" }).into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+    ("disabled", (move || disabled.get()).into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <MenuAnchor as_child=true node_ref=node_ref>
+      <Primitive
+        element=html::button
+        on:pointerdown=move |ev: PointerEvent| {
+          on_pointer_down.call(ev.clone());
+
+          if disabled.get_untracked() || ev.button() != 0 || ev.ctrl_key() {
+            return;
+          }
+
+          let was_open = open.get_untracked();
+          on_open_toggle.call(());
+
+          if !was_open {
+            ev.prevent_default();
+          }
+        }
+        on:keydown=move |ev: KeyboardEvent| {
+          on_key_down.call(ev.clone());
+
+          if disabled.get_untracked() {
+            return;
+          }
+
+          match ev.key().as_str() {
+            "Enter" | " " => on_open_toggle.call(()),
+            "ArrowDown" => on_open_change.call(true),
+            _ => return,
+          }
+
+          ev.prevent_default();
+        }
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </MenuAnchor>
+  }
+}
+
+#[component]
+pub fn DropdownMenuPortal(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  MenuPortal(MenuPortalProps {
+    force_mount,
+    container,
+    children,
+  })
+}
+
+#[component]
+pub fn DropdownMenuContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let DropdownMenuContextValue {
+    trigger_id,
+    trigger_ref,
+    content_id,
+    modal,
+    ..
+  } = use_context().expect("DropdownMenuContent must be used in a DropdownMenuRoot component");
+
+  let has_interacted_outside = StoredValue::new(false);
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-dropdown-menu-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-dropdown-menu-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-dropdown-menu-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-dropdown-menu-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-dropdown-menu-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let mut merged_attrs = vec![
+    ("id", (move || content_id.get()).into_attribute()),
+    (
+      "aria-labelledby",
+      (move || trigger_id.get()).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <MenuContent
+      force_mount=force_mount
+      should_loop=should_loop
+      side=side
+      side_offset=side_offset
+      align=align
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_close_auto_focus=Callback::new(move |ev: Event| {
+        on_close_auto_focus.call(ev.clone());
+
+        if ev.default_prevented() {
+          return;
+        }
+
+        if !has_interacted_outside.get_value() {
+          if let Some(trigger) = trigger_ref.get_untracked() {
+            _ = trigger.focus();
+          }
+        }
+
+        has_interacted_outside.set_value(false);
+        ev.prevent_default();
+      })
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=Callback::new(move |ev: PointerEvent| {
+        on_pointer_down_outside.call(ev.clone());
+
+        let is_right_click = ev.button() == 2 || (ev.button() == 0 && ev.ctrl_key());
+
+        if !modal.get_untracked() || is_right_click {
+          has_interacted_outside.set_value(true);
+        }
+      })
+      on_focus_outside=Callback::new(move |ev: FocusEvent| {
+        on_focus_outside.call(ev);
+
+        if !modal.get_untracked() {
+          has_interacted_outside.set_value(true);
+        }
+      })
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuContent>
+  }
+}
+
+#[component]
+pub fn DropdownMenuGroup(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuGroup
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuGroup>
+  }
+}
+
+#[component]
+pub fn DropdownMenuLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuLabel
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuLabel>
+  }
+}
+
+#[component]
+pub fn DropdownMenuItem(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuItem
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      on_click=on_click
+      on_key_down=on_key_down
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuItem>
+  }
+}
+
+#[component]
+pub fn DropdownMenuCheckboxItem(
+  #[prop(optional, into)] checked: MaybeProp<CheckedState>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_checked_change: Callback<CheckedState>,
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuCheckboxItem
+      checked=checked
+      disabled=disabled
+      text_value=text_value
+      on_checked_change=on_checked_change
+      on_select=on_select
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuCheckboxItem>
+  }
+}
+
+#[component]
+pub fn DropdownMenuRadioGroup(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioGroup
+      value=value
+      on_value_change=on_value_change
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioGroup>
+  }
+}
+
+#[component]
+pub fn DropdownMenuRadioItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioItem
+      value=value
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioItem>
+  }
+}
+
+#[component]
+pub fn DropdownMenuItemIndicator(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuItemIndicator
+      force_mount=force_mount
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuItemIndicator>
+  }
+}
+
+#[component]
+pub fn DropdownMenuSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSeparator
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    />
+  }
+}
+
+#[component]
+pub fn DropdownMenuArrow(
+  #[prop(default=10.0f64.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0f64.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+  MenuArrow(MenuArrowProps {
+    width,
+    height,
+    attrs,
+    children,
+  })
+}
+
+#[component]
+pub fn DropdownMenuSub(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  view! {
+    <MenuSub
+      id=id
+      open=Signal::derive(move || open.get().unwrap_or(false))
+      on_open_change=Callback::new(move |open| {
+        set_open.set(open);
+      })
+    >
+      {children()}
+    </MenuSub>
+  }
+}
+
+#[component]
+pub fn DropdownMenuSubTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSubTrigger
+      disabled=disabled
+      text_value=text_value
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubTrigger>
+  }
+}
+
+#[component]
+pub fn DropdownMenuSubContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-dropdown-menu-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-dropdown-menu-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-dropdown-menu-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-dropdown-menu-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-dropdown-menu-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  view! {
+    <MenuSubContent
+      force_mount=force_mount
+      should_loop=should_loop
+      side_offset=side_offset
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=on_pointer_down_outside
+      on_focus_outside=on_focus_outside
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubContent>
+  }
+}
@@ -0,0 +1,287 @@
+use std::cell::RefCell;
+
+use leptos::{
+  ev::{focusin, keydown},
+  html::AnyElement,
+  *,
+};
+use leptos_use::{use_document, use_event_listener};
+use wasm_bindgen::JsCast;
+use web_sys::{CustomEvent, CustomEventInit, Event, HtmlElement, KeyboardEvent, Node};
+
+use crate::{primitive::Primitive, util::Attributes};
+
+const AUTOFOCUS_ON_MOUNT: &str = "focus_scope.auto_focus_on_mount";
+const AUTOFOCUS_ON_UNMOUNT: &str = "focus_scope.auto_focus_on_unmount";
+
+#[derive(Default)]
+struct FocusScopeStack {
+  next_id: usize,
+  scopes: Vec<usize>,
+}
+
+thread_local! {
+  static FOCUS_SCOPE_STACK: RefCell<FocusScopeStack> = RefCell::new(FocusScopeStack::default());
+}
+
+fn with_focus_scope_stack<T>(f: impl FnOnce(&mut FocusScopeStack) -> T) -> T {
+  FOCUS_SCOPE_STACK.with(|stack| f(&mut stack.borrow_mut()))
+}
+
+#[component]
+pub fn FocusScope(
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+  #[prop(optional, into)] trapped: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_mount_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_unmount_auto_focus: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let previously_focused = StoredValue::<Option<web_sys::Element>>::new(None);
+  let last_focused = StoredValue::<Option<HtmlElement>>::new(None);
+  let scope_id = StoredValue::<Option<usize>>::new(None);
+
+  let is_paused = move || {
+    scope_id
+      .get_value()
+      .map(|id| with_focus_scope_stack(|stack| stack.scopes.last() != Some(&id)))
+      .unwrap_or(true)
+  };
+
+  Effect::new(move |prev: Option<bool>| {
+    let Some(node) = node_ref.get() else {
+      return false;
+    };
+
+    if prev.unwrap_or(false) {
+      return true;
+    }
+
+    previously_focused.set_value(document().active_element());
+
+    with_focus_scope_stack(|stack| {
+      stack.next_id += 1;
+
+      let id = stack.next_id;
+      stack.scopes.push(id);
+      scope_id.set_value(Some(id));
+    });
+
+    let container: &web_sys::Element = &node;
+
+    if !container.contains(document().active_element().as_deref()) {
+      if let Some(ev) = create_focus_scope_event(AUTOFOCUS_ON_MOUNT) {
+        on_mount_auto_focus.call(ev.clone());
+
+        if !ev.default_prevented() {
+          let candidates = get_tabbable_candidates(container)
+            .into_iter()
+            .filter(|candidate| candidate.tag_name() != "A")
+            .collect::<Vec<_>>();
+
+          if !focus_first(&candidates) {
+            _ = node.focus();
+          }
+        }
+      }
+    }
+
+    true
+  });
+
+  on_cleanup(move || {
+    let Some(Some(id)) = scope_id.try_get_value() else {
+      return;
+    };
+
+    with_focus_scope_stack(|stack| stack.scopes.retain(|scope| *scope != id));
+
+    let Some(ev) = create_focus_scope_event(AUTOFOCUS_ON_UNMOUNT) else {
+      return;
+    };
+
+    on_unmount_auto_focus.call(ev.clone());
+
+    if ev.default_prevented() {
+      return;
+    }
+
+    let target = previously_focused
+      .try_get_value()
+      .flatten()
+      .and_then(|el| el.dyn_into::<HtmlElement>().ok())
+      .or_else(|| document().body());
+
+    if let Some(target) = target {
+      _ = target.focus();
+    }
+  });
+
+  _ = use_event_listener(use_document(), focusin, move |ev| {
+    if is_paused() {
+      return;
+    }
+
+    let Some(node) = node_ref.get_untracked() else {
+      return;
+    };
+
+    let target = ev
+      .target()
+      .and_then(|target| target.dyn_into::<HtmlElement>().ok());
+
+    if node.contains(target.as_ref().map(|target| target.unchecked_ref::<Node>())) {
+      last_focused.set_value(target);
+    } else if trapped.get_untracked() {
+      match last_focused.get_value() {
+        Some(last_focused) => _ = last_focused.focus(),
+        None => _ = node.focus(),
+      }
+    }
+  });
+
+  _ = use_event_listener(node_ref, keydown, move |ev: KeyboardEvent| {
+    if (!should_loop.get_untracked() && !trapped.get_untracked()) || is_paused() {
+      return;
+    }
+
+    if ev.key() != "Tab" || ev.alt_key() || ev.ctrl_key() || ev.meta_key() {
+      return;
+    }
+
+    let Some(node) = node_ref.get_untracked() else {
+      return;
+    };
+
+    let candidates = get_tabbable_candidates(&node);
+    let focused = document().active_element();
+
+    let (Some(first), Some(last)) = (candidates.first(), candidates.last()) else {
+      if focused.as_deref() == Some(&node) {
+        ev.prevent_default();
+      }
+
+      return;
+    };
+
+    let focused = focused.as_ref();
+
+    if !ev.shift_key() && focused == Some(last) {
+      ev.prevent_default();
+
+      if should_loop.get_untracked() {
+        _ = first.focus();
+      }
+    } else if ev.shift_key() && focused == Some(first) {
+      ev.prevent_default();
+
+      if should_loop.get_untracked() {
+        _ = last.focus();
+      }
+    }
+  });
+
+  let mut merged_attrs = vec![("tabindex", (-1).into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+fn create_focus_scope_event(name: &str) -> Option<Event> {
+  use_document().as_ref()?;
+
+  let mut init = CustomEventInit::new();
+  init.bubbles(false).cancelable(true);
+
+  CustomEvent::new_with_event_init_dict(name, &init)
+    .ok()
+    .map(Into::into)
+}
+
+pub(crate) fn get_tabbable_candidates(container: &web_sys::Element) -> Vec<HtmlElement> {
+  let Ok(nodes) = container
+    .query_selector_all("a[href], button, input, select, textarea, [tabindex], [contenteditable]")
+  else {
+    return vec![];
+  };
+
+  (0..nodes.length())
+    .filter_map(|index| nodes.item(index)?.dyn_into::<HtmlElement>().ok())
+    .filter(|el| {
+      let is_hidden_input =
+        el.tag_name() == "INPUT" && el.get_attribute("type").as_deref() == Some("hidden");
+      let is_inert = el.closest("[inert]").ok().flatten().is_some();
+
+      el.tab_index() >= 0
+        && !el.has_attribute("disabled")
+        && !is_hidden_input
+        && !is_inert
+        && !is_hidden(el, container)
+    })
+    .collect()
+}
+
+fn is_hidden(el: &HtmlElement, up_to: &web_sys::Element) -> bool {
+  if el.hidden() {
+    return true;
+  }
+
+  let Ok(Some(styles)) = window().get_computed_style(el) else {
+    return false;
+  };
+
+  if styles.get_property_value("visibility").as_deref() == Ok("hidden") {
+    return true;
+  }
+
+  let mut current: Option<web_sys::Element> = Some(el.clone().into());
+
+  while let Some(node) = current {
+    if node.is_same_node(Some(up_to)) {
+      break;
+    }
+
+    let is_display_none = window()
+      .get_computed_style(&node)
+      .ok()
+      .flatten()
+      .and_then(|styles| styles.get_property_value("display").ok())
+      .is_some_and(|display| display == "none");
+
+    if is_display_none {
+      return true;
+    }
+
+    current = node.parent_element();
+  }
+
+  false
+}
+
+pub(crate) fn focus_first(candidates: &[HtmlElement]) -> bool {
+  let previously_focused = document().active_element();
+
+  for candidate in candidates {
+    _ = candidate.focus();
+
+    if document().active_element() != previously_focused {
+      return true;
+    }
+  }
+
+  false
+}
@@ -0,0 +1,1037 @@
+use std::{collections::HashMap, future::Future, pin::Pin, rc::Rc};
+
+use leptos::{
+  ev::{change, click, invalid},
+  html::AnyElement,
+  *,
+};
+use leptos_use::{use_event_listener_with_options, UseEventListenerOptions};
+use wasm_bindgen::JsCast;
+use web_sys::{
+  Element, Event, FormData, HtmlFormElement, HtmlInputElement, HtmlSelectElement,
+  HtmlTextAreaElement, SubmitEvent,
+};
+
+use crate::{
+  label::LabelRoot,
+  primitive::Primitive,
+  util::{use_id::create_id, Attributes},
+};
+
+const DEFAULT_INVALID_MESSAGE: &str = "This value is not valid";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ValidityState {
+  pub bad_input: bool,
+  pub custom_error: bool,
+  pub pattern_mismatch: bool,
+  pub range_overflow: bool,
+  pub range_underflow: bool,
+  pub step_mismatch: bool,
+  pub too_long: bool,
+  pub too_short: bool,
+  pub type_mismatch: bool,
+  pub valid: bool,
+  pub value_missing: bool,
+}
+
+impl ValidityState {
+  pub fn has_built_in_error(&self) -> bool {
+    self.bad_input
+      || self.pattern_mismatch
+      || self.range_overflow
+      || self.range_underflow
+      || self.step_mismatch
+      || self.too_long
+      || self.too_short
+      || self.type_mismatch
+      || self.value_missing
+  }
+}
+
+impl From<web_sys::ValidityState> for ValidityState {
+  fn from(validity: web_sys::ValidityState) -> Self {
+    Self {
+      bad_input: validity.bad_input(),
+      custom_error: validity.custom_error(),
+      pattern_mismatch: validity.pattern_mismatch(),
+      range_overflow: validity.range_overflow(),
+      range_underflow: validity.range_underflow(),
+      step_mismatch: validity.step_mismatch(),
+      too_long: validity.too_long(),
+      too_short: validity.too_short(),
+      type_mismatch: validity.type_mismatch(),
+      valid: validity.valid(),
+      value_missing: validity.value_missing(),
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValidityMatcher {
+  BadInput,
+  PatternMismatch,
+  RangeOverflow,
+  RangeUnderflow,
+  StepMismatch,
+  TooLong,
+  TooShort,
+  TypeMismatch,
+  Valid,
+  ValueMissing,
+}
+
+impl ValidityMatcher {
+  fn matches(&self, validity: &ValidityState) -> bool {
+    match self {
+      Self::BadInput => validity.bad_input,
+      Self::PatternMismatch => validity.pattern_mismatch,
+      Self::RangeOverflow => validity.range_overflow,
+      Self::RangeUnderflow => validity.range_underflow,
+      Self::StepMismatch => validity.step_mismatch,
+      Self::TooLong => validity.too_long,
+      Self::TooShort => validity.too_short,
+      Self::TypeMismatch => validity.type_mismatch,
+      Self::Valid => validity.valid,
+      Self::ValueMissing => validity.value_missing,
+    }
+  }
+
+  fn default_message(&self) -> Option<&'static str> {
+    match self {
+      Self::BadInput => Some(DEFAULT_INVALID_MESSAGE),
+      Self::PatternMismatch => Some("This value does not match the required pattern"),
+      Self::RangeOverflow => Some("This value is too large"),
+      Self::RangeUnderflow => Some("This value is too small"),
+      Self::StepMismatch => Some("This value does not match the required step"),
+      Self::TooLong => Some("This value is too long"),
+      Self::TooShort => Some("This value is too short"),
+      Self::TypeMismatch => Some("This value does not match the required type"),
+      Self::Valid => None,
+      Self::ValueMissing => Some("This value is missing"),
+    }
+  }
+}
+
+type CustomMatcher = Rc<dyn Fn(String, FormData) -> bool>;
+type AsyncCustomMatcher = Rc<dyn Fn(String, FormData) -> Pin<Box<dyn Future<Output = bool>>>>;
+
+#[derive(Clone)]
+pub enum FormMatcher {
+  BuiltIn(ValidityMatcher),
+  Custom(CustomMatcher),
+  Async(AsyncCustomMatcher),
+}
+
+impl FormMatcher {
+  pub fn custom(matcher: impl Fn(String, FormData) -> bool + 'static) -> Self {
+    Self::Custom(Rc::new(matcher))
+  }
+
+  pub fn custom_async<F, Fut>(matcher: F) -> Self
+  where
+    F: Fn(String, FormData) -> Fut + 'static,
+    Fut: Future<Output = bool> + 'static,
+  {
+    Self::Async(Rc::new(move |value, form_data| {
+      Box::pin(matcher(value, form_data))
+    }))
+  }
+}
+
+impl From<ValidityMatcher> for FormMatcher {
+  fn from(matcher: ValidityMatcher) -> Self {
+    Self::BuiltIn(matcher)
+  }
+}
+
+#[derive(Clone, Copy)]
+struct FormContextValue {
+  validity: RwSignal<HashMap<String, ValidityState>>,
+  custom_errors: RwSignal<HashMap<String, HashMap<String, bool>>>,
+  custom_matchers: StoredValue<HashMap<String, Vec<(String, FormMatcher)>>>,
+  message_ids: RwSignal<HashMap<String, Vec<String>>>,
+  validation_runs: StoredValue<HashMap<String, usize>>,
+}
+
+impl FormContextValue {
+  fn new() -> Self {
+    Self {
+      validity: RwSignal::new(HashMap::new()),
+      custom_errors: RwSignal::new(HashMap::new()),
+      custom_matchers: StoredValue::new(HashMap::new()),
+      message_ids: RwSignal::new(HashMap::new()),
+      validation_runs: StoredValue::new(HashMap::new()),
+    }
+  }
+
+  fn field_validity(&self, name: &str) -> Option<ValidityState> {
+    self.validity.with(|validity| validity.get(name).copied())
+  }
+
+  fn field_custom_error(&self, name: &str, id: &str) -> bool {
+    self.custom_errors.with(|errors| {
+      errors
+        .get(name)
+        .and_then(|errors| errors.get(id))
+        .copied()
+        .unwrap_or(false)
+    })
+  }
+
+  fn field_description(&self, name: &str) -> Option<String> {
+    self.message_ids.with(|message_ids| {
+      message_ids
+        .get(name)
+        .filter(|ids| !ids.is_empty())
+        .map(|ids| ids.join(" "))
+    })
+  }
+
+  fn next_validation_run(&self, name: &str) -> usize {
+    self
+      .validation_runs
+      .try_update_value(|runs| {
+        let run = runs.entry(name.to_string()).or_default();
+        *run += 1;
+        *run
+      })
+      .unwrap_or_default()
+  }
+
+  fn is_current_validation_run(&self, name: &str, run: usize) -> bool {
+    self
+      .validation_runs
+      .try_with_value(|runs| runs.get(name) == Some(&run))
+      .unwrap_or(false)
+  }
+
+  fn clear_field(&self, name: &str) {
+    self.next_validation_run(name);
+
+    _ = self.validity.try_update(|validity| {
+      validity.remove(name);
+    });
+    _ = self.custom_errors.try_update(|errors| {
+      errors.remove(name);
+    });
+  }
+
+  fn set_field_result(
+    &self,
+    name: &str,
+    control: &FormControlElement,
+    custom_errors: HashMap<String, bool>,
+  ) {
+    let has_custom_error = custom_errors.values().any(|error| *error);
+
+    control.set_custom_validity(if has_custom_error {
+      DEFAULT_INVALID_MESSAGE
+    } else {
+      ""
+    });
+
+    self.record_field_result(name, control.validity(), custom_errors);
+  }
+
+  fn record_field_result(
+    &self,
+    name: &str,
+    field_validity: ValidityState,
+    custom_errors: HashMap<String, bool>,
+  ) {
+    _ = self.validity.try_update(|validity| {
+      validity.insert(name.to_string(), field_validity);
+    });
+    _ = self.custom_errors.try_update(|errors| {
+      errors
+        .entry(name.to_string())
+        .or_default()
+        .extend(custom_errors);
+    });
+  }
+
+  fn update_control_validity(&self, name: String, control: FormControlElement) {
+    let run = self.next_validation_run(&name);
+    let validity = control.validity();
+
+    if validity.has_built_in_error() {
+      _ = self.validity.try_update(|field_validity| {
+        field_validity.insert(name, validity);
+      });
+
+      return;
+    }
+
+    let Some(form_data) = control
+      .form()
+      .and_then(|form| FormData::new_with_form(&form).ok())
+      .or_else(|| FormData::new().ok())
+    else {
+      return;
+    };
+
+    let value = control.value();
+    let matchers = self
+      .custom_matchers
+      .with_value(|matchers| matchers.get(&name).cloned().unwrap_or_default());
+
+    let mut sync_errors = HashMap::new();
+    let mut async_matchers = vec![];
+
+    for (id, matcher) in matchers {
+      match matcher {
+        FormMatcher::BuiltIn(_) => {}
+        FormMatcher::Custom(matcher) => {
+          sync_errors.insert(id, matcher(value.clone(), form_data.clone()));
+        }
+        FormMatcher::Async(matcher) => async_matchers.push((id, matcher)),
+      }
+    }
+
+    let has_sync_error = sync_errors.values().any(|error| *error);
+
+    _ = self.custom_errors.try_update(|errors| {
+      errors.remove(&name);
+    });
+    self.set_field_result(&name, &control, sync_errors);
+
+    if has_sync_error || async_matchers.is_empty() {
+      return;
+    }
+
+    let context = *self;
+
+    spawn_local(async move {
+      let mut async_errors = HashMap::new();
+
+      for (id, matcher) in async_matchers {
+        async_errors.insert(id, matcher(value.clone(), form_data.clone()).await);
+      }
+
+      if context.is_current_validation_run(&name, run) {
+        context.set_field_result(&name, &control, async_errors);
+      }
+    });
+  }
+}
+
+#[derive(Clone, Copy)]
+struct FormFieldContextValue {
+  id: Signal<String>,
+  name: Signal<String>,
+  server_invalid: Signal<bool>,
+}
+
+#[component]
+pub fn FormRoot(
+  #[prop(default=(|_|{}).into(), into)] on_clear_server_errors: Callback<()>,
+  #[prop(default=(|_|{}).into(), into)] on_submit: Callback<SubmitEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_reset: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = FormContextValue::new();
+
+  provide_context(context);
+
+  _ = use_event_listener_with_options(
+    node_ref,
+    invalid,
+    move |ev: Event| {
+      let Some(form) = node_ref
+        .get_untracked()
+        .and_then(|node| node.dyn_ref::<HtmlFormElement>().cloned())
+      else {
+        return;
+      };
+
+      let first_invalid_control = get_first_invalid_control(&form);
+      let target = ev
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok());
+
+      if let (Some(control), Some(target)) = (first_invalid_control, target) {
+        if control.is_same_node(&target) {
+          control.focus();
+        }
+      }
+
+      ev.prevent_default();
+    },
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  view! {
+    <Primitive
+      element=html::form
+      on:submit=move |ev: SubmitEvent| {
+        on_submit.call(ev);
+        on_clear_server_errors.call(());
+      }
+      on:reset=move |ev: Event| {
+        on_reset.call(ev.clone());
+
+        if ev.default_prevented() {
+          return;
+        }
+
+        if let Some(form) = node_ref
+          .get_untracked()
+          .and_then(|node| node.dyn_ref::<HtmlFormElement>().cloned())
+        {
+          for control in get_form_controls(&form) {
+            control.set_custom_validity("");
+          }
+        }
+
+        context.validity.set(HashMap::new());
+        context.custom_errors.set(HashMap::new());
+        on_clear_server_errors.call(());
+      }
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn FormField(
+  #[prop(into)] name: MaybeSignal<String>,
+  #[prop(optional, into)] server_invalid: MaybeSignal<bool>,
+  #[prop(optional, into)] id: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context =
+    use_context::<FormContextValue>().expect("FormField must be used in a FormRoot component");
+
+  let name = Signal::derive(move || name.get());
+  let server_invalid = Signal::derive(move || server_invalid.get());
+
+  provide_context(FormFieldContextValue {
+    id: create_id(id),
+    name,
+    server_invalid,
+  });
+
+  let validate = move |ev: Event| {
+    if let Some(control) = ev.target().and_then(FormControlElement::from_target) {
+      context.update_control_validity(name.get_untracked(), control);
+    }
+  };
+
+  _ = use_event_listener_with_options(
+    node_ref,
+    invalid,
+    validate,
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  _ = use_event_listener_with_options(
+    node_ref,
+    change,
+    validate,
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  _ = use_event_listener_with_options(
+    node_ref,
+    click,
+    move |ev| {
+      let is_checkable = ev
+        .target()
+        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+        .is_some_and(|input| matches!(input.type_().as_str(), "checkbox" | "radio"));
+
+      if is_checkable {
+        validate(ev.into());
+      }
+    },
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  _ = use_event_listener_with_options(
+    node_ref,
+    ev::input,
+    move |ev| {
+      if let Some(control) = ev.target().and_then(FormControlElement::from_target) {
+        control.set_custom_validity("");
+        context.clear_field(&name.get_untracked());
+      }
+    },
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  Effect::new(move |_| {
+    if !server_invalid.get() {
+      return;
+    }
+
+    let Some(node) = node_ref.get_untracked() else {
+      return;
+    };
+
+    let Some(form) = node
+      .closest("form")
+      .ok()
+      .flatten()
+      .and_then(|form| form.dyn_into::<HtmlFormElement>().ok())
+    else {
+      return;
+    };
+
+    if let Some(control) = get_first_invalid_control(&form) {
+      if node.contains(Some(control.as_ref())) {
+        control.focus();
+      }
+    }
+  });
+
+  let validity = Signal::derive(move || context.field_validity(&name.get()));
+
+  let mut merged_attrs = vec![
+    (
+      "data-valid",
+      (move || get_is_valid(validity.get(), server_invalid.get())).into_attribute(),
+    ),
+    (
+      "data-invalid",
+      (move || get_is_invalid(validity.get(), server_invalid.get())).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn FormLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context =
+    use_context::<FormContextValue>().expect("FormLabel must be used in a FormRoot component");
+  let FormFieldContextValue {
+    id,
+    name,
+    server_invalid,
+  } = use_context().expect("FormLabel must be used in a FormField component");
+
+  let validity = Signal::derive(move || context.field_validity(&name.get()));
+
+  let mut merged_attrs = vec![
+    (
+      "data-valid",
+      (move || get_is_valid(validity.get(), server_invalid.get())).into_attribute(),
+    ),
+    (
+      "data-invalid",
+      (move || get_is_invalid(validity.get(), server_invalid.get())).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <LabelRoot
+      for_html=id
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </LabelRoot>
+  }
+}
+
+#[component]
+pub fn FormControl(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let FormFieldContextValue { name, .. } =
+    use_context().expect("FormControl must be used in a FormField component");
+
+  let mut merged_attrs = vec![
+    ("name", (move || name.get()).into_attribute()),
+    ("title", "".into_attribute()),
+  ];
+
+  merged_attrs.extend(use_form_control_attrs());
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::input
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn FormMessage(
+  #[prop(optional, into)] matcher: Option<FormMatcher>,
+  #[prop(optional, into)] force_match: MaybeSignal<bool>,
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] id: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context =
+    use_context::<FormContextValue>().expect("FormMessage must be used in a FormRoot component");
+  let field_name = use_context::<FormFieldContextValue>().map(|field| field.name);
+
+  let name = Signal::derive(move || {
+    name
+      .get()
+      .or_else(|| field_name.map(|name| name.get()))
+      .expect("FormMessage must be used in a FormField component or given a name")
+  });
+
+  let id = create_id(id);
+  let validity = Signal::derive(move || context.field_validity(&name.get()));
+
+  let default_message = match &matcher {
+    Some(FormMatcher::BuiltIn(matcher)) => matcher.default_message(),
+    _ => Some(DEFAULT_INVALID_MESSAGE),
+  };
+
+  let matches = match matcher {
+    None => Signal::derive(|| true),
+    Some(FormMatcher::BuiltIn(matcher)) => Signal::derive(move || {
+      force_match.get()
+        || validity
+          .get()
+          .is_some_and(|validity| matcher.matches(&validity))
+    }),
+    Some(matcher) => {
+      let field_name = name.get_untracked();
+      let matcher_id = id.get_untracked();
+
+      context.custom_matchers.update_value(|matchers| {
+        matchers
+          .entry(field_name.clone())
+          .or_default()
+          .push((matcher_id.clone(), matcher));
+      });
+
+      on_cleanup(move || {
+        context.custom_matchers.try_update_value(|matchers| {
+          if let Some(matchers) = matchers.get_mut(&field_name) {
+            matchers.retain(|(id, _)| *id != matcher_id);
+          }
+        });
+      });
+
+      Signal::derive(move || {
+        force_match.get()
+          || custom_matcher_matches(
+            validity.get(),
+            context.field_custom_error(&name.get(), &id.get()),
+          )
+      })
+    }
+  };
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || matches.get()>
+      <FormMessageImpl
+        id=id
+        name=name
+        node_ref=node_ref
+        attrs=attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| match children {
+          Some(children) => children().into_view(),
+          None => default_message.into_view(),
+        })}
+      </FormMessageImpl>
+    </Show>
+  }
+}
+
+#[component]
+fn FormMessageImpl(
+  id: Signal<String>,
+  name: Signal<String>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<FormContextValue>()
+    .expect("FormMessageImpl must be used in a FormRoot component");
+
+  let field_name = name.get_untracked();
+  let message_id = id.get_untracked();
+
+  context.message_ids.update(|message_ids| {
+    message_ids
+      .entry(field_name.clone())
+      .or_default()
+      .push(message_id.clone());
+  });
+
+  on_cleanup(move || {
+    _ = context.message_ids.try_update(|message_ids| {
+      if let Some(ids) = message_ids.get_mut(&field_name) {
+        ids.retain(|id| *id != message_id);
+      }
+    });
+  });
+
+  let mut merged_attrs = vec![("id", (move || id.get()).into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::span
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn FormValidityState<F, IV>(
+  #[prop(optional, into)] name: MaybeProp<String>,
+
+  children: F,
+) -> impl IntoView
+where
+  F: Fn(Option<ValidityState>) -> IV + 'static,
+  IV: IntoView,
+{
+  let context = use_context::<FormContextValue>()
+    .expect("FormValidityState must be used in a FormRoot component");
+  let field_name = use_context::<FormFieldContextValue>().map(|field| field.name);
+
+  let name = Signal::derive(move || {
+    name
+      .get()
+      .or_else(|| field_name.map(|name| name.get()))
+      .expect("FormValidityState must be used in a FormField component or given a name")
+  });
+
+  move || children(context.field_validity(&name.get()))
+}
+
+#[component]
+pub fn FormSubmit(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let mut merged_attrs = vec![("type", "submit".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::button
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+pub(crate) fn use_form_field_name() -> Option<Signal<String>> {
+  use_context::<FormFieldContextValue>().map(|field| field.name)
+}
+
+pub(crate) fn use_form_control_attrs() -> Attributes {
+  let (Some(context), Some(field)) = (
+    use_context::<FormContextValue>(),
+    use_context::<FormFieldContextValue>(),
+  ) else {
+    return vec![];
+  };
+
+  let FormFieldContextValue {
+    id,
+    name,
+    server_invalid,
+  } = field;
+
+  let validity = Signal::derive(move || context.field_validity(&name.get()));
+
+  vec![
+    ("id", (move || id.get()).into_attribute()),
+    (
+      "aria-invalid",
+      (move || get_is_invalid(validity.get(), server_invalid.get()).then_some("true"))
+        .into_attribute(),
+    ),
+    (
+      "aria-describedby",
+      (move || context.field_description(&name.get())).into_attribute(),
+    ),
+    (
+      "data-valid",
+      (move || get_is_valid(validity.get(), server_invalid.get())).into_attribute(),
+    ),
+    (
+      "data-invalid",
+      (move || get_is_invalid(validity.get(), server_invalid.get())).into_attribute(),
+    ),
+  ]
+}
+
+fn custom_matcher_matches(validity: Option<ValidityState>, has_custom_error: bool) -> bool {
+  has_custom_error && validity.is_some_and(|validity| !validity.has_built_in_error())
+}
+
+fn get_is_valid(validity: Option<ValidityState>, server_invalid: bool) -> bool {
+  validity.is_some_and(|validity| validity.valid) && !server_invalid
+}
+
+fn get_is_invalid(validity: Option<ValidityState>, server_invalid: bool) -> bool {
+  server_invalid || validity.is_some_and(|validity| !validity.valid)
+}
+
+#[derive(Clone)]
+enum FormControlElement {
+  Input(HtmlInputElement),
+  Select(HtmlSelectElement),
+  TextArea(HtmlTextAreaElement),
+}
+
+impl FormControlElement {
+  fn from_target(target: web_sys::EventTarget) -> Option<Self> {
+    Self::from_element(target.dyn_ref::<Element>()?)
+  }
+
+  fn from_element(element: &Element) -> Option<Self> {
+    if let Some(input) = element.dyn_ref::<HtmlInputElement>() {
+      Some(Self::Input(input.clone()))
+    } else if let Some(select) = element.dyn_ref::<HtmlSelectElement>() {
+      Some(Self::Select(select.clone()))
+    } else {
+      element
+        .dyn_ref::<HtmlTextAreaElement>()
+        .map(|textarea| Self::TextArea(textarea.clone()))
+    }
+  }
+
+  fn validity(&self) -> ValidityState {
+    match self {
+      Self::Input(input) => input.validity(),
+      Self::Select(select) => select.validity(),
+      Self::TextArea(textarea) => textarea.validity(),
+    }
+    .into()
+  }
+
+  fn set_custom_validity(&self, message: &str) {
+    match self {
+      Self::Input(input) => input.set_custom_validity(message),
+      Self::Select(select) => select.set_custom_validity(message),
+      Self::TextArea(textarea) => textarea.set_custom_validity(message),
+    }
+  }
+
+  fn value(&self) -> String {
+    match self {
+      Self::Input(input) => input.value(),
+      Self::Select(select) => select.value(),
+      Self::TextArea(textarea) => textarea.value(),
+    }
+  }
+
+  fn form(&self) -> Option<HtmlFormElement> {
+    match self {
+      Self::Input(input) => input.form(),
+      Self::Select(select) => select.form(),
+      Self::TextArea(textarea) => textarea.form(),
+    }
+  }
+
+  fn is_invalid(&self) -> bool {
+    let element: &Element = self.as_ref();
+
+    !self.validity().valid || element.get_attribute("aria-invalid").as_deref() == Some("true")
+  }
+
+  fn focus(&self) {
+    match self {
+      Self::Input(input) => _ = input.focus(),
+      Self::Select(select) => _ = select.focus(),
+      Self::TextArea(textarea) => _ = textarea.focus(),
+    }
+  }
+
+  fn is_same_node(&self, element: &Element) -> bool {
+    let own: &Element = self.as_ref();
+
+    own.is_same_node(Some(element))
+  }
+}
+
+impl AsRef<Element> for FormControlElement {
+  fn as_ref(&self) -> &Element {
+    match self {
+      Self::Input(input) => input,
+      Self::Select(select) => select,
+      Self::TextArea(textarea) => textarea,
+    }
+  }
+}
+
+fn get_form_controls(form: &HtmlFormElement) -> Vec<FormControlElement> {
+  let elements = form.elements();
+
+  (0..elements.length())
+    .filter_map(|index| FormControlElement::from_element(&elements.item(index)?))
+    .collect()
+}
+
+fn get_first_invalid_control(form: &HtmlFormElement) -> Option<FormControlElement> {
+  get_form_controls(form)
+    .into_iter()
+    .find(|control| control.is_invalid())
+}
+
+#[test]
+fn built_in_errors_exclude_custom_errors_and_validity() {
+  let custom_error = ValidityState {
+    custom_error: true,
+    ..Default::default()
+  };
+  let value_missing = ValidityState {
+    value_missing: true,
+    ..Default::default()
+  };
+
+  assert!(!custom_error.has_built_in_error());
+  assert!(value_missing.has_built_in_error());
+  assert!(ValidityMatcher::ValueMissing.matches(&value_missing));
+  assert!(!ValidityMatcher::TooShort.matches(&value_missing));
+  assert_eq!(ValidityMatcher::Valid.default_message(), None);
+}
+
+#[test]
+fn custom_errors_only_match_when_no_built_in_error_is_present() {
+  let runtime = create_runtime();
+  let context = FormContextValue::new();
+
+  let custom_invalid = ValidityState {
+    custom_error: true,
+    ..Default::default()
+  };
+
+  context.record_field_result(
+    "email",
+    custom_invalid,
+    HashMap::from([("taken".into(), true), ("banned".into(), false)]),
+  );
+
+  assert!(context.field_custom_error("email", "taken"));
+  assert!(!context.field_custom_error("email", "banned"));
+  assert!(!context.field_custom_error("name", "taken"));
+  assert!(custom_matcher_matches(
+    context.field_validity("email"),
+    true
+  ));
+
+  let value_missing = ValidityState {
+    value_missing: true,
+    ..Default::default()
+  };
+
+  assert!(!custom_matcher_matches(Some(value_missing), true));
+  assert!(!custom_matcher_matches(None, true));
+
+  runtime.dispose();
+}
+
+#[test]
+fn stale_async_validation_runs_are_discarded() {
+  let runtime = create_runtime();
+  let context = FormContextValue::new();
+
+  let first_run = context.next_validation_run("username");
+  let second_run = context.next_validation_run("username");
+
+  assert!(!context.is_current_validation_run("username", first_run));
+  assert!(context.is_current_validation_run("username", second_run));
+
+  context.record_field_result(
+    "username",
+    ValidityState::default(),
+    HashMap::from([("available".into(), true)]),
+  );
+  context.clear_field("username");
+
+  assert!(!context.is_current_validation_run("username", second_run));
+  assert_eq!(context.field_validity("username"), None);
+  assert!(!context.field_custom_error("username", "available"));
+
+  runtime.dispose();
+}
+
+#[test]
+fn server_errors_override_browser_validity() {
+  let valid = ValidityState {
+    valid: true,
+    ..Default::default()
+  };
+
+  assert!(get_is_valid(Some(valid), false));
+  assert!(!get_is_invalid(Some(valid), false));
+  assert!(!get_is_valid(Some(valid), true));
+  assert!(get_is_invalid(Some(valid), true));
+  assert!(get_is_invalid(None, true));
+  assert!(!get_is_valid(None, false));
+  assert!(!get_is_invalid(None, false));
+}
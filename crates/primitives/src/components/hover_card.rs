@@ -0,0 +1,469 @@
+use std::time::Duration;
+
+use leptos::{
+  ev::{pointerdown, pointerenter, pointerleave, pointerup},
+  html::AnyElement,
+  leptos_dom::helpers::TimeoutHandle,
+  *,
+};
+use leptos_use::{use_document, use_event_listener};
+use wasm_bindgen::JsCast;
+use web_sys::{FocusEvent, KeyboardEvent, Node, PointerEvent, TouchEvent};
+
+use crate::{
+  dismissable_layer::DismissableLayer,
+  focus_scope::get_tabbable_candidates,
+  popper::{
+    Align, PopperAnchor, PopperArrow, PopperArrowProps, PopperContent, PopperRoot, Side, Sticky,
+  },
+  portal::{Portal, PortalContainer, PortalProps},
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    Attributes,
+  },
+};
+
+#[derive(Clone)]
+struct HoverCardContextValue {
+  open: Signal<bool>,
+  on_open: Callback<()>,
+  on_close: Callback<()>,
+  on_dismiss: Callback<()>,
+  has_selection: StoredValue<bool>,
+  is_pointer_down_on_content: StoredValue<bool>,
+}
+
+#[component]
+pub fn HoverCardRoot(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(default=700.into(), into)] open_delay: MaybeSignal<u64>,
+  #[prop(default=300.into(), into)] close_delay: MaybeSignal<u64>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let open_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let close_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let has_selection = StoredValue::new(false);
+  let is_pointer_down_on_content = StoredValue::new(false);
+
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let clear_timer = move |timer: StoredValue<Option<TimeoutHandle>>| {
+    if let Some(handle) = timer.get_value() {
+      handle.clear();
+      timer.set_value(None);
+    }
+  };
+
+  on_cleanup(move || {
+    clear_timer(open_timer);
+    clear_timer(close_timer);
+  });
+
+  provide_context(HoverCardContextValue {
+    open: Signal::derive(move || open.get().unwrap_or(false)),
+    on_open: Callback::new(move |_| {
+      clear_timer(close_timer);
+      clear_timer(open_timer);
+
+      if let Ok(handle) = set_timeout_with_handle(
+        move || set_open.set(true),
+        Duration::from_millis(open_delay.get_untracked()),
+      ) {
+        open_timer.set_value(Some(handle));
+      }
+    }),
+    on_close: Callback::new(move |_| {
+      clear_timer(open_timer);
+
+      if has_selection.get_value() || is_pointer_down_on_content.get_value() {
+        return;
+      }
+
+      clear_timer(close_timer);
+
+      if let Ok(handle) = set_timeout_with_handle(
+        move || set_open.set(false),
+        Duration::from_millis(close_delay.get_untracked()),
+      ) {
+        close_timer.set_value(Some(handle));
+      }
+    }),
+    on_dismiss: Callback::new(move |_| {
+      clear_timer(open_timer);
+      clear_timer(close_timer);
+      set_open.set(false);
+    }),
+    has_selection,
+    is_pointer_down_on_content,
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub fn HoverCardTrigger(
+  #[prop(default=(|_|{}).into(), into)] on_pointer_enter: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_leave: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus: Callback<FocusEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_blur: Callback<FocusEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_touch_start: Callback<TouchEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let HoverCardContextValue {
+    open,
+    on_open,
+    on_close,
+    ..
+  } = use_context().expect("HoverCardTrigger must be used in a HoverCardRoot component");
+
+  let mut merged_attrs = vec![(
+    "data-state",
+    (move || get_state(open.get())).into_attribute(),
+  )];
+
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <PopperAnchor as_child=true node_ref=node_ref>
+      <Primitive
+        element=html::a
+        on:pointerenter=move |ev: PointerEvent| {
+          on_pointer_enter.call(ev.clone());
+
+          if ev.pointer_type() != "touch" {
+            on_open.call(());
+          }
+        }
+        on:pointerleave=move |ev: PointerEvent| {
+          on_pointer_leave.call(ev.clone());
+
+          if ev.pointer_type() != "touch" {
+            on_close.call(());
+          }
+        }
+        on:focus=move |ev: FocusEvent| {
+          on_focus.call(ev);
+          on_open.call(());
+        }
+        on:blur=move |ev: FocusEvent| {
+          on_blur.call(ev);
+          on_close.call(());
+        }
+        on:touchstart=move |ev: TouchEvent| {
+          on_touch_start.call(ev.clone());
+          ev.prevent_default();
+        }
+        node_ref=node_ref
+        attrs=merged_attrs.clone()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </PopperAnchor>
+  }
+}
+
+#[derive(Clone)]
+struct HoverCardPortalContextValue {
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub fn HoverCardPortal(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(HoverCardPortalContextValue {
+    force_mount: Signal::derive(move || force_mount.get()),
+  });
+
+  Portal(PortalProps {
+    container,
+    children,
+  })
+}
+
+#[component]
+pub fn HoverCardContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_pointer_enter: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_leave: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let HoverCardContextValue {
+    open,
+    on_open,
+    on_close,
+    on_dismiss,
+    has_selection,
+    is_pointer_down_on_content,
+    ..
+  } = use_context().expect("HoverCardContent must be used in a HoverCardRoot component");
+  let portal_force_mount = use_context::<HoverCardPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  let is_present =
+    Signal::derive(move || force_mount.get() || portal_force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+  let layer_ref = NodeRef::<AnyElement>::new();
+
+  let contain_selection = RwSignal::new(false);
+  let original_body_user_select = StoredValue::<Option<String>>::new(None);
+
+  Effect::new(move |_| {
+    let Some(body) = document().body() else {
+      return;
+    };
+
+    if contain_selection.get() {
+      original_body_user_select.set_value(body.style().get_property_value("user-select").ok());
+      _ = body.style().set_property("user-select", "none");
+    } else if let Some(user_select) = original_body_user_select.get_value() {
+      _ = body.style().set_property("user-select", &user_select);
+      original_body_user_select.set_value(None);
+    }
+  });
+
+  on_cleanup(move || {
+    let Some(Some(user_select)) = original_body_user_select.try_get_value() else {
+      return;
+    };
+
+    let Some(body) = document().body() else {
+      return;
+    };
+
+    _ = body.style().set_property("user-select", &user_select);
+  });
+
+  _ = use_event_listener(node_ref, pointerenter, move |ev: PointerEvent| {
+    on_pointer_enter.call(ev.clone());
+
+    if ev.pointer_type() != "touch" {
+      on_open.call(());
+    }
+  });
+
+  _ = use_event_listener(node_ref, pointerleave, move |ev: PointerEvent| {
+    on_pointer_leave.call(ev.clone());
+
+    if ev.pointer_type() != "touch" {
+      on_close.call(());
+    }
+  });
+
+  _ = use_event_listener(node_ref, pointerdown, move |ev: PointerEvent| {
+    on_pointer_down.call(ev.clone());
+
+    let target = ev
+      .target()
+      .and_then(|target| target.dyn_into::<Node>().ok());
+    let is_inside = node_ref
+      .get_untracked()
+      .map(|node| node.contains(target.as_ref()))
+      .unwrap_or(false);
+
+    if is_inside {
+      contain_selection.set(true);
+      is_pointer_down_on_content.set_value(true);
+    }
+  });
+
+  _ = use_event_listener(use_document(), pointerup, move |_| {
+    contain_selection.set(false);
+    is_pointer_down_on_content.set_value(false);
+
+    set_timeout(
+      move || {
+        let has_selected_text = document()
+          .get_selection()
+          .ok()
+          .flatten()
+          .and_then(|selection| selection.to_string().as_string())
+          .map(|selection| !selection.is_empty())
+          .unwrap_or(false);
+
+        _ = has_selection.try_set_value(has_selected_text);
+      },
+      Duration::ZERO,
+    );
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    for tabbable in get_tabbable_candidates(&node) {
+      _ = tabbable.set_attribute("tabindex", "-1");
+    }
+
+    _ = node
+      .style("user-select", move || {
+        contain_selection.get().then_some("text")
+      })
+      .style(
+        "--leptix-hover-card-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-hover-card-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-hover-card-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-hover-card-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-hover-card-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let mut merged_attrs = vec![(
+    "data-state",
+    (move || get_state(open.get())).into_attribute(),
+  )];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let collision_boundary = StoredValue::new(collision_boundary);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <DismissableLayer
+        as_child=true
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        on_focus_outside=Callback::new(move |ev: FocusEvent| {
+          on_focus_outside.call(ev.clone());
+          ev.prevent_default();
+        })
+        on_dismiss=on_dismiss
+        node_ref=layer_ref
+      >
+        <PopperContent
+          side=side
+          side_offset=side_offset
+          align=align
+          align_offset=align_offset
+          arrow_padding=arrow_padding
+          avoid_collisions=avoid_collisions
+          collision_boundary=collision_boundary.get_value()
+          collision_padding=collision_padding
+          sticky=sticky
+          hide_when_detached=hide_when_detached
+          node_ref=node_ref
+          attrs=merged_attrs.get_value()
+          as_child=as_child
+        >
+          {children.with_value(|children| children())}
+        </PopperContent>
+      </DismissableLayer>
+    </Show>
+  }
+}
+
+#[component]
+pub fn HoverCardArrow(
+  #[prop(default=10.0f64.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0f64.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+  PopperArrow(PopperArrowProps {
+    width,
+    height,
+    attrs,
+    children,
+  })
+}
+
+fn get_state(open: bool) -> &'static str {
+  if open {
+    "open"
+  } else {
+    "closed"
+  }
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn open_hover_card_renders_and_cleans_up_on_the_server() {
+  let runtime = create_runtime();
+  let render_count = RwSignal::new(0);
+
+  // re-running the memo disposes the previous render, which runs its cleanups
+  let html = Memo::new(move |_| {
+    render_count.track();
+
+    view! {
+      <HoverCardRoot default_open=true>
+        <HoverCardTrigger>"@leptix"</HoverCardTrigger>
+        <HoverCardContent>"Profile"</HoverCardContent>
+      </HoverCardRoot>
+    }
+    .into_view()
+    .render_to_string()
+    .to_string()
+  });
+
+  assert!(html.get_untracked().contains("Profile"));
+
+  render_count.set(1);
+  assert!(html.get_untracked().contains("Profile"));
+
+  runtime.dispose();
+}
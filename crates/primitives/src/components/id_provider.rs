@@ -0,0 +1,10 @@
+use leptos::*;
+
+use crate::util::use_id::IdContextValue;
+
+#[component]
+pub fn IdProvider(#[prop(into)] prefix: String, children: Children) -> impl IntoView {
+  provide_context(IdContextValue { prefix });
+
+  children()
+}
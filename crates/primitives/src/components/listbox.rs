@@ -0,0 +1,729 @@
+use std::{collections::HashMap, time::Duration};
+
+use leptos::{html::AnyElement, leptos_dom::helpers::TimeoutHandle, *};
+use web_sys::{
+  FocusEvent, KeyboardEvent, MouseEvent, ScrollIntoViewOptions, ScrollLogicalPosition,
+};
+
+use crate::{
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  menu::get_next_match,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    form_data::get_form_field_name,
+    use_id::{create_id, create_part_id},
+    Attributes,
+  },
+  Orientation,
+};
+
+pub enum ListboxKind {
+  Single {
+    value: MaybeProp<String>,
+    default_value: MaybeProp<String>,
+    on_value_change: Option<Callback<String>>,
+  },
+  Multiple {
+    value: MaybeProp<Vec<String>>,
+    default_value: MaybeProp<Vec<String>>,
+    on_value_change: Option<Callback<Vec<String>>>,
+  },
+}
+
+pub struct ListboxSingle;
+pub struct ListboxMultiple;
+
+impl ListboxSingle {
+  pub fn none() -> Option<String> {
+    None
+  }
+}
+
+impl ListboxMultiple {
+  pub fn none() -> Option<Vec<String>> {
+    None
+  }
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum ListboxFocusStrategy {
+  #[default]
+  RovingTabIndex,
+  ActiveDescendant,
+}
+
+#[derive(Clone)]
+struct ListboxItemData {
+  value: String,
+  disabled: Signal<bool>,
+  text_value: Option<String>,
+  id: Signal<String>,
+}
+
+impl std::fmt::Debug for ListboxItemData {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("ListboxItemData")
+      .field("value", &self.value)
+      .field("text_value", &self.text_value)
+      .finish()
+  }
+}
+
+impl PartialEq for ListboxItemData {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
+impl Eq for ListboxItemData {}
+
+impl Ord for ListboxItemData {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.value.cmp(&other.value)
+  }
+}
+
+impl PartialOrd for ListboxItemData {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[derive(Clone, Copy)]
+struct ListboxContextValue {
+  value: Signal<Vec<String>>,
+  on_item_select: Callback<(String, SelectionModifier)>,
+  multiple: bool,
+  focus_strategy: Signal<ListboxFocusStrategy>,
+  highlighted: RwSignal<Option<String>>,
+  tab_stop: Signal<Option<String>>,
+  disabled: Signal<bool>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SelectionModifier {
+  Toggle,
+  Range,
+}
+
+#[component]
+pub fn ListboxRoot(
+  kind: ListboxKind,
+
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] focus_strategy: MaybeSignal<ListboxFocusStrategy>,
+  #[prop(default=Orientation::Vertical.into(), into)] orientation: MaybeSignal<Orientation>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (value, set_value, multiple) = match kind {
+    ListboxKind::Single {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      (
+        Signal::derive(move || value.get().into_iter().collect::<Vec<_>>()),
+        Callback::new(move |values: Vec<String>| {
+          set_value.set(values.into_iter().next().unwrap_or_default());
+        }),
+        false,
+      )
+    }
+    ListboxKind::Multiple {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      (
+        Signal::derive(move || value.get().unwrap_or_default()),
+        Callback::new(move |values: Vec<String>| {
+          set_value.set(values);
+        }),
+        true,
+      )
+    }
+  };
+
+  provide_context(CollectionContextValue::<ListboxItemData, AnyElement> {
+    collection_ref: node_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  let get_items = use_collection_context::<ListboxItemData, AnyElement>();
+  let get_enabled_items = move || {
+    get_items
+      .get_untracked()
+      .into_iter()
+      .filter(|(_, data)| !data.disabled.get_untracked())
+      .collect::<Vec<_>>()
+  };
+
+  let id = create_id(id);
+  let highlighted = RwSignal::new(None::<String>);
+  let anchor = StoredValue::new(None::<String>);
+  let disabled = Signal::derive(move || disabled.get());
+  let focus_strategy = Signal::derive(move || focus_strategy.get());
+
+  let tab_stop = Signal::derive(move || {
+    let items = get_items.get();
+
+    highlighted
+      .get()
+      .filter(|highlighted| items.iter().any(|(_, data)| data.value == *highlighted))
+      .or_else(|| {
+        value.with(|value| {
+          items
+            .iter()
+            .find(|(_, data)| value.contains(&data.value))
+            .map(|(_, data)| data.value.clone())
+        })
+      })
+      .or_else(|| {
+        items
+          .iter()
+          .find(|(_, data)| !data.disabled.get())
+          .map(|(_, data)| data.value.clone())
+      })
+  });
+
+  let select_range = move |target: &str| {
+    let values = get_enabled_items()
+      .into_iter()
+      .map(|(_, data)| data.value)
+      .collect::<Vec<_>>();
+    let anchor = anchor.get_value().unwrap_or_else(|| target.to_string());
+
+    set_value.call(get_range_values(&values, &anchor, target));
+  };
+
+  let on_item_select = Callback::new(move |(item_value, modifier): (String, SelectionModifier)| {
+    if !multiple {
+      set_value.call(vec![item_value.clone()]);
+      anchor.set_value(Some(item_value));
+      return;
+    }
+
+    if modifier == SelectionModifier::Range {
+      select_range(&item_value);
+      return;
+    }
+
+    let mut values = value.get_untracked();
+
+    if let Some(index) = values.iter().position(|value| *value == item_value) {
+      values.remove(index);
+    } else {
+      values.push(item_value.clone());
+    }
+
+    set_value.call(values);
+    anchor.set_value(Some(item_value));
+  });
+
+  let highlight = move |(item_ref, data): &(NodeRef<AnyElement>, ListboxItemData)| {
+    highlighted.set(Some(data.value.clone()));
+
+    let Some(node) = item_ref.get_untracked() else {
+      return;
+    };
+
+    if focus_strategy.get_untracked() == ListboxFocusStrategy::RovingTabIndex {
+      _ = node.focus();
+    } else {
+      let mut options = ScrollIntoViewOptions::new();
+      options.block(ScrollLogicalPosition::Nearest);
+
+      node.scroll_into_view_with_scroll_into_view_options(&options);
+    }
+  };
+
+  let search = StoredValue::new(String::new());
+  let search_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  let handle_typeahead_search = move |key: String| {
+    let search_value = format!("{}{key}", search.get_value());
+    search.set_value(search_value.clone());
+
+    if let Some(handle) = search_timer.get_value() {
+      handle.clear();
+    }
+
+    if let Ok(handle) = set_timeout_with_handle(
+      move || _ = search.try_set_value(String::new()),
+      Duration::from_millis(1000),
+    ) {
+      search_timer.set_value(Some(handle));
+    }
+
+    let items = get_enabled_items();
+    let text_values = items
+      .iter()
+      .map(|(node, data)| get_item_text_value(*node, data))
+      .collect::<Vec<_>>();
+
+    let current_value = highlighted.get_untracked();
+    let current_index = items
+      .iter()
+      .position(|(_, data)| Some(&data.value) == current_value.as_ref());
+
+    if let Some(next_index) = get_next_match(&text_values, &search_value, current_index) {
+      highlight(&items[next_index]);
+    }
+  };
+
+  let mut merged_attrs = vec![
+    ("id", (move || id.get()).into_attribute()),
+    ("role", "listbox".into_attribute()),
+    (
+      "aria-multiselectable",
+      multiple.then_some("true").into_attribute(),
+    ),
+    (
+      "aria-orientation",
+      (move || orientation.get().to_string()).into_attribute(),
+    ),
+    (
+      "aria-disabled",
+      (move || disabled.get().then_some("true")).into_attribute(),
+    ),
+    (
+      "aria-activedescendant",
+      (move || {
+        if focus_strategy.get() != ListboxFocusStrategy::ActiveDescendant {
+          return None;
+        }
+
+        let highlighted = highlighted.get()?;
+
+        get_items.with(|items| {
+          items
+            .iter()
+            .find(|(_, data)| data.value == highlighted)
+            .map(|(_, data)| data.id.get())
+        })
+      })
+      .into_attribute(),
+    ),
+    (
+      "tabindex",
+      (move || {
+        if focus_strategy.get() == ListboxFocusStrategy::ActiveDescendant && !disabled.get() {
+          0
+        } else {
+          -1
+        }
+      })
+      .into_attribute(),
+    ),
+    (
+      "data-orientation",
+      (move || orientation.get().to_string()).into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  provide_context(ListboxContextValue {
+    value,
+    on_item_select,
+    multiple,
+    focus_strategy,
+    highlighted,
+    tab_stop,
+    disabled,
+  });
+
+  view! {
+    <Primitive
+      element=html::div
+      on:focus=move |ev: FocusEvent| {
+        on_focus.call(ev.clone());
+
+        if focus_strategy.get_untracked() != ListboxFocusStrategy::ActiveDescendant
+          || highlighted.get_untracked().is_some()
+        {
+          return;
+        }
+
+        if let Some(tab_stop) = tab_stop.get_untracked() {
+          highlighted.set(Some(tab_stop));
+        }
+      }
+      on:keydown=move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        if ev.default_prevented() || disabled.get_untracked() {
+          return;
+        }
+
+        let key = ev.key();
+        let is_modifier_key = ev.ctrl_key() || ev.meta_key();
+        let items = get_enabled_items();
+
+        if items.is_empty() {
+          return;
+        }
+
+        let current_index = highlighted.get_untracked().and_then(|highlighted| {
+          items
+            .iter()
+            .position(|(_, data)| data.value == highlighted)
+        });
+
+        let (next_key, previous_key) = match orientation.get_untracked() {
+          Orientation::Vertical => ("ArrowDown", "ArrowUp"),
+          Orientation::Horizontal => ("ArrowRight", "ArrowLeft"),
+        };
+
+        let next_index = match key.as_str() {
+          key if key == next_key => Some(match current_index {
+            Some(index) if index + 1 < items.len() => index + 1,
+            Some(index) if !should_loop.get_untracked() => index,
+            Some(_) => 0,
+            None => 0,
+          }),
+          key if key == previous_key => Some(match current_index {
+            Some(0) if should_loop.get_untracked() => items.len() - 1,
+            Some(index) => index.saturating_sub(1),
+            None => items.len() - 1,
+          }),
+          "Home" => Some(0),
+          "End" => Some(items.len() - 1),
+          _ => None,
+        };
+
+        if let Some(next_index) = next_index {
+          ev.prevent_default();
+
+          let item = &items[next_index];
+          highlight(item);
+
+          let is_range_key = key.starts_with("Arrow") && ev.shift_key();
+          let is_range_jump = (key == "Home" || key == "End") && ev.shift_key() && is_modifier_key;
+
+          if multiple && (is_range_key || is_range_jump) {
+            if anchor.get_value().is_none() {
+              anchor.set_value(current_index.map(|index| items[index].1.value.clone()));
+            }
+
+            select_range(&item.1.value);
+          }
+
+          return;
+        }
+
+        match key.as_str() {
+          " " | "Enter" => {
+            ev.prevent_default();
+
+            if let Some(index) = current_index {
+              let modifier = if ev.shift_key() {
+                SelectionModifier::Range
+              } else {
+                SelectionModifier::Toggle
+              };
+
+              on_item_select.call((items[index].1.value.clone(), modifier));
+            }
+          }
+          "a" | "A" if multiple && is_modifier_key => {
+            ev.prevent_default();
+
+            let enabled_values = items
+              .into_iter()
+              .map(|(_, data)| data.value)
+              .collect::<Vec<_>>();
+            let is_all_selected = value.with_untracked(|value| {
+              enabled_values.iter().all(|enabled| value.contains(enabled))
+            });
+
+            set_value.call(if is_all_selected { vec![] } else { enabled_values });
+          }
+          key if key.chars().count() == 1 && !is_modifier_key && !ev.alt_key() => {
+            handle_typeahead_search(key.to_string());
+          }
+          _ => {}
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+
+    {move || {
+      name.get().map(|name| {
+        let name = get_form_field_name(&name, multiple);
+
+        value
+          .get()
+          .into_iter()
+          .map(|value| view! {
+            <input
+              type="hidden"
+              name=name.clone()
+              value=value
+              disabled=disabled.get()
+            />
+          })
+          .collect_view()
+      })
+    }}
+  }
+}
+
+#[component]
+pub fn ListboxItem(
+  #[prop(into)] value: String,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] id: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ListboxContextValue {
+    value: selected_values,
+    on_item_select,
+    multiple,
+    focus_strategy,
+    highlighted,
+    tab_stop,
+    disabled: root_disabled,
+  } = use_context().expect("ListboxItem must be used in a ListboxRoot component");
+
+  let item_id = create_id(id);
+  let disabled = Signal::derive(move || disabled.get() || root_disabled.get());
+  let value = StoredValue::new(value);
+
+  use_collection_item_ref::<AnyElement, ListboxItemData>(
+    node_ref,
+    ListboxItemData {
+      value: value.get_value(),
+      disabled,
+      text_value: text_value.get_untracked(),
+      id: item_id,
+    },
+  );
+
+  let is_selected = Signal::derive(move || {
+    value.with_value(|value| selected_values.with(|values| values.contains(value)))
+  });
+  let is_highlighted =
+    Signal::derive(move || value.with_value(|value| highlighted.get().as_ref() == Some(value)));
+
+  let mut merged_attrs = vec![
+    ("id", (move || item_id.get()).into_attribute()),
+    ("role", "option".into_attribute()),
+    (
+      "aria-selected",
+      (move || is_selected.get()).into_attribute(),
+    ),
+    (
+      "aria-disabled",
+      (move || disabled.get().then_some("true")).into_attribute(),
+    ),
+    (
+      "tabindex",
+      (move || {
+        (focus_strategy.get() == ListboxFocusStrategy::RovingTabIndex).then(|| {
+          if !disabled.get() && value.with_value(|value| tab_stop.get().as_ref() == Some(value)) {
+            0
+          } else {
+            -1
+          }
+        })
+      })
+      .into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || {
+        if is_selected.get() {
+          "checked"
+        } else {
+          "unchecked"
+        }
+      })
+      .into_attribute(),
+    ),
+    (
+      "data-highlighted",
+      (move || is_highlighted.get().then_some("")).into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      on:focus=move |ev: FocusEvent| {
+        on_focus.call(ev);
+
+        if !disabled.get_untracked() {
+          highlighted.set(Some(value.get_value()));
+        }
+      }
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev.clone());
+
+        if ev.default_prevented() || disabled.get_untracked() {
+          return;
+        }
+
+        let modifier = if multiple && ev.shift_key() {
+          SelectionModifier::Range
+        } else {
+          SelectionModifier::Toggle
+        };
+
+        highlighted.set(Some(value.get_value()));
+        on_item_select.call((value.get_value(), modifier));
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[derive(Clone, Copy)]
+struct ListboxGroupContextValue {
+  label_id: Signal<String>,
+}
+
+#[component]
+pub fn ListboxGroup(
+  #[prop(optional, into)] id: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let label_id = create_part_id(create_id(id.clone()), "label");
+
+  provide_context(ListboxGroupContextValue { label_id });
+
+  let mut merged_attrs = vec![
+    ("id", id.into_attribute()),
+    ("role", "group".into_attribute()),
+    ("aria-labelledby", (move || label_id.get()).into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ListboxGroupLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ListboxGroupContextValue { label_id } =
+    use_context().expect("ListboxGroupLabel must be used in a ListboxGroup component");
+
+  let mut merged_attrs = vec![("id", (move || label_id.get()).into_attribute())];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+fn get_item_text_value(node: NodeRef<AnyElement>, data: &ListboxItemData) -> String {
+  data.text_value.clone().unwrap_or_else(|| {
+    node
+      .get_untracked()
+      .and_then(|node| node.text_content())
+      .unwrap_or_default()
+  })
+}
+
+fn get_range_values(values: &[String], anchor: &str, target: &str) -> Vec<String> {
+  let target_index = values.iter().position(|value| value == target);
+  let anchor_index = values
+    .iter()
+    .position(|value| value == anchor)
+    .or(target_index);
+
+  let (Some(anchor_index), Some(target_index)) = (anchor_index, target_index) else {
+    return vec![];
+  };
+
+  values[anchor_index.min(target_index)..=anchor_index.max(target_index)].to_vec()
+}
+
+#[test]
+fn range_selection_spans_anchor_and_target_in_either_direction() {
+  let values = ["a", "b", "c", "d", "e"].map(String::from).to_vec();
+
+  assert_eq!(get_range_values(&values, "b", "d"), ["b", "c", "d"]);
+  assert_eq!(get_range_values(&values, "d", "b"), ["b", "c", "d"]);
+  assert_eq!(get_range_values(&values, "missing", "c"), ["c"]);
+  assert!(get_range_values(&values, "a", "missing").is_empty());
+}
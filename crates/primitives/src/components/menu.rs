@@ -0,0 +1,1616 @@
+use std::{collections::HashMap, time::Duration};
+
+use leptos::{
+  ev::{keydown, pointerdown, pointermove},
+  html::AnyElement,
+  leptos_dom::helpers::TimeoutHandle,
+  *,
+};
+use leptos_use::{
+  use_document, use_event_listener, use_event_listener_with_options, UseEventListenerOptions,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{
+  CustomEvent, CustomEventInit, Event, FocusEvent, KeyboardEvent, MouseEvent, Node, PointerEvent,
+};
+
+use crate::{
+  checkbox::CheckedState,
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  dismissable_layer::DismissableLayer,
+  focus_scope::{focus_first, FocusScope},
+  popper::{
+    Align, PopperAnchor, PopperAnchorProps, PopperArrow, PopperArrowProps, PopperContent,
+    PopperRoot, Rect, Side, Sticky,
+  },
+  portal::{Portal, PortalContainer, PortalProps},
+  presence::create_presence,
+  primitive::Primitive,
+  roving_focus::{RovingFocusGroup, RovingFocusGroupItem},
+  util::{
+    hide_others::{create_hide_others, HideOthersMode},
+    polygon::{is_point_in_polygon, Point},
+    scroll_lock::create_scroll_lock,
+    use_id::{create_id, create_part_id},
+    Attributes,
+  },
+  Direction, Orientation,
+};
+
+const ITEM_SELECT: &str = "menu.item_select";
+const SELECTION_KEYS: [&str; 2] = ["Enter", " "];
+const FIRST_KEYS: [&str; 3] = ["ArrowDown", "PageUp", "Home"];
+const LAST_KEYS: [&str; 3] = ["ArrowUp", "PageDown", "End"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MenuItemData {
+  disabled: Signal<bool>,
+  text_value: Option<String>,
+}
+
+impl Ord for MenuItemData {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.text_value.cmp(&other.text_value)
+  }
+}
+
+impl PartialOrd for MenuItemData {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[derive(Clone)]
+struct MenuContextValue {
+  open: Signal<bool>,
+  on_open_change: Callback<bool>,
+  content_ref: NodeRef<AnyElement>,
+}
+
+#[derive(Clone)]
+struct MenuRootContextValue {
+  on_close: Callback<()>,
+  is_using_keyboard: StoredValue<bool>,
+  direction: Signal<Direction>,
+  modal: Signal<bool>,
+}
+
+#[component]
+pub(crate) fn Menu(
+  #[prop(into)] open: MaybeSignal<bool>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(default=true.into(), into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let is_using_keyboard = StoredValue::new(false);
+
+  _ = use_event_listener_with_options(
+    use_document(),
+    keydown,
+    move |_| is_using_keyboard.set_value(true),
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  _ = use_event_listener_with_options(
+    use_document(),
+    pointerdown,
+    move |_| is_using_keyboard.set_value(false),
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  _ = use_event_listener_with_options(
+    use_document(),
+    pointermove,
+    move |_| is_using_keyboard.set_value(false),
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  provide_context(MenuContextValue {
+    open: Signal::derive(move || open.get()),
+    on_open_change,
+    content_ref: NodeRef::new(),
+  });
+
+  provide_context(MenuRootContextValue {
+    on_close: Callback::new(move |_| on_open_change.call(false)),
+    is_using_keyboard,
+    direction: Signal::derive(move || direction.get().unwrap_or_default()),
+    modal: Signal::derive(move || modal.get()),
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub(crate) fn MenuAnchor(
+  #[prop(optional, into)] virtual_ref: MaybeProp<Rect>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  PopperAnchor(PopperAnchorProps {
+    virtual_ref,
+    node_ref,
+    attrs,
+    children,
+    as_child,
+  })
+}
+
+#[derive(Clone)]
+struct MenuPortalContextValue {
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub(crate) fn MenuPortal(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(MenuPortalContextValue {
+    force_mount: Signal::derive(move || force_mount.get()),
+  });
+
+  Portal(PortalProps {
+    container,
+    children,
+  })
+}
+
+#[component]
+pub(crate) fn MenuContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuContextValue {
+    open,
+    on_open_change,
+    ..
+  } = use_context().expect("MenuContent must be used in a Menu component");
+  let MenuRootContextValue { modal, .. } =
+    use_context().expect("MenuContent must be used in a Menu component");
+  let portal_force_mount = use_context::<MenuPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  let is_present =
+    Signal::derive(move || force_mount.get() || portal_force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+
+  let attrs = StoredValue::new(attrs);
+  let collision_boundary = StoredValue::new(collision_boundary);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <MenuContentImpl
+        should_loop=should_loop
+        side=side
+        side_offset=side_offset
+        align=align
+        align_offset=align_offset
+        arrow_padding=arrow_padding
+        avoid_collisions=avoid_collisions
+        collision_boundary=collision_boundary.get_value()
+        collision_padding=collision_padding
+        sticky=sticky
+        hide_when_detached=hide_when_detached
+        on_open_auto_focus=on_open_auto_focus
+        on_close_auto_focus=on_close_auto_focus
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        on_focus_outside=Callback::new(move |ev: FocusEvent| {
+          on_focus_outside.call(ev.clone());
+
+          if modal.get_untracked() {
+            ev.prevent_default();
+          }
+        })
+        on_key_down=on_key_down
+        on_dismiss=Callback::new(move |_| on_open_change.call(false))
+        node_ref=node_ref
+        attrs=attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </MenuContentImpl>
+    </Show>
+  }
+}
+
+#[derive(Clone)]
+struct GraceIntent {
+  area: Vec<Point>,
+  side: Side,
+}
+
+#[derive(Clone)]
+struct MenuContentContextValue {
+  depth: usize,
+  search: StoredValue<String>,
+  on_item_enter: Callback<PointerEvent>,
+  on_item_leave: Callback<PointerEvent>,
+  on_trigger_leave: Callback<PointerEvent>,
+  pointer_grace_timer: StoredValue<Option<TimeoutHandle>>,
+  on_pointer_grace_intent_change: Callback<Option<GraceIntent>>,
+}
+
+#[component]
+fn MenuContentImpl(
+  should_loop: MaybeSignal<bool>,
+
+  #[prop(into)] side: MaybeSignal<Side>,
+  side_offset: MaybeSignal<f64>,
+  #[prop(into)] align: MaybeSignal<Align>,
+  align_offset: MaybeSignal<f64>,
+  arrow_padding: MaybeSignal<f64>,
+  avoid_collisions: MaybeSignal<bool>,
+  collision_boundary: Vec<NodeRef<AnyElement>>,
+  collision_padding: MaybeSignal<f64>,
+  sticky: MaybeSignal<Sticky>,
+  hide_when_detached: MaybeSignal<bool>,
+
+  on_open_auto_focus: Callback<Event>,
+  on_close_auto_focus: Callback<Event>,
+  on_escape_key_down: Callback<KeyboardEvent>,
+  on_pointer_down_outside: Callback<PointerEvent>,
+  on_focus_outside: Callback<FocusEvent>,
+  on_key_down: Callback<KeyboardEvent>,
+  on_dismiss: Callback<()>,
+
+  node_ref: NodeRef<AnyElement>,
+  attrs: Attributes,
+  children: ChildrenFn,
+
+  as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuContextValue {
+    open, content_ref, ..
+  } = use_context().expect("MenuContent must be used in a Menu component");
+  let MenuRootContextValue {
+    is_using_keyboard,
+    direction,
+    modal,
+    ..
+  } = use_context().expect("MenuContent must be used in a Menu component");
+
+  let depth = use_context::<MenuContentContextValue>()
+    .map(|parent| parent.depth + 1)
+    .unwrap_or(0);
+
+  provide_context(CollectionContextValue::<MenuItemData, AnyElement> {
+    collection_ref: node_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  let get_items = use_collection_context::<MenuItemData, AnyElement>();
+  let get_candidates = move || {
+    get_items
+      .get_untracked()
+      .into_iter()
+      .filter(|(_, data)| !data.disabled.get_untracked())
+      .filter_map(|(node, data)| Some(((*node.get_untracked()?).clone(), data)))
+      .collect::<Vec<_>>()
+  };
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      content_ref.load(&node);
+    }
+  });
+
+  let search = StoredValue::new(String::new());
+  let search_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  let handle_typeahead_search = move |key: String| {
+    let search_value = format!("{}{key}", search.get_value());
+    search.set_value(search_value.clone());
+
+    if let Some(handle) = search_timer.get_value() {
+      handle.clear();
+    }
+
+    if let Ok(handle) = set_timeout_with_handle(
+      move || _ = search.try_set_value(String::new()),
+      Duration::from_millis(1000),
+    ) {
+      search_timer.set_value(Some(handle));
+    }
+
+    let candidates = get_candidates();
+    let text_values = candidates
+      .iter()
+      .map(|(node, data)| {
+        data
+          .text_value
+          .clone()
+          .unwrap_or_else(|| node.text_content().unwrap_or_default())
+      })
+      .collect::<Vec<_>>();
+
+    let active_element = document().active_element();
+    let current_index = candidates.iter().position(|(node, _)| {
+      let node: &web_sys::Element = node;
+      Some(node) == active_element.as_ref()
+    });
+
+    if let Some(index) = get_next_match(&text_values, &search_value, current_index) {
+      _ = candidates[index].0.focus();
+    }
+  };
+
+  on_cleanup(move || {
+    if let Some(Some(handle)) = search_timer.try_get_value() {
+      handle.clear();
+    }
+  });
+
+  let pointer_grace_intent = StoredValue::<Option<GraceIntent>>::new(None);
+  let pointer_grace_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let pointer_dir = StoredValue::new(Side::Right);
+  let last_pointer_x = StoredValue::new(0);
+
+  on_cleanup(move || {
+    if let Some(Some(handle)) = pointer_grace_timer.try_get_value() {
+      handle.clear();
+    }
+  });
+
+  let is_pointer_moving_to_submenu = move |ev: &PointerEvent| {
+    pointer_grace_intent.with_value(|intent| {
+      intent
+        .as_ref()
+        .map(|intent| {
+          pointer_dir.get_value() == intent.side
+            && is_point_in_polygon(
+              Point::new(ev.client_x() as f64, ev.client_y() as f64),
+              &intent.area,
+            )
+        })
+        .unwrap_or(false)
+    })
+  };
+
+  let is_modal_open = Signal::derive(move || depth == 0 && modal.get() && open.get());
+  let layer_ref = NodeRef::<AnyElement>::new();
+  create_scroll_lock(is_modal_open);
+  create_hide_others(node_ref, is_modal_open, HideOthersMode::AriaHidden);
+
+  provide_context(MenuContentContextValue {
+    depth,
+    search,
+    on_item_enter: Callback::new(move |ev: PointerEvent| {
+      if is_pointer_moving_to_submenu(&ev) {
+        ev.prevent_default();
+      }
+    }),
+    on_item_leave: Callback::new(move |ev: PointerEvent| {
+      if is_pointer_moving_to_submenu(&ev) {
+        return;
+      }
+
+      if let Some(node) = node_ref.get_untracked() {
+        _ = node.focus();
+      }
+    }),
+    on_trigger_leave: Callback::new(move |ev: PointerEvent| {
+      if is_pointer_moving_to_submenu(&ev) {
+        ev.prevent_default();
+      }
+    }),
+    pointer_grace_timer,
+    on_pointer_grace_intent_change: Callback::new(move |intent| {
+      pointer_grace_intent.set_value(intent);
+    }),
+  });
+
+  _ = use_event_listener(node_ref, pointermove, move |ev: PointerEvent| {
+    if ev.pointer_type() != "mouse" {
+      return;
+    }
+
+    let target = ev
+      .target()
+      .and_then(|target| target.dyn_into::<Node>().ok());
+    let Some(node) = node_ref.get_untracked() else {
+      return;
+    };
+
+    if !node.contains(target.as_ref()) || last_pointer_x.get_value() == ev.client_x() {
+      return;
+    }
+
+    pointer_dir.set_value(if ev.client_x() > last_pointer_x.get_value() {
+      Side::Right
+    } else {
+      Side::Left
+    });
+    last_pointer_x.set_value(ev.client_x());
+  });
+
+  _ = use_event_listener(node_ref, keydown, move |ev: KeyboardEvent| {
+    on_key_down.call(ev.clone());
+
+    let Some(node) = node_ref.get_untracked() else {
+      return;
+    };
+    let node: &web_sys::Element = &node;
+
+    let target = ev
+      .target()
+      .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+    let is_key_down_inside = target
+      .as_ref()
+      .and_then(|target| target.closest("[data-leptix-menu-content]").ok().flatten())
+      .map(|content| &content == node)
+      .unwrap_or(false);
+
+    let key = ev.key();
+    let is_modifier_key = ev.ctrl_key() || ev.alt_key() || ev.meta_key();
+    let action = get_content_key_down_action(
+      &key,
+      is_modifier_key,
+      is_key_down_inside,
+      target.as_ref() == Some(node),
+    );
+
+    if action.prevent_default {
+      ev.prevent_default();
+    }
+
+    if action.typeahead {
+      handle_typeahead_search(key);
+    }
+
+    let Some(edge) = action.focus_edge else {
+      return;
+    };
+
+    let mut candidates = get_candidates()
+      .into_iter()
+      .map(|(node, _)| node)
+      .collect::<Vec<_>>();
+
+    if edge == FocusEdge::Last {
+      candidates.reverse();
+    }
+
+    focus_first(&candidates);
+  });
+
+  let mut merged_attrs = vec![
+    ("role", "menu".into_attribute()),
+    ("aria-orientation", "vertical".into_attribute()),
+    ("tabindex", (-1).into_attribute()),
+    (
+      "data-state",
+      (move || get_open_state(open.get())).into_attribute(),
+    ),
+    ("data-leptix-menu-content", "".into_attribute()),
+    (
+      "dir",
+      (move || direction.get().to_string()).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let collision_boundary = StoredValue::new(collision_boundary);
+  let children = StoredValue::new(children);
+
+  view! {
+    <FocusScope
+      as_child=true
+      trapped=is_modal_open
+      on_mount_auto_focus=Callback::new(move |ev: Event| {
+        on_open_auto_focus.call(ev.clone());
+
+        if ev.default_prevented() {
+          return;
+        }
+
+        ev.prevent_default();
+
+        if depth == 0 {
+          if let Some(node) = node_ref.get_untracked() {
+            _ = node.focus();
+          }
+        }
+
+        if is_using_keyboard.get_value() {
+          request_animation_frame(move || {
+            let candidates = get_candidates()
+              .into_iter()
+              .map(|(node, _)| node)
+              .collect::<Vec<_>>();
+
+            focus_first(&candidates);
+          });
+        }
+      })
+      on_unmount_auto_focus=on_close_auto_focus
+      node_ref=layer_ref
+    >
+      <DismissableLayer
+        as_child=true
+        disable_outside_pointer_events=is_modal_open
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        on_focus_outside=on_focus_outside
+        on_dismiss=on_dismiss
+        node_ref=layer_ref
+      >
+        <PopperContent
+          side=side
+          side_offset=side_offset
+          align=align
+          align_offset=align_offset
+          arrow_padding=arrow_padding
+          avoid_collisions=avoid_collisions
+          collision_boundary=collision_boundary.get_value()
+          collision_padding=collision_padding
+          sticky=sticky
+          hide_when_detached=hide_when_detached
+          node_ref=node_ref
+          attrs=merged_attrs.get_value()
+          as_child=as_child
+        >
+          <RovingFocusGroup
+            orientation=Orientation::Vertical
+            direction=Signal::derive(move || Some(direction.get()))
+            should_loop=should_loop
+          >
+            {children.with_value(|children| children())}
+          </RovingFocusGroup>
+        </PopperContent>
+      </DismissableLayer>
+    </FocusScope>
+  }
+}
+
+#[component]
+pub(crate) fn MenuGroup(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let mut merged_attrs = vec![("role", "group".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub(crate) fn MenuLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub(crate) fn MenuItem(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuRootContextValue { on_close, .. } =
+    use_context().expect("MenuItem must be used in a Menu component");
+  let MenuContentContextValue { search, .. } =
+    use_context().expect("MenuItem must be used in a MenuContent component");
+
+  let handle_select = move || {
+    if disabled.get_untracked() {
+      return;
+    }
+
+    let mut init = CustomEventInit::new();
+    init.bubbles(true).cancelable(true);
+
+    let Ok(ev) = CustomEvent::new_with_event_init_dict(ITEM_SELECT, &init) else {
+      return;
+    };
+
+    let ev: Event = ev.into();
+    on_select.call(ev.clone());
+
+    if !ev.default_prevented() {
+      on_close.call(());
+    }
+  };
+
+  view! {
+    <MenuItemImpl
+      disabled=disabled
+      text_value=text_value
+      on_click=Callback::new(move |ev: MouseEvent| {
+        on_click.call(ev);
+        handle_select();
+      })
+      on_key_down=Callback::new(move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        if disabled.get_untracked() || (!search.get_value().is_empty() && ev.key() == " ") {
+          return;
+        }
+
+        if SELECTION_KEYS.contains(&ev.key().as_str()) {
+          ev.prevent_default();
+
+          if let Some(node) = node_ref.get_untracked() {
+            node.click();
+          }
+        }
+      })
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuItemImpl>
+  }
+}
+
+#[component]
+fn MenuItemImpl(
+  disabled: MaybeSignal<bool>,
+  text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_move: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_leave: Callback<PointerEvent>,
+
+  node_ref: NodeRef<AnyElement>,
+  attrs: Attributes,
+  children: ChildrenFn,
+
+  as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuContentContextValue {
+    on_item_enter,
+    on_item_leave,
+    ..
+  } = use_context().expect("MenuItem must be used in a MenuContent component");
+
+  use_collection_item_ref::<AnyElement, MenuItemData>(
+    node_ref,
+    MenuItemData {
+      disabled: Signal::derive(move || disabled.get()),
+      text_value: text_value.get_untracked(),
+    },
+  );
+
+  let is_focused = RwSignal::new(false);
+
+  let mut merged_attrs = vec![
+    ("role", "menuitem".into_attribute()),
+    ("tabindex", (-1).into_attribute()),
+    (
+      "data-highlighted",
+      (move || is_focused.get().then_some("")).into_attribute(),
+    ),
+    (
+      "aria-disabled",
+      (move || disabled.get().then_some("true")).into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <RovingFocusGroupItem
+      as_child=true
+      focusable=Signal::derive(move || !disabled.get())
+      node_ref=node_ref
+    >
+      <Primitive
+        element=html::div
+        on:click=move |ev: MouseEvent| on_click.call(ev)
+        on:keydown=move |ev: KeyboardEvent| on_key_down.call(ev)
+        on:pointermove=move |ev: PointerEvent| {
+          on_pointer_move.call(ev.clone());
+
+          if ev.default_prevented() || ev.pointer_type() != "mouse" {
+            return;
+          }
+
+          if disabled.get_untracked() {
+            on_item_leave.call(ev);
+            return;
+          }
+
+          on_item_enter.call(ev.clone());
+
+          if ev.default_prevented() {
+            return;
+          }
+
+          if let Some(node) = node_ref.get_untracked() {
+            _ = node.focus();
+          }
+        }
+        on:pointerleave=move |ev: PointerEvent| {
+          on_pointer_leave.call(ev.clone());
+
+          if ev.default_prevented() || ev.pointer_type() != "mouse" {
+            return;
+          }
+
+          on_item_leave.call(ev);
+        }
+        on:focus=move |_| is_focused.set(true)
+        on:blur=move |_| is_focused.set(false)
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </RovingFocusGroupItem>
+  }
+}
+
+#[derive(Clone)]
+struct MenuItemIndicatorContextValue {
+  checked: Signal<CheckedState>,
+}
+
+#[component]
+pub(crate) fn MenuCheckboxItem(
+  #[prop(optional, into)] checked: MaybeProp<CheckedState>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_checked_change: Callback<CheckedState>,
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let checked = Signal::derive(move || checked.get().unwrap_or(CheckedState::Checked(false)));
+
+  provide_context(MenuItemIndicatorContextValue { checked });
+
+  let mut merged_attrs = vec![
+    ("role", "menuitemcheckbox".into_attribute()),
+    (
+      "aria-checked",
+      (move || match checked.get() {
+        CheckedState::Checked(checked) => checked.to_string(),
+        CheckedState::Indeterminate => "mixed".to_string(),
+      })
+      .into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || get_checked_state(checked.get())).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <MenuItem
+      disabled=disabled
+      text_value=text_value
+      on_select=Callback::new(move |ev: Event| {
+        on_select.call(ev);
+
+        on_checked_change.call(match checked.get_untracked() {
+          CheckedState::Checked(checked) => CheckedState::Checked(!checked),
+          CheckedState::Indeterminate => CheckedState::Checked(true),
+        });
+      })
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuItem>
+  }
+}
+
+#[derive(Clone)]
+struct MenuRadioGroupContextValue {
+  value: Signal<Option<String>>,
+  on_value_change: Callback<String>,
+}
+
+#[component]
+pub(crate) fn MenuRadioGroup(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  provide_context(MenuRadioGroupContextValue {
+    value: Signal::derive(move || value.get()),
+    on_value_change,
+  });
+
+  view! {
+    <MenuGroup
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuGroup>
+  }
+}
+
+#[component]
+pub(crate) fn MenuRadioItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuRadioGroupContextValue {
+    value: group_value,
+    on_value_change,
+  } = use_context().expect("MenuRadioItem must be used in a MenuRadioGroup component");
+
+  let item_value = value.clone();
+  let checked = Signal::derive(move || group_value.get() == Some(item_value.get()));
+
+  provide_context(MenuItemIndicatorContextValue {
+    checked: Signal::derive(move || CheckedState::Checked(checked.get())),
+  });
+
+  let mut merged_attrs = vec![
+    ("role", "menuitemradio".into_attribute()),
+    ("aria-checked", (move || checked.get()).into_attribute()),
+    (
+      "data-state",
+      (move || get_checked_state(CheckedState::Checked(checked.get()))).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <MenuItem
+      disabled=disabled
+      text_value=text_value
+      on_select=Callback::new(move |ev: Event| {
+        on_select.call(ev);
+        on_value_change.call(value.get_untracked());
+      })
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuItem>
+  }
+}
+
+#[component]
+pub(crate) fn MenuItemIndicator(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuItemIndicatorContextValue { checked } = use_context()
+    .expect("MenuItemIndicator must be used in a MenuCheckboxItem or MenuRadioItem component");
+
+  let is_present =
+    Signal::derive(move || force_mount.get() || checked.get() != CheckedState::Checked(false));
+  let presence = create_presence(is_present, node_ref);
+
+  let mut merged_attrs = vec![(
+    "data-state",
+    (move || get_checked_state(checked.get())).into_attribute(),
+  )];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <Primitive
+        element=html::span
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub(crate) fn MenuSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let mut merged_attrs = vec![
+    ("role", "separator".into_attribute()),
+    ("aria-orientation", "horizontal".into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </Primitive>
+  }
+}
+
+#[component]
+pub(crate) fn MenuArrow(
+  #[prop(default=10.0f64.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0f64.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+  PopperArrow(PopperArrowProps {
+    width,
+    height,
+    attrs,
+    children,
+  })
+}
+
+#[derive(Clone)]
+struct MenuSubContextValue {
+  content_id: Signal<String>,
+  trigger_id: Signal<String>,
+  trigger_ref: NodeRef<AnyElement>,
+}
+
+#[component]
+pub(crate) fn MenuSub(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(into)] open: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let MenuContextValue {
+    open: parent_open, ..
+  } = use_context().expect("MenuSub must be used in a Menu component");
+
+  let open = Signal::derive(move || open.get());
+
+  Effect::new(move |_| {
+    if !parent_open.get() && open.get_untracked() {
+      on_open_change.call(false);
+    }
+  });
+
+  provide_context(MenuContextValue {
+    open,
+    on_open_change,
+    content_ref: NodeRef::new(),
+  });
+
+  let id = create_id(id);
+
+  provide_context(MenuSubContextValue {
+    content_id: create_part_id(id, "content"),
+    trigger_id: create_part_id(id, "trigger"),
+    trigger_ref: NodeRef::new(),
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub(crate) fn MenuSubTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_move: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_leave: Callback<PointerEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuContextValue {
+    open,
+    on_open_change,
+    content_ref,
+  } = use_context().expect("MenuSubTrigger must be used in a MenuSub component");
+  let MenuRootContextValue { direction, .. } =
+    use_context().expect("MenuSubTrigger must be used in a Menu component");
+  let MenuSubContextValue {
+    content_id,
+    trigger_id,
+    trigger_ref,
+  } = use_context().expect("MenuSubTrigger must be used in a MenuSub component");
+  let MenuContentContextValue {
+    search,
+    on_trigger_leave,
+    pointer_grace_timer,
+    on_pointer_grace_intent_change,
+    ..
+  } = use_context().expect("MenuSubTrigger must be used in a MenuContent component");
+
+  let open_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  let clear_open_timer = move || {
+    if let Some(Some(handle)) = open_timer.try_get_value() {
+      handle.clear();
+      open_timer.set_value(None);
+    }
+  };
+
+  on_cleanup(move || {
+    clear_open_timer();
+
+    if let Some(Some(handle)) = pointer_grace_timer.try_get_value() {
+      handle.clear();
+    }
+
+    on_pointer_grace_intent_change.call(None);
+  });
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let mut merged_attrs = vec![
+    ("id", (move || trigger_id.get()).into_attribute()),
+    ("aria-haspopup", "menu".into_attribute()),
+    ("aria-expanded", (move || open.get()).into_attribute()),
+    (
+      "aria-controls",
+      (move || open.get().then(|| content_id.get())).into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || get_open_state(open.get())).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <MenuAnchor as_child=true node_ref=node_ref>
+      <MenuItemImpl
+        disabled=disabled
+        text_value=text_value.clone()
+        on_click=Callback::new(move |ev: MouseEvent| {
+          on_click.call(ev.clone());
+
+          if disabled.get_untracked() || ev.default_prevented() {
+            return;
+          }
+
+          if let Some(node) = node_ref.get_untracked() {
+            _ = node.focus();
+          }
+
+          if !open.get_untracked() {
+            on_open_change.call(true);
+          }
+        })
+        on_key_down=Callback::new(move |ev: KeyboardEvent| {
+          on_key_down.call(ev.clone());
+
+          let is_typing_ahead = !search.get_value().is_empty();
+
+          if disabled.get_untracked() || (is_typing_ahead && ev.key() == " ") {
+            return;
+          }
+
+          if get_sub_open_keys(direction.get_untracked()).contains(&ev.key().as_str()) {
+            on_open_change.call(true);
+            ev.prevent_default();
+          }
+        })
+        on_pointer_move=Callback::new(move |ev: PointerEvent| {
+          on_pointer_move.call(ev.clone());
+
+          if ev.default_prevented() || ev.pointer_type() != "mouse" {
+            return;
+          }
+
+          if !disabled.get_untracked() && !open.get_untracked() && open_timer.get_value().is_none() {
+            on_pointer_grace_intent_change.call(None);
+
+            if let Ok(handle) = set_timeout_with_handle(
+              move || {
+                on_open_change.call(true);
+                clear_open_timer();
+              },
+              Duration::from_millis(100),
+            ) {
+              open_timer.set_value(Some(handle));
+            }
+          }
+        })
+        on_pointer_leave=Callback::new(move |ev: PointerEvent| {
+          on_pointer_leave.call(ev.clone());
+
+          if ev.default_prevented() || ev.pointer_type() != "mouse" {
+            return;
+          }
+
+          clear_open_timer();
+
+          let Some(content) = content_ref.get_untracked() else {
+            on_trigger_leave.call(ev.clone());
+
+            if !ev.default_prevented() {
+              on_pointer_grace_intent_change.call(None);
+            }
+
+            return;
+          };
+
+          let rect = content.get_bounding_client_rect();
+          let is_right_side = content.get_attribute("data-side").as_deref() != Some("left");
+
+          let bleed = if is_right_side { -5. } else { 5. };
+          let (near_edge, far_edge) = if is_right_side {
+            (rect.left(), rect.right())
+          } else {
+            (rect.right(), rect.left())
+          };
+
+          on_pointer_grace_intent_change.call(Some(GraceIntent {
+            area: vec![
+              Point::new(ev.client_x() as f64 + bleed, ev.client_y() as f64),
+              Point::new(near_edge, rect.top()),
+              Point::new(far_edge, rect.top()),
+              Point::new(far_edge, rect.bottom()),
+              Point::new(near_edge, rect.bottom()),
+            ],
+            side: if is_right_side { Side::Right } else { Side::Left },
+          }));
+
+          if let Some(handle) = pointer_grace_timer.get_value() {
+            handle.clear();
+          }
+
+          if let Ok(handle) = set_timeout_with_handle(
+            move || on_pointer_grace_intent_change.call(None),
+            Duration::from_millis(300),
+          ) {
+            pointer_grace_timer.set_value(Some(handle));
+          }
+        })
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </MenuItemImpl>
+    </MenuAnchor>
+  }
+}
+
+#[component]
+pub(crate) fn MenuSubContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenuContextValue {
+    open,
+    on_open_change,
+    ..
+  } = use_context().expect("MenuSubContent must be used in a MenuSub component");
+  let MenuRootContextValue {
+    on_close,
+    direction,
+    ..
+  } = use_context().expect("MenuSubContent must be used in a Menu component");
+  let MenuSubContextValue {
+    content_id,
+    trigger_id,
+    trigger_ref,
+  } = use_context().expect("MenuSubContent must be used in a MenuSub component");
+  let portal_force_mount = use_context::<MenuPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  let is_present =
+    Signal::derive(move || force_mount.get() || portal_force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+
+  let focus_trigger = move || {
+    if let Some(trigger) = trigger_ref.get_untracked() {
+      _ = trigger.focus();
+    }
+  };
+
+  let mut merged_attrs = vec![
+    ("id", (move || content_id.get()).into_attribute()),
+    (
+      "aria-labelledby",
+      (move || trigger_id.get()).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let collision_boundary = StoredValue::new(collision_boundary);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <MenuContentImpl
+        should_loop=should_loop
+        side=Signal::derive(move || match direction.get() {
+          Direction::LeftToRight => Side::Right,
+          Direction::RightToLeft => Side::Left,
+        })
+        side_offset=side_offset
+        align=Align::Start
+        align_offset=align_offset
+        arrow_padding=arrow_padding
+        avoid_collisions=avoid_collisions
+        collision_boundary=collision_boundary.get_value()
+        collision_padding=collision_padding
+        sticky=sticky
+        hide_when_detached=hide_when_detached
+        on_open_auto_focus=Callback::new(|_| {})
+        on_close_auto_focus=Callback::new(|ev: Event| ev.prevent_default())
+        on_escape_key_down=Callback::new(move |ev: KeyboardEvent| {
+          on_escape_key_down.call(ev.clone());
+
+          if ev.default_prevented() {
+            return;
+          }
+
+          on_close.call(());
+          focus_trigger();
+          ev.prevent_default();
+        })
+        on_pointer_down_outside=on_pointer_down_outside
+        on_focus_outside=Callback::new(move |ev: FocusEvent| {
+          on_focus_outside.call(ev.clone());
+
+          let target = ev.target().and_then(|target| target.dyn_into::<Node>().ok());
+          let is_trigger = trigger_ref
+            .get_untracked()
+            .map(|trigger| {
+              let trigger: &Node = &trigger;
+              Some(trigger) == target.as_ref()
+            })
+            .unwrap_or(false);
+
+          if is_trigger {
+            ev.prevent_default();
+          }
+        })
+        on_key_down=Callback::new(move |ev: KeyboardEvent| {
+          on_key_down.call(ev.clone());
+
+          let target = ev.target().and_then(|target| target.dyn_into::<Node>().ok());
+          let is_key_down_inside = ev
+            .current_target()
+            .and_then(|current_target| current_target.dyn_into::<Node>().ok())
+            .map(|current_target| current_target.contains(target.as_ref()))
+            .unwrap_or(false);
+
+          if is_key_down_inside
+            && get_sub_close_keys(direction.get_untracked()).contains(&ev.key().as_str())
+          {
+            on_open_change.call(false);
+            focus_trigger();
+            ev.prevent_default();
+          }
+        })
+        on_dismiss=Callback::new(move |_| on_open_change.call(false))
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </MenuContentImpl>
+    </Show>
+  }
+}
+
+fn get_open_state(open: bool) -> &'static str {
+  if open {
+    "open"
+  } else {
+    "closed"
+  }
+}
+
+fn get_checked_state(checked: CheckedState) -> &'static str {
+  match checked {
+    CheckedState::Checked(true) => "checked",
+    CheckedState::Checked(false) => "unchecked",
+    CheckedState::Indeterminate => "indeterminate",
+  }
+}
+
+fn get_sub_open_keys(direction: Direction) -> [&'static str; 3] {
+  match direction {
+    Direction::LeftToRight => ["Enter", " ", "ArrowRight"],
+    Direction::RightToLeft => ["Enter", " ", "ArrowLeft"],
+  }
+}
+
+fn get_sub_close_keys(direction: Direction) -> [&'static str; 1] {
+  match direction {
+    Direction::LeftToRight => ["ArrowLeft"],
+    Direction::RightToLeft => ["ArrowRight"],
+  }
+}
+
+pub(crate) fn get_next_match(
+  values: &[String],
+  search: &str,
+  current_index: Option<usize>,
+) -> Option<usize> {
+  let mut chars = search.chars();
+  let first_char = chars.next()?;
+  let is_repeated = chars.clone().count() > 0 && chars.all(|char| char == first_char);
+
+  let normalized_search = if is_repeated {
+    first_char.to_string()
+  } else {
+    search.to_string()
+  }
+  .to_lowercase();
+
+  let exclude_current = normalized_search.chars().count() == 1;
+  let start = current_index.unwrap_or(0);
+
+  let next_index = (0..values.len())
+    .map(|offset| (start + offset) % values.len())
+    .filter(|index| !(exclude_current && Some(*index) == current_index))
+    .find(|index| {
+      values[*index]
+        .to_lowercase()
+        .starts_with(&normalized_search)
+    })?;
+
+  (Some(next_index) != current_index).then_some(next_index)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FocusEdge {
+  First,
+  Last,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct ContentKeyDownAction {
+  prevent_default: bool,
+  typeahead: bool,
+  focus_edge: Option<FocusEdge>,
+}
+
+fn get_content_key_down_action(
+  key: &str,
+  is_modifier_key: bool,
+  is_key_down_inside: bool,
+  is_content_target: bool,
+) -> ContentKeyDownAction {
+  let mut action = ContentKeyDownAction::default();
+
+  if is_key_down_inside {
+    action.prevent_default = key == "Tab";
+    action.typeahead = !is_modifier_key && key.chars().count() == 1;
+  }
+
+  if !is_content_target {
+    return action;
+  }
+
+  action.focus_edge = if FIRST_KEYS.contains(&key) {
+    Some(FocusEdge::First)
+  } else if LAST_KEYS.contains(&key) {
+    Some(FocusEdge::Last)
+  } else {
+    None
+  };
+  action.prevent_default |= action.focus_edge.is_some();
+
+  action
+}
+
+#[test]
+fn content_key_down_handles_tab_typeahead_and_edge_keys() {
+  assert_eq!(
+    get_content_key_down_action("Tab", false, true, false),
+    ContentKeyDownAction {
+      prevent_default: true,
+      ..Default::default()
+    }
+  );
+  assert_eq!(
+    get_content_key_down_action("b", false, true, false),
+    ContentKeyDownAction {
+      typeahead: true,
+      ..Default::default()
+    }
+  );
+  assert_eq!(
+    get_content_key_down_action("b", true, true, false),
+    ContentKeyDownAction::default()
+  );
+  assert_eq!(
+    get_content_key_down_action("Home", false, true, true),
+    ContentKeyDownAction {
+      prevent_default: true,
+      focus_edge: Some(FocusEdge::First),
+      ..Default::default()
+    }
+  );
+  assert_eq!(
+    get_content_key_down_action("End", false, true, true),
+    ContentKeyDownAction {
+      prevent_default: true,
+      focus_edge: Some(FocusEdge::Last),
+      ..Default::default()
+    }
+  );
+  assert_eq!(
+    get_content_key_down_action("End", false, true, false),
+    ContentKeyDownAction::default()
+  );
+  assert_eq!(
+    get_content_key_down_action("a", false, false, false),
+    ContentKeyDownAction::default()
+  );
+}
+
+#[test]
+fn typeahead_cycles_through_matching_items() {
+  let values = ["Apple", "Banana", "Blueberry", "Cherry"]
+    .map(String::from)
+    .to_vec();
+
+  assert_eq!(get_next_match(&values, "b", None), Some(1));
+  assert_eq!(get_next_match(&values, "b", Some(1)), Some(2));
+  assert_eq!(get_next_match(&values, "bb", Some(2)), Some(1));
+  assert_eq!(get_next_match(&values, "blu", Some(1)), Some(2));
+  assert_eq!(get_next_match(&values, "z", Some(0)), None);
+}
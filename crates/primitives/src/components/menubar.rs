@@ -0,0 +1,938 @@
+use std::collections::HashMap;
+
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsCast;
+use web_sys::{Event, FocusEvent, KeyboardEvent, MouseEvent, Node, PointerEvent};
+
+use crate::{
+  checkbox::CheckedState,
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  menu::{
+    Menu, MenuAnchor, MenuArrow, MenuArrowProps, MenuCheckboxItem, MenuContent, MenuGroup,
+    MenuItem, MenuItemIndicator, MenuLabel, MenuPortal, MenuPortalProps, MenuRadioGroup,
+    MenuRadioItem, MenuSeparator, MenuSub, MenuSubContent, MenuSubTrigger,
+  },
+  popper::{Align, Side, Sticky},
+  portal::PortalContainer,
+  primitive::Primitive,
+  roving_focus::{RovingFocusGroup, RovingFocusGroupItem},
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    use_id::{create_id, create_part_id, use_id},
+    Attributes,
+  },
+  Direction, Orientation,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MenubarTriggerData {
+  value: String,
+  disabled: Signal<bool>,
+}
+
+impl Ord for MenubarTriggerData {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.value.cmp(&other.value)
+  }
+}
+
+impl PartialOrd for MenubarTriggerData {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[derive(Clone)]
+struct MenubarContextValue {
+  value: Signal<String>,
+  on_menu_open: Callback<String>,
+  on_menu_close: Callback<()>,
+  on_menu_toggle: Callback<String>,
+  direction: Signal<Direction>,
+  should_loop: Signal<bool>,
+}
+
+#[component]
+pub fn MenubarRoot(
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(default=true.into(), into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let value = Signal::derive(move || value.get().unwrap_or_default());
+
+  let set_on_menu_open = set_value.clone();
+  let set_on_menu_close = set_value.clone();
+
+  provide_context(CollectionContextValue::<MenubarTriggerData, AnyElement> {
+    collection_ref: node_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  provide_context(MenubarContextValue {
+    value,
+    on_menu_open: Callback::new(move |value| {
+      set_on_menu_open.set(value);
+    }),
+    on_menu_close: Callback::new(move |_| {
+      set_on_menu_close.set(String::new());
+    }),
+    on_menu_toggle: Callback::new(move |value: String| {
+      set_value.update(|current| {
+        *current = Some(if current.as_deref() == Some(value.as_str()) {
+          String::new()
+        } else {
+          value
+        });
+      });
+    }),
+    direction: Signal::derive(move || direction.get().unwrap_or_default()),
+    should_loop: Signal::derive(move || should_loop.get()),
+  });
+
+  let mut merged_attrs = vec![("role", "menubar".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <RovingFocusGroup
+      orientation=Orientation::Horizontal
+      direction=direction
+      should_loop=should_loop
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </RovingFocusGroup>
+  }
+}
+
+#[derive(Clone)]
+struct MenubarMenuContextValue {
+  value: Signal<String>,
+  trigger_id: Signal<String>,
+  trigger_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+}
+
+#[component]
+pub fn MenubarMenu(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let MenubarContextValue {
+    value: menubar_value,
+    on_menu_close,
+    direction,
+    ..
+  } = use_context().expect("MenubarMenu must be used in a MenubarRoot component");
+
+  let value = value.get_untracked().unwrap_or_else(use_id);
+  let value = Signal::derive(move || value.clone());
+
+  let id = create_id(id);
+
+  provide_context(MenubarMenuContextValue {
+    value,
+    trigger_id: create_part_id(id, "trigger"),
+    trigger_ref: NodeRef::new(),
+    content_id: create_part_id(id, "content"),
+  });
+
+  view! {
+    <Menu
+      open=Signal::derive(move || menubar_value.get() == value.get())
+      direction=Signal::derive(move || Some(direction.get()))
+      modal=false
+      on_open_change=Callback::new(move |open: bool| {
+        if !open {
+          on_menu_close.call(());
+        }
+      })
+    >
+      {children()}
+    </Menu>
+  }
+}
+
+#[component]
+pub fn MenubarTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_enter: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus: Callback<FocusEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_blur: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenubarContextValue {
+    value: menubar_value,
+    on_menu_open,
+    on_menu_toggle,
+    ..
+  } = use_context().expect("MenubarTrigger must be used in a MenubarRoot component");
+  let MenubarMenuContextValue {
+    value,
+    trigger_id,
+    trigger_ref,
+    content_id,
+  } = use_context().expect("MenubarTrigger must be used in a MenubarMenu component");
+
+  use_collection_item_ref::<AnyElement, MenubarTriggerData>(
+    node_ref,
+    MenubarTriggerData {
+      value: value.get_untracked(),
+      disabled: Signal::derive(move || disabled.get()),
+    },
+  );
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let open = Signal::derive(move || menubar_value.get() == value.get());
+  let is_focused = RwSignal::new(false);
+
+  let mut merged_attrs = vec![
+    ("type", "button".into_attribute()),
+    ("role", "menuitem".into_attribute()),
+    ("id", (move || trigger_id.get()).into_attribute()),
+    ("aria-haspopup", "menu".into_attribute()),
+    ("aria-expanded", (move || open.get()).into_attribute()),
+    (
+      "aria-controls",
+      (move || open.get().then(|| content_id.get())).into_attribute(),
+    ),
+    (
+      "data-highlighted",
+      (move || is_focused.get().then_some("")).into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || if open.get() { "open" } else { "closed" }).into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+    ("disabled", (move || disabled.get()).into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <RovingFocusGroupItem
+      as_child=true
+      focusable=Signal::derive(move || !disabled.get())
+      tab_stop_id=value
+      node_ref=node_ref
+    >
+      <MenuAnchor as_child=true node_ref=node_ref>
+        <Primitive
+          element=html::button
+          on:pointerdown=move |ev: PointerEvent| {
+            on_pointer_down.call(ev.clone());
+
+            if disabled.get_untracked() || ev.button() != 0 || ev.ctrl_key() {
+              return;
+            }
+
+            on_menu_open.call(value.get_untracked());
+
+            if !open.get_untracked() {
+              ev.prevent_default();
+            }
+          }
+          on:pointerenter=move |ev: PointerEvent| {
+            on_pointer_enter.call(ev);
+
+            let is_menubar_open = !menubar_value.get_untracked().is_empty();
+
+            if is_menubar_open && !open.get_untracked() && !disabled.get_untracked() {
+              on_menu_open.call(value.get_untracked());
+
+              if let Some(node) = node_ref.get_untracked() {
+                _ = node.focus();
+              }
+            }
+          }
+          on:keydown=move |ev: KeyboardEvent| {
+            on_key_down.call(ev.clone());
+
+            if disabled.get_untracked() {
+              return;
+            }
+
+            match ev.key().as_str() {
+              "Enter" | " " => on_menu_toggle.call(value.get_untracked()),
+              "ArrowDown" => on_menu_open.call(value.get_untracked()),
+              _ => return,
+            }
+
+            ev.prevent_default();
+          }
+          on:focus=move |ev: FocusEvent| {
+            on_focus.call(ev);
+            is_focused.set(true);
+          }
+          on:blur=move |ev: FocusEvent| {
+            on_blur.call(ev);
+            is_focused.set(false);
+          }
+          node_ref=node_ref
+          attrs=merged_attrs.get_value()
+          as_child=as_child
+        >
+          {children.with_value(|children| children())}
+        </Primitive>
+      </MenuAnchor>
+    </RovingFocusGroupItem>
+  }
+}
+
+#[component]
+pub fn MenubarPortal(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  MenuPortal(MenuPortalProps {
+    force_mount,
+    container,
+    children,
+  })
+}
+
+#[derive(Clone)]
+struct MenubarContentContextValue {
+  on_sub_content_key_down: Callback<KeyboardEvent>,
+}
+
+#[component]
+pub fn MenubarContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(default=Align::Start.into(), into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenubarContextValue {
+    value: menubar_value,
+    on_menu_open,
+    direction,
+    should_loop: menubar_should_loop,
+    ..
+  } = use_context().expect("MenubarContent must be used in a MenubarRoot component");
+  let MenubarMenuContextValue {
+    value,
+    trigger_id,
+    trigger_ref,
+    content_id,
+  } = use_context().expect("MenubarContent must be used in a MenubarMenu component");
+
+  let get_items = use_collection_context::<MenubarTriggerData, AnyElement>();
+  let has_interacted_outside = StoredValue::new(false);
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-menubar-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-menubar-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-menubar-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-menubar-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-menubar-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let handle_arrow_navigation = move |ev: &KeyboardEvent, is_key_down_inside_sub_menu: bool| {
+    let key = ev.key();
+
+    if key != "ArrowRight" && key != "ArrowLeft" {
+      return;
+    }
+
+    let target_is_sub_trigger = ev
+      .target()
+      .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+      .map(|target| target.has_attribute("data-leptix-menubar-subtrigger"))
+      .unwrap_or(false);
+
+    let prev_key = match direction.get_untracked() {
+      Direction::LeftToRight => "ArrowLeft",
+      Direction::RightToLeft => "ArrowRight",
+    };
+    let is_prev_key = key == prev_key;
+
+    if (!is_prev_key && target_is_sub_trigger) || (is_key_down_inside_sub_menu && is_prev_key) {
+      return;
+    }
+
+    let mut values = get_items
+      .get_untracked()
+      .into_iter()
+      .filter(|(_, data)| !data.disabled.get_untracked())
+      .map(|(_, data)| data.value)
+      .collect::<Vec<_>>();
+
+    if is_prev_key {
+      values.reverse();
+    }
+
+    let current_value = value.get_untracked();
+    let next_value = match values.iter().position(|value| *value == current_value) {
+      Some(index) if menubar_should_loop.get_untracked() => values
+        .iter()
+        .cycle()
+        .skip(index + 1)
+        .take(values.len() - 1)
+        .next()
+        .cloned(),
+      Some(index) => values.get(index + 1).cloned(),
+      None => values.first().cloned(),
+    };
+
+    if let Some(next_value) = next_value {
+      on_menu_open.call(next_value);
+    }
+  };
+
+  provide_context(MenubarContentContextValue {
+    on_sub_content_key_down: Callback::new(move |ev: KeyboardEvent| {
+      handle_arrow_navigation(&ev, true);
+    }),
+  });
+
+  let is_menubar_trigger = move |target: Option<web_sys::EventTarget>| {
+    let target = target.and_then(|target| target.dyn_into::<Node>().ok());
+
+    get_items.get_untracked().iter().any(|(node, _)| {
+      node
+        .get_untracked()
+        .map(|node| node.contains(target.as_ref()))
+        .unwrap_or(false)
+    })
+  };
+
+  let mut merged_attrs = vec![
+    ("id", (move || content_id.get()).into_attribute()),
+    (
+      "aria-labelledby",
+      (move || trigger_id.get()).into_attribute(),
+    ),
+    ("data-leptix-menubar-content", "".into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <MenuContent
+      force_mount=force_mount
+      should_loop=should_loop
+      side=side
+      side_offset=side_offset
+      align=align
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_close_auto_focus=Callback::new(move |ev: Event| {
+        on_close_auto_focus.call(ev.clone());
+
+        if ev.default_prevented() {
+          return;
+        }
+
+        let is_menubar_open = !menubar_value.get_untracked().is_empty();
+
+        if !is_menubar_open && !has_interacted_outside.get_value() {
+          if let Some(trigger) = trigger_ref.get_untracked() {
+            _ = trigger.focus();
+          }
+        }
+
+        has_interacted_outside.set_value(false);
+        ev.prevent_default();
+      })
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=Callback::new(move |ev: PointerEvent| {
+        on_pointer_down_outside.call(ev.clone());
+
+        if !ev.default_prevented() {
+          has_interacted_outside.set_value(true);
+        }
+      })
+      on_focus_outside=Callback::new(move |ev: FocusEvent| {
+        on_focus_outside.call(ev.clone());
+
+        if is_menubar_trigger(ev.target()) {
+          ev.prevent_default();
+        }
+
+        if !ev.default_prevented() {
+          has_interacted_outside.set_value(true);
+        }
+      })
+      on_key_down=Callback::new(move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        let is_key_down_inside_sub_menu = ev
+          .target()
+          .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+          .and_then(|target| target.closest("[data-leptix-menubar-content]").ok().flatten())
+          .map(|content| {
+            let current_target = ev
+              .current_target()
+              .and_then(|current_target| current_target.dyn_into::<web_sys::Element>().ok());
+
+            Some(content) != current_target
+          })
+          .unwrap_or(false);
+
+        if !is_key_down_inside_sub_menu {
+          handle_arrow_navigation(&ev, false);
+        }
+      })
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuContent>
+  }
+}
+
+#[component]
+pub fn MenubarGroup(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuGroup
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuGroup>
+  }
+}
+
+#[component]
+pub fn MenubarLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuLabel
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuLabel>
+  }
+}
+
+#[component]
+pub fn MenubarItem(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuItem
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      on_click=on_click
+      on_key_down=on_key_down
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuItem>
+  }
+}
+
+#[component]
+pub fn MenubarCheckboxItem(
+  #[prop(optional, into)] checked: MaybeProp<CheckedState>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_checked_change: Callback<CheckedState>,
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuCheckboxItem
+      checked=checked
+      disabled=disabled
+      text_value=text_value
+      on_checked_change=on_checked_change
+      on_select=on_select
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuCheckboxItem>
+  }
+}
+
+#[component]
+pub fn MenubarRadioGroup(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioGroup
+      value=value
+      on_value_change=on_value_change
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioGroup>
+  }
+}
+
+#[component]
+pub fn MenubarRadioItem(
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuRadioItem
+      value=value
+      disabled=disabled
+      text_value=text_value
+      on_select=on_select
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuRadioItem>
+  }
+}
+
+#[component]
+pub fn MenubarItemIndicator(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuItemIndicator
+      force_mount=force_mount
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuItemIndicator>
+  }
+}
+
+#[component]
+pub fn MenubarSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <MenuSeparator
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    />
+  }
+}
+
+#[component]
+pub fn MenubarArrow(
+  #[prop(default=10.0f64.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0f64.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+  MenuArrow(MenuArrowProps {
+    width,
+    height,
+    attrs,
+    children,
+  })
+}
+
+#[component]
+pub fn MenubarSub(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  view! {
+    <MenuSub
+      id=id
+      open=Signal::derive(move || open.get().unwrap_or(false))
+      on_open_change=Callback::new(move |open| {
+        set_open.set(open);
+      })
+    >
+      {children()}
+    </MenuSub>
+  }
+}
+
+#[component]
+pub fn MenubarSubTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let mut merged_attrs = vec![("data-leptix-menubar-subtrigger", "".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <MenuSubTrigger
+      disabled=disabled
+      text_value=text_value
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubTrigger>
+  }
+}
+
+#[component]
+pub fn MenubarSubContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] should_loop: MaybeSignal<bool>,
+
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let MenubarContentContextValue {
+    on_sub_content_key_down,
+  } = use_context().expect("MenubarSubContent must be used in a MenubarContent component");
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-menubar-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-menubar-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-menubar-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-menubar-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-menubar-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let mut merged_attrs = vec![("data-leptix-menubar-content", "".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <MenuSubContent
+      force_mount=force_mount
+      should_loop=should_loop
+      side_offset=side_offset
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_escape_key_down=on_escape_key_down
+      on_pointer_down_outside=on_pointer_down_outside
+      on_focus_outside=on_focus_outside
+      on_key_down=Callback::new(move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        let is_key_down_inside = ev
+          .target()
+          .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+          .and_then(|target| target.closest("[data-leptix-menubar-content]").ok().flatten())
+          .map(|content| {
+            let current_target = ev
+              .current_target()
+              .and_then(|current_target| current_target.dyn_into::<web_sys::Element>().ok());
+
+            Some(content) == current_target
+          })
+          .unwrap_or(false);
+
+        if is_key_down_inside {
+          on_sub_content_key_down.call(ev);
+        }
+      })
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </MenuSubContent>
+  }
+}
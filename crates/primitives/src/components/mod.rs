@@ -1,24 +1,48 @@
 pub mod accordion;
+pub mod alert_dialog;
 pub mod aspect_ratio;
 pub mod avatar;
 pub mod checkbox;
 pub mod collapsible;
+pub mod combobox;
+pub mod command;
+pub mod context_menu;
+pub mod dialog;
+pub mod dismissable_layer;
+pub mod dropdown_menu;
+pub mod focus_scope;
+pub mod form;
+pub mod hover_card;
+pub mod id_provider;
 pub mod label;
+pub mod listbox;
+pub mod menubar;
+pub mod navigation_menu;
+pub mod number_field;
+pub mod pin_input;
+pub mod popover;
+pub mod popper;
+pub mod portal;
 pub mod primitive;
 pub mod progress;
 pub(crate) mod radio;
 pub mod radio_group;
 pub mod scroll_area;
+pub mod select;
 pub mod separator;
 pub mod slider;
 pub mod slot;
 pub mod switch;
 pub mod tabs;
+pub mod toast;
 pub mod toggle;
 pub mod toggle_group;
 pub mod toolbar;
+pub mod tooltip;
+pub mod tree;
 
 pub(crate) mod collection;
+pub(crate) mod menu;
 pub(crate) mod presence;
 pub(crate) mod roving_focus;
 
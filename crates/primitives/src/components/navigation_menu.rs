@@ -0,0 +1,1621 @@
+use std::{collections::HashMap, time::Duration};
+
+use leptos::{
+  ev::{keydown, pointerenter, pointerleave, Custom},
+  html::AnyElement,
+  leptos_dom::helpers::TimeoutHandle,
+  *,
+};
+use leptos_use::{use_event_listener, use_resize_observer};
+use wasm_bindgen::JsCast;
+use web_sys::{
+  CustomEvent, CustomEventInit, Event, EventInit, FocusEvent, KeyboardEvent, MouseEvent, Node,
+  PointerEvent,
+};
+
+use crate::{
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  dismissable_layer::DismissableLayer,
+  focus_scope::{focus_first, get_tabbable_candidates},
+  portal::Portal,
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    use_id::{create_id, use_id},
+    Attributes,
+  },
+  Direction, Orientation,
+};
+
+const ROOT_CONTENT_DISMISS: &str = "navigation_menu.root_content_dismiss";
+const LINK_SELECT: &str = "navigation_menu.link_select";
+const ARROW_KEYS: [&str; 4] = ["ArrowRight", "ArrowLeft", "ArrowUp", "ArrowDown"];
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct NavigationMenuItemData {
+  value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct FocusGroupItemData;
+
+#[derive(Clone)]
+struct NavigationMenuContextValue {
+  is_root_menu: bool,
+  value: Signal<String>,
+  previous_value: Signal<String>,
+  base_id: Signal<String>,
+  direction: Signal<Direction>,
+  orientation: Signal<Orientation>,
+  root_navigation_menu: NodeRef<AnyElement>,
+  indicator_track: RwSignal<Option<web_sys::Element>>,
+  viewport: RwSignal<Option<web_sys::Element>>,
+  viewport_content: RwSignal<HashMap<String, NodeRef<AnyElement>>>,
+  on_trigger_enter: Callback<String>,
+  on_trigger_leave: Callback<()>,
+  on_content_enter: Callback<()>,
+  on_content_leave: Callback<()>,
+  on_item_select: Callback<String>,
+  on_item_dismiss: Callback<()>,
+}
+
+#[component]
+pub fn NavigationMenuRoot(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+  #[prop(default=200.into(), into)] delay_duration: MaybeSignal<u64>,
+  #[prop(default=300.into(), into)] skip_delay_duration: MaybeSignal<u64>,
+  #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let open_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let close_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let skip_delay_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let is_open_delayed = StoredValue::new(true);
+
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: Callback::new(move |value: String| {
+      clear_timer(skip_delay_timer);
+
+      if !value.is_empty() {
+        is_open_delayed.set_value(false);
+      } else if let Ok(handle) = set_timeout_with_handle(
+        move || is_open_delayed.set_value(true),
+        Duration::from_millis(skip_delay_duration.get_untracked()),
+      ) {
+        skip_delay_timer.set_value(Some(handle));
+      }
+
+      on_value_change.call(value);
+    }),
+  });
+
+  let value = Signal::derive(move || value.get().unwrap_or_default());
+  let set_value = Callback::new(move |value: String| {
+    set_value.set(value);
+  });
+
+  let start_close_timer = move || {
+    clear_timer(close_timer);
+
+    if let Ok(handle) = set_timeout_with_handle(
+      move || set_value.call(String::new()),
+      Duration::from_millis(150),
+    ) {
+      close_timer.set_value(Some(handle));
+    }
+  };
+
+  let handle_open = move |item_value: String| {
+    clear_timer(close_timer);
+    set_value.call(item_value);
+  };
+
+  let handle_delayed_open = move |item_value: String| {
+    if value.get_untracked() == item_value {
+      clear_timer(close_timer);
+      return;
+    }
+
+    if let Ok(handle) = set_timeout_with_handle(
+      move || {
+        clear_timer(close_timer);
+        set_value.call(item_value);
+      },
+      Duration::from_millis(delay_duration.get_untracked()),
+    ) {
+      open_timer.set_value(Some(handle));
+    }
+  };
+
+  on_cleanup(move || {
+    clear_timer(open_timer);
+    clear_timer(close_timer);
+    clear_timer(skip_delay_timer);
+  });
+
+  let direction = Signal::derive(move || direction.get().unwrap_or_default());
+  let orientation = Signal::derive(move || orientation.get());
+
+  let base_id = create_id(id.clone());
+
+  let mut merged_attrs = vec![
+    ("id", id.into_attribute()),
+    ("aria-label", "Main".into_attribute()),
+    (
+      "data-orientation",
+      (move || orientation.get().to_string()).into_attribute(),
+    ),
+    (
+      "dir",
+      (move || direction.get().to_string()).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <NavigationMenuProvider
+      is_root_menu=true
+      base_id=base_id
+      value=value
+      direction=direction
+      orientation=orientation
+      root_navigation_menu=node_ref
+      on_trigger_enter=Callback::new(move |item_value: String| {
+        clear_timer(open_timer);
+
+        if is_open_delayed.get_value() {
+          handle_delayed_open(item_value);
+        } else {
+          handle_open(item_value);
+        }
+      })
+      on_trigger_leave=Callback::new(move |_| {
+        clear_timer(open_timer);
+        start_close_timer();
+      })
+      on_content_enter=Callback::new(move |_| {
+        clear_timer(close_timer);
+      })
+      on_content_leave=Callback::new(move |_| {
+        start_close_timer();
+      })
+      on_item_select=Callback::new(move |item_value: String| {
+        set_value.call(if value.get_untracked() == item_value {
+          String::new()
+        } else {
+          item_value
+        });
+      })
+      on_item_dismiss=Callback::new(move |_| {
+        set_value.call(String::new());
+      })
+    >
+      <Primitive
+        element=html::nav
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </NavigationMenuProvider>
+  }
+}
+
+#[component]
+pub fn NavigationMenuSub(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+  #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    direction,
+    root_navigation_menu,
+    ..
+  } = use_context().expect("NavigationMenuSub must be used in a NavigationMenuRoot component");
+
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let value = Signal::derive(move || value.get().unwrap_or_default());
+  let set_value = Callback::new(move |value: String| {
+    set_value.set(value);
+  });
+
+  let orientation = Signal::derive(move || orientation.get());
+
+  let base_id = create_id(id.clone());
+
+  let mut merged_attrs = vec![
+    ("id", id.into_attribute()),
+    (
+      "data-orientation",
+      (move || orientation.get().to_string()).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <NavigationMenuProvider
+      is_root_menu=false
+      base_id=base_id
+      value=value
+      direction=direction
+      orientation=orientation
+      root_navigation_menu=root_navigation_menu
+      on_trigger_enter=set_value
+      on_item_select=set_value
+      on_item_dismiss=Callback::new(move |_| {
+        set_value.call(String::new());
+      })
+    >
+      <Primitive
+        element=html::div
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </NavigationMenuProvider>
+  }
+}
+
+#[component]
+fn NavigationMenuProvider(
+  is_root_menu: bool,
+  base_id: Signal<String>,
+  #[prop(into)] value: Signal<String>,
+  #[prop(into)] direction: Signal<Direction>,
+  #[prop(into)] orientation: Signal<Orientation>,
+  root_navigation_menu: NodeRef<AnyElement>,
+
+  #[prop(default=(|_|{}).into(), into)] on_trigger_enter: Callback<String>,
+  #[prop(default=(|_|{}).into(), into)] on_trigger_leave: Callback<()>,
+  #[prop(default=(|_|{}).into(), into)] on_content_enter: Callback<()>,
+  #[prop(default=(|_|{}).into(), into)] on_content_leave: Callback<()>,
+  #[prop(default=(|_|{}).into(), into)] on_item_select: Callback<String>,
+  #[prop(default=(|_|{}).into(), into)] on_item_dismiss: Callback<()>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let last_values = StoredValue::new((value.get_untracked(), value.get_untracked()));
+
+  let previous_value = Memo::new(move |_| {
+    let current = value.get();
+    let (last, previous) = last_values.get_value();
+
+    if current == last {
+      return previous;
+    }
+
+    last_values.set_value((current, last.clone()));
+    last
+  });
+
+  provide_context(
+    CollectionContextValue::<NavigationMenuItemData, AnyElement> {
+      collection_ref: NodeRef::new(),
+      item_map: RwSignal::new(HashMap::new()),
+    },
+  );
+
+  provide_context(NavigationMenuContextValue {
+    is_root_menu,
+    value,
+    previous_value: previous_value.into(),
+    base_id,
+    direction,
+    orientation,
+    root_navigation_menu,
+    indicator_track: RwSignal::new(None),
+    viewport: RwSignal::new(None),
+    viewport_content: RwSignal::new(HashMap::new()),
+    on_trigger_enter,
+    on_trigger_leave,
+    on_content_enter,
+    on_content_leave,
+    on_item_select,
+    on_item_dismiss,
+  });
+
+  children()
+}
+
+#[component]
+pub fn NavigationMenuList(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    is_root_menu,
+    orientation,
+    indicator_track,
+    ..
+  } = use_context().expect("NavigationMenuList must be used in a NavigationMenuRoot component");
+  let CollectionContextValue::<NavigationMenuItemData, AnyElement> { collection_ref, .. } =
+    use_context().expect("NavigationMenuList must be used in a NavigationMenuRoot component");
+
+  provide_context(CollectionContextValue::<FocusGroupItemData, AnyElement> {
+    collection_ref: node_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      collection_ref.load(&node);
+    }
+  });
+
+  let track_ref = NodeRef::<html::Div>::new();
+
+  Effect::new(move |_| {
+    if let Some(track) = track_ref.get() {
+      let track: web_sys::Element = (*track).clone().into();
+      indicator_track.set(Some(track));
+    }
+  });
+
+  let mut merged_attrs = vec![(
+    "data-orientation",
+    (move || orientation.get().to_string()).into_attribute(),
+  )];
+
+  merged_attrs.extend(attrs);
+
+  let list = view! {
+    <Primitive
+      element=html::ul
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  };
+
+  if is_root_menu {
+    view! {
+      <div style="position: relative;" node_ref=track_ref>
+        {list}
+      </div>
+    }
+    .into_view()
+  } else {
+    list.into_view()
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum FocusProxySide {
+  Start,
+  End,
+}
+
+#[derive(Clone)]
+struct NavigationMenuItemContextValue {
+  value: Signal<String>,
+  trigger_ref: NodeRef<AnyElement>,
+  content_ref: NodeRef<AnyElement>,
+  focus_proxy_ref: NodeRef<html::Span>,
+  was_escape_close: StoredValue<bool>,
+  on_entry_key_down: Callback<()>,
+  on_focus_proxy_enter: Callback<FocusProxySide>,
+  on_root_content_close: Callback<()>,
+  on_content_focus_outside: Callback<()>,
+}
+
+#[component]
+pub fn NavigationMenuItem(
+  #[prop(optional, into)] value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let value = value.get_untracked().unwrap_or_else(use_id);
+  let value = Signal::derive(move || value.clone());
+
+  let content_ref = NodeRef::<AnyElement>::new();
+  let restore_content_tab_order =
+    StoredValue::new(Vec::<(web_sys::HtmlElement, Option<String>)>::new());
+
+  let handle_content_entry = move |side: FocusProxySide| {
+    let Some(content) = content_ref.get_untracked() else {
+      return;
+    };
+
+    restore_tab_order(restore_content_tab_order.get_value());
+    restore_content_tab_order.set_value(vec![]);
+
+    let mut candidates = get_tabbable_candidates(&content);
+
+    if side == FocusProxySide::End {
+      candidates.reverse();
+    }
+
+    focus_first(&candidates);
+  };
+
+  let handle_content_exit = move || {
+    let Some(content) = content_ref.get_untracked() else {
+      return;
+    };
+
+    let candidates = get_tabbable_candidates(&content);
+
+    if !candidates.is_empty() {
+      restore_content_tab_order.set_value(remove_from_tab_order(&candidates));
+    }
+  };
+
+  provide_context(NavigationMenuItemContextValue {
+    value,
+    trigger_ref: NodeRef::new(),
+    content_ref,
+    focus_proxy_ref: NodeRef::new(),
+    was_escape_close: StoredValue::new(false),
+    on_entry_key_down: Callback::new(move |_| handle_content_entry(FocusProxySide::Start)),
+    on_focus_proxy_enter: Callback::new(handle_content_entry),
+    on_root_content_close: Callback::new(move |_| handle_content_exit()),
+    on_content_focus_outside: Callback::new(move |_| handle_content_exit()),
+  });
+
+  view! {
+    <Primitive
+      element=html::li
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn NavigationMenuTrigger(
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_pointer_enter: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_move: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_leave: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    value: menu_value,
+    base_id,
+    direction,
+    orientation,
+    viewport,
+    on_trigger_enter,
+    on_trigger_leave,
+    on_item_select,
+    ..
+  } = use_context().expect("NavigationMenuTrigger must be used in a NavigationMenuRoot component");
+  let NavigationMenuItemContextValue {
+    value,
+    trigger_ref,
+    content_ref,
+    focus_proxy_ref,
+    was_escape_close,
+    on_entry_key_down,
+    on_focus_proxy_enter,
+    ..
+  } = use_context().expect("NavigationMenuTrigger must be used in a NavigationMenuItem component");
+
+  use_collection_item_ref::<AnyElement, NavigationMenuItemData>(
+    node_ref,
+    NavigationMenuItemData {
+      value: value.get_untracked(),
+    },
+  );
+  use_collection_item_ref::<AnyElement, FocusGroupItemData>(node_ref, FocusGroupItemData);
+  let get_focus_group_items = use_collection_context::<FocusGroupItemData, AnyElement>();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let trigger_id = Signal::derive(move || make_trigger_id(&base_id.get(), &value.get()));
+  let content_id = Signal::derive(move || make_content_id(&base_id.get(), &value.get()));
+  let open = Signal::derive(move || value.get() == menu_value.get());
+
+  let has_pointer_move_opened = StoredValue::new(false);
+  let was_click_close = StoredValue::new(false);
+
+  let mut merged_attrs = vec![
+    ("id", (move || trigger_id.get()).into_attribute()),
+    ("disabled", (move || disabled.get()).into_attribute()),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || get_open_state(open.get())).into_attribute(),
+    ),
+    ("aria-expanded", (move || open.get()).into_attribute()),
+    ("aria-controls", (move || content_id.get()).into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::button
+      on:pointerenter=move |ev: PointerEvent| {
+        on_pointer_enter.call(ev);
+
+        was_click_close.set_value(false);
+        was_escape_close.set_value(false);
+      }
+      on:pointermove=move |ev: PointerEvent| {
+        on_pointer_move.call(ev.clone());
+
+        if ev.pointer_type() != "mouse"
+          || disabled.get_untracked()
+          || was_click_close.get_value()
+          || was_escape_close.get_value()
+          || has_pointer_move_opened.get_value()
+        {
+          return;
+        }
+
+        on_trigger_enter.call(value.get_untracked());
+        has_pointer_move_opened.set_value(true);
+      }
+      on:pointerleave=move |ev: PointerEvent| {
+        on_pointer_leave.call(ev.clone());
+
+        if ev.pointer_type() != "mouse" || disabled.get_untracked() {
+          return;
+        }
+
+        on_trigger_leave.call(());
+        has_pointer_move_opened.set_value(false);
+      }
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev);
+
+        let was_open = open.get_untracked();
+
+        on_item_select.call(value.get_untracked());
+        was_click_close.set_value(was_open);
+      }
+      on:keydown=move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        let vertical_entry_key = match direction.get_untracked() {
+          Direction::LeftToRight => "ArrowRight",
+          Direction::RightToLeft => "ArrowLeft",
+        };
+
+        let entry_key = match orientation.get_untracked() {
+          Orientation::Horizontal => "ArrowDown",
+          Orientation::Vertical => vertical_entry_key,
+        };
+
+        if open.get_untracked() && ev.key() == entry_key {
+          on_entry_key_down.call(());
+          ev.prevent_default();
+          return;
+        }
+
+        handle_focus_group_key_down(
+          &ev,
+          get_focus_group_items.get_untracked(),
+          direction.get_untracked(),
+        );
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+
+    <Show when=move || open.get()>
+      <span
+        aria-hidden="true"
+        tabindex="0"
+        style="position: absolute; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; word-wrap: normal;"
+        node_ref=focus_proxy_ref
+        on:focus=move |ev: FocusEvent| {
+          let previously_focused = ev
+            .related_target()
+            .and_then(|target| target.dyn_into::<Node>().ok());
+
+          let was_trigger_focused = trigger_ref
+            .get_untracked()
+            .map(|trigger| trigger.is_same_node(previously_focused.as_ref()))
+            .unwrap_or(false);
+
+          let was_focus_from_content = content_ref
+            .get_untracked()
+            .map(|content| content.contains(previously_focused.as_ref()))
+            .unwrap_or(false);
+
+          if was_trigger_focused || !was_focus_from_content {
+            on_focus_proxy_enter.call(if was_trigger_focused {
+              FocusProxySide::Start
+            } else {
+              FocusProxySide::End
+            });
+          }
+        }
+      />
+
+      <Show when=move || viewport.get().is_some()>
+        <span aria-owns=move || content_id.get() />
+      </Show>
+    </Show>
+  }
+}
+
+#[component]
+pub fn NavigationMenuLink(
+  #[prop(optional, into)] active: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_select: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue { direction, .. } =
+    use_context().expect("NavigationMenuLink must be used in a NavigationMenuRoot component");
+
+  let get_focus_group_items =
+    use_context::<CollectionContextValue<FocusGroupItemData, AnyElement>>().map(|_| {
+      use_collection_item_ref::<AnyElement, FocusGroupItemData>(node_ref, FocusGroupItemData);
+      use_collection_context::<FocusGroupItemData, AnyElement>()
+    });
+
+  let mut merged_attrs = vec![
+    (
+      "aria-current",
+      (move || active.get().then_some("page")).into_attribute(),
+    ),
+    (
+      "data-active",
+      (move || active.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::a
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev.clone());
+
+        let Some(target) = ev.target() else {
+          return;
+        };
+
+        let mut init = CustomEventInit::new();
+        init.bubbles(true).cancelable(true);
+
+        let Ok(link_select) = CustomEvent::new_with_event_init_dict(LINK_SELECT, &init) else {
+          return;
+        };
+
+        let link_select: Event = link_select.into();
+        on_select.call(link_select.clone());
+
+        if link_select.default_prevented() || ev.meta_key() {
+          return;
+        }
+
+        let mut init = EventInit::new();
+        init.bubbles(true).cancelable(true);
+
+        if let Ok(root_content_dismiss) = Event::new_with_event_init_dict(ROOT_CONTENT_DISMISS, &init) {
+          _ = target.dispatch_event(&root_content_dismiss);
+        }
+      }
+      on:keydown=move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        if let Some(get_focus_group_items) = get_focus_group_items {
+          handle_focus_group_key_down(
+            &ev,
+            get_focus_group_items.get_untracked(),
+            direction.get_untracked(),
+          );
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn NavigationMenuIndicator(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    value,
+    indicator_track,
+    ..
+  } =
+    use_context().expect("NavigationMenuIndicator must be used in a NavigationMenuRoot component");
+
+  let is_present = Signal::derive(move || force_mount.get() || !value.get().is_empty());
+  let presence = create_presence(is_present, node_ref);
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  move || {
+    indicator_track.get().map(|track| {
+      view! {
+        <Portal container=track>
+          <Show when=move || presence.get()>
+            {NavigationMenuIndicatorImpl(NavigationMenuIndicatorImplProps {
+              node_ref,
+              attrs: attrs.get_value(),
+              children: children.get_value(),
+              as_child,
+            })}
+          </Show>
+        </Portal>
+      }
+    })
+  }
+}
+
+#[component]
+fn NavigationMenuIndicatorImpl(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    value,
+    orientation,
+    indicator_track,
+    ..
+  } =
+    use_context().expect("NavigationMenuIndicator must be used in a NavigationMenuRoot component");
+
+  let get_items = use_collection_context::<NavigationMenuItemData, AnyElement>();
+
+  let active_trigger = Signal::derive(move || {
+    let value = value.get();
+
+    get_items
+      .get()
+      .into_iter()
+      .find(|(_, data)| data.value == value)
+      .and_then(|(node, _)| node.get())
+      .map(|node| {
+        let node: web_sys::Element = (*node).clone().into();
+        node
+      })
+  });
+
+  let position = RwSignal::new(None::<(i32, i32)>);
+
+  let handle_position_change = move || {
+    let Some(trigger) = active_trigger.get_untracked() else {
+      return;
+    };
+
+    let trigger = trigger.unchecked_into::<web_sys::HtmlElement>();
+
+    position.set(Some(match orientation.get_untracked() {
+      Orientation::Horizontal => (trigger.offset_width(), trigger.offset_left()),
+      Orientation::Vertical => (trigger.offset_height(), trigger.offset_top()),
+    }));
+  };
+
+  Effect::new(move |_| {
+    active_trigger.track();
+    handle_position_change();
+  });
+
+  use_resize_observer(active_trigger, move |_, _| handle_position_change());
+  use_resize_observer(
+    Signal::derive(move || indicator_track.get()),
+    move |_, _| handle_position_change(),
+  );
+
+  let mut merged_attrs = vec![
+    ("aria-hidden", "true".into_attribute()),
+    (
+      "data-state",
+      (move || {
+        if value.get().is_empty() {
+          "hidden"
+        } else {
+          "visible"
+        }
+      })
+      .into_attribute(),
+    ),
+    (
+      "data-orientation",
+      (move || orientation.get().to_string()).into_attribute(),
+    ),
+    (
+      "style",
+      (move || {
+        let (size, offset) = position.get().unwrap_or_default();
+
+        match orientation.get() {
+          Orientation::Horizontal => format!(
+            "position: absolute; left: 0; width: {size}px; transform: translateX({offset}px);"
+          ),
+          Orientation::Vertical => format!(
+            "position: absolute; top: 0; height: {size}px; transform: translateY({offset}px);"
+          ),
+        }
+      })
+      .into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || position.get().is_some()>
+      <Primitive
+        element=html::div
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn NavigationMenuContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    is_root_menu,
+    value: menu_value,
+    previous_value,
+    viewport,
+    viewport_content,
+    on_content_enter,
+    on_content_leave,
+    ..
+  } = use_context().expect("NavigationMenuContent must be used in a NavigationMenuRoot component");
+  let NavigationMenuItemContextValue {
+    value, content_ref, ..
+  } = use_context().expect("NavigationMenuContent must be used in a NavigationMenuItem component");
+
+  let open = Signal::derive(move || value.get() == menu_value.get());
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      content_ref.load(&node);
+    }
+  });
+
+  viewport_content.update(|viewport_content| {
+    viewport_content.insert(value.get_untracked(), node_ref);
+  });
+
+  on_cleanup(move || {
+    _ = viewport_content.try_update(|viewport_content| {
+      viewport_content.remove(&value.get_untracked());
+    });
+  });
+
+  let is_present = Signal::derive(move || {
+    if force_mount.get() {
+      return true;
+    }
+
+    if viewport.get().is_none() {
+      return open.get();
+    }
+
+    let active_value = if menu_value.get().is_empty() {
+      previous_value.get()
+    } else {
+      menu_value.get()
+    };
+
+    active_value == value.get()
+  });
+  let presence = create_presence(is_present, node_ref);
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node.style(
+      "pointer-events",
+      (!open.get() && is_root_menu && viewport.get().is_none()).then_some("none"),
+    );
+  });
+
+  _ = use_event_listener(node_ref, pointerenter, move |_| {
+    if viewport.get_untracked().is_none() {
+      on_content_enter.call(());
+    }
+  });
+
+  _ = use_event_listener(node_ref, pointerleave, move |ev: PointerEvent| {
+    if ev.pointer_type() == "mouse" && viewport.get_untracked().is_none() {
+      on_content_leave.call(());
+    }
+  });
+
+  let mut merged_attrs = vec![(
+    "data-state",
+    (move || get_open_state(open.get())).into_attribute(),
+  )];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  let content = move || {
+    view! {
+      <Show when=move || presence.get()>
+        <NavigationMenuContentImpl
+          on_escape_key_down=on_escape_key_down
+          on_pointer_down_outside=on_pointer_down_outside
+          on_focus_outside=on_focus_outside
+          node_ref=node_ref
+          attrs=merged_attrs.get_value()
+          as_child=as_child
+        >
+          {children.with_value(|children| children())}
+        </NavigationMenuContentImpl>
+      </Show>
+    }
+  };
+
+  // the viewport registers itself from an effect, so the server (and the hydration pass) keeps
+  // content inline next to its trigger; it is only moved into the viewport once mounted
+  move || match viewport.get() {
+    Some(viewport) => view! {
+      <Portal container=viewport>
+        {content}
+      </Portal>
+    }
+    .into_view(),
+    None => content().into_view(),
+  }
+}
+
+#[component]
+fn NavigationMenuContentImpl(
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    is_root_menu,
+    value: menu_value,
+    previous_value,
+    base_id,
+    direction,
+    orientation,
+    root_navigation_menu,
+    viewport,
+    on_item_dismiss,
+    ..
+  } = use_context().expect("NavigationMenuContent must be used in a NavigationMenuRoot component");
+  let NavigationMenuItemContextValue {
+    value,
+    trigger_ref,
+    focus_proxy_ref,
+    was_escape_close,
+    on_root_content_close,
+    on_content_focus_outside,
+    ..
+  } = use_context().expect("NavigationMenuContent must be used in a NavigationMenuItem component");
+
+  let get_items = use_collection_context::<NavigationMenuItemData, AnyElement>();
+
+  provide_context(CollectionContextValue::<FocusGroupItemData, AnyElement> {
+    collection_ref: node_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  let trigger_id = Signal::derive(move || make_trigger_id(&base_id.get(), &value.get()));
+  let content_id = Signal::derive(move || make_content_id(&base_id.get(), &value.get()));
+
+  let prev_motion_attribute = StoredValue::new(None::<&'static str>);
+
+  let motion_attribute = Memo::new(move |_| {
+    let mut values = get_items
+      .get()
+      .into_iter()
+      .map(|(_, data)| data.value)
+      .collect::<Vec<_>>();
+
+    if direction.get() == Direction::RightToLeft {
+      values.reverse();
+    }
+
+    let Some(attribute) = get_motion_attribute(
+      &values,
+      &value.get(),
+      &menu_value.get(),
+      &previous_value.get(),
+    ) else {
+      return prev_motion_attribute.get_value();
+    };
+
+    prev_motion_attribute.set_value(attribute);
+    attribute
+  });
+
+  if is_root_menu {
+    _ = use_event_listener(
+      node_ref,
+      Custom::<Event>::new(ROOT_CONTENT_DISMISS),
+      move |_| {
+        on_item_dismiss.call(());
+        on_root_content_close.call(());
+
+        let Some(content) = node_ref.get_untracked() else {
+          return;
+        };
+
+        if content.contains(document().active_element().as_deref()) {
+          if let Some(trigger) = trigger_ref.get_untracked() {
+            _ = trigger.focus();
+          }
+        }
+      },
+    );
+  }
+
+  _ = use_event_listener(node_ref, keydown, move |ev: KeyboardEvent| {
+    let is_meta_key = ev.alt_key() || ev.ctrl_key() || ev.meta_key();
+
+    if ev.key() != "Tab" || is_meta_key {
+      return;
+    }
+
+    let Some(content) = node_ref.get_untracked() else {
+      return;
+    };
+
+    let candidates = get_tabbable_candidates(&content);
+    let focused_element = document().active_element();
+    let index = candidates.iter().position(|candidate| {
+      focused_element
+        .as_ref()
+        .map(|focused_element| candidate.is_same_node(Some(focused_element)))
+        .unwrap_or(false)
+    });
+
+    let next_candidates = match (index, ev.shift_key()) {
+      (Some(index), true) => candidates[..index].iter().rev().cloned().collect(),
+      (Some(index), false) => candidates[index + 1..].to_vec(),
+      (None, true) => candidates.into_iter().rev().collect(),
+      (None, false) => candidates,
+    };
+
+    if focus_first(&next_candidates) {
+      ev.prevent_default();
+    } else if let Some(focus_proxy) = focus_proxy_ref.get_untracked() {
+      _ = focus_proxy.focus();
+    }
+  });
+
+  let mut merged_attrs = vec![
+    ("id", (move || content_id.get()).into_attribute()),
+    (
+      "aria-labelledby",
+      (move || trigger_id.get()).into_attribute(),
+    ),
+    (
+      "data-motion",
+      (move || motion_attribute.get()).into_attribute(),
+    ),
+    (
+      "data-orientation",
+      (move || orientation.get().to_string()).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <DismissableLayer
+      on_escape_key_down=Callback::new(move |ev: KeyboardEvent| {
+        on_escape_key_down.call(ev.clone());
+
+        if !ev.default_prevented() {
+          was_escape_close.set_value(true);
+        }
+      })
+      on_pointer_down_outside=Callback::new(move |ev: PointerEvent| {
+        on_pointer_down_outside.call(ev.clone());
+
+        let target = ev.target().and_then(|target| target.dyn_into::<Node>().ok());
+
+        let is_trigger = get_items.get_untracked().iter().any(|(node, _)| {
+          node
+            .get_untracked()
+            .map(|node| node.contains(target.as_ref()))
+            .unwrap_or(false)
+        });
+
+        let is_root_viewport = is_root_menu
+          && viewport
+            .get_untracked()
+            .map(|viewport| viewport.contains(target.as_ref()))
+            .unwrap_or(false);
+
+        if is_trigger || is_root_viewport || !is_root_menu {
+          ev.prevent_default();
+        }
+      })
+      on_focus_outside=Callback::new(move |ev: FocusEvent| {
+        on_focus_outside.call(ev.clone());
+
+        if ev.default_prevented() {
+          return;
+        }
+
+        on_content_focus_outside.call(());
+
+        let target = ev.target().and_then(|target| target.dyn_into::<Node>().ok());
+
+        let is_inside_root = root_navigation_menu
+          .get_untracked()
+          .map(|root| root.contains(target.as_ref()))
+          .unwrap_or(false);
+
+        if is_inside_root {
+          ev.prevent_default();
+        }
+      })
+      on_dismiss=Callback::new(move |_| {
+        let Some(content) = node_ref.get_untracked() else {
+          return;
+        };
+
+        let mut init = EventInit::new();
+        init.bubbles(true).cancelable(true);
+
+        if let Ok(root_content_dismiss) = Event::new_with_event_init_dict(ROOT_CONTENT_DISMISS, &init) {
+          _ = content.dispatch_event(&root_content_dismiss);
+        }
+      })
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </DismissableLayer>
+  }
+}
+
+#[component]
+pub fn NavigationMenuViewport(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue { value, .. } =
+    use_context().expect("NavigationMenuViewport must be used in a NavigationMenuRoot component");
+
+  let is_present = Signal::derive(move || force_mount.get() || !value.get().is_empty());
+  let presence = create_presence(is_present, node_ref);
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      {NavigationMenuViewportImpl(NavigationMenuViewportImplProps {
+        node_ref,
+        attrs: attrs.get_value(),
+        children: children.get_value(),
+        as_child,
+      })}
+    </Show>
+  }
+}
+
+#[component]
+fn NavigationMenuViewportImpl(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let NavigationMenuContextValue {
+    is_root_menu,
+    value,
+    previous_value,
+    orientation,
+    viewport,
+    viewport_content,
+    on_content_enter,
+    on_content_leave,
+    ..
+  } = use_context().expect("NavigationMenuViewport must be used in a NavigationMenuRoot component");
+
+  let open = Signal::derive(move || !value.get().is_empty());
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      let node: web_sys::Element = (*node).clone().into();
+      viewport.set(Some(node));
+    }
+  });
+
+  on_cleanup(move || {
+    _ = viewport.try_set(None);
+  });
+
+  let active_content = Signal::derive(move || {
+    let active_value = if open.get() {
+      value.get()
+    } else {
+      previous_value.get()
+    };
+
+    viewport_content
+      .get()
+      .get(&active_value)
+      .and_then(|content| content.get())
+      .map(|content| {
+        let content: web_sys::Element = (*content).clone().into();
+        content
+      })
+  });
+
+  let size = RwSignal::new(None::<(i32, i32)>);
+
+  let handle_size_change = move || {
+    if let Some(content) = active_content.get_untracked() {
+      let content = content.unchecked_into::<web_sys::HtmlElement>();
+      size.set(Some((content.offset_width(), content.offset_height())));
+    }
+  };
+
+  Effect::new(move |_| {
+    active_content.track();
+    handle_size_change();
+  });
+
+  use_resize_observer(active_content, move |_, _| handle_size_change());
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    let (width, height) = size.get().unwrap_or_default();
+
+    _ = node
+      .style(
+        "--leptix-navigation-menu-viewport-width",
+        format!("{width}px"),
+      )
+      .style(
+        "--leptix-navigation-menu-viewport-height",
+        format!("{height}px"),
+      )
+      .style(
+        "pointer-events",
+        (!open.get() && is_root_menu).then_some("none"),
+      );
+  });
+
+  _ = use_event_listener(node_ref, pointerenter, move |_| {
+    on_content_enter.call(());
+  });
+
+  _ = use_event_listener(node_ref, pointerleave, move |ev: PointerEvent| {
+    if ev.pointer_type() == "mouse" {
+      on_content_leave.call(());
+    }
+  });
+
+  let mut merged_attrs = vec![
+    (
+      "data-state",
+      (move || get_open_state(open.get())).into_attribute(),
+    ),
+    (
+      "data-orientation",
+      (move || orientation.get().to_string()).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.as_ref().map(|children| children())}
+    </Primitive>
+  }
+}
+
+fn handle_focus_group_key_down(
+  ev: &KeyboardEvent,
+  items: Vec<(NodeRef<AnyElement>, FocusGroupItemData)>,
+  direction: Direction,
+) {
+  let key = ev.key();
+
+  if key != "Home" && key != "End" && !ARROW_KEYS.contains(&key.as_str()) {
+    return;
+  }
+
+  let mut candidates = items
+    .into_iter()
+    .filter_map(|(node, _)| node.get_untracked())
+    .map(|node| (*node).clone())
+    .collect::<Vec<_>>();
+
+  let prev_item_key = match direction {
+    Direction::LeftToRight => "ArrowLeft",
+    Direction::RightToLeft => "ArrowRight",
+  };
+
+  if [prev_item_key, "ArrowUp", "End"].contains(&key.as_str()) {
+    candidates.reverse();
+  }
+
+  if ARROW_KEYS.contains(&key.as_str()) {
+    let current_target = ev
+      .current_target()
+      .and_then(|target| target.dyn_into::<Node>().ok());
+
+    if let Some(index) = candidates
+      .iter()
+      .position(|candidate| candidate.is_same_node(current_target.as_ref()))
+    {
+      candidates = candidates.split_off(index + 1);
+    }
+  }
+
+  set_timeout(
+    move || {
+      focus_first(&candidates);
+    },
+    Duration::ZERO,
+  );
+
+  ev.prevent_default();
+}
+
+fn get_motion_attribute(
+  values: &[String],
+  value: &str,
+  current_value: &str,
+  previous_value: &str,
+) -> Option<Option<&'static str>> {
+  let is_selected = value == current_value;
+  let was_selected = value == previous_value;
+
+  if !is_selected && !was_selected {
+    return None;
+  }
+
+  let index = values.iter().position(|value| value == current_value);
+  let prev_index = values.iter().position(|value| value == previous_value);
+
+  if index == prev_index {
+    return Some(None);
+  }
+
+  Some(match (index, prev_index) {
+    (Some(index), Some(prev_index)) if is_selected => Some(if index > prev_index {
+      "from-end"
+    } else {
+      "from-start"
+    }),
+    (Some(index), Some(prev_index)) if was_selected => Some(if index > prev_index {
+      "to-start"
+    } else {
+      "to-end"
+    }),
+    _ => None,
+  })
+}
+
+fn remove_from_tab_order(
+  candidates: &[web_sys::HtmlElement],
+) -> Vec<(web_sys::HtmlElement, Option<String>)> {
+  candidates
+    .iter()
+    .map(|candidate| {
+      let tab_index = candidate.get_attribute("tabindex");
+      _ = candidate.set_attribute("tabindex", "-1");
+
+      (candidate.clone(), tab_index)
+    })
+    .collect()
+}
+
+fn restore_tab_order(candidates: Vec<(web_sys::HtmlElement, Option<String>)>) {
+  for (candidate, tab_index) in candidates {
+    match tab_index {
+      Some(tab_index) => _ = candidate.set_attribute("tabindex", &tab_index),
+      None => _ = candidate.remove_attribute("tabindex"),
+    }
+  }
+}
+
+fn clear_timer(timer: StoredValue<Option<TimeoutHandle>>) {
+  if let Some(handle) = timer.get_value() {
+    handle.clear();
+  }
+}
+
+fn make_trigger_id(base_id: &str, value: &str) -> String {
+  format!("{base_id}-trigger-{value}")
+}
+
+fn make_content_id(base_id: &str, value: &str) -> String {
+  format!("{base_id}-content-{value}")
+}
+
+fn get_open_state(open: bool) -> &'static str {
+  if open {
+    "open"
+  } else {
+    "closed"
+  }
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn open_content_renders_inline_on_the_server_with_a_viewport() {
+  let runtime = create_runtime();
+
+  let html = ssr::render_to_string(|| {
+    view! {
+      <NavigationMenuRoot default_value="products">
+        <NavigationMenuList>
+          <NavigationMenuItem value="products">
+            <NavigationMenuTrigger>"Products"</NavigationMenuTrigger>
+            <NavigationMenuContent>"Catalogue"</NavigationMenuContent>
+          </NavigationMenuItem>
+        </NavigationMenuList>
+        <NavigationMenuViewport />
+      </NavigationMenuRoot>
+    }
+  })
+  .to_string();
+
+  let content = html.find("Catalogue").expect("content should be rendered");
+  let viewport = html
+    .find("leptos-navigation-menu-viewport-start")
+    .expect("viewport should be rendered");
+
+  assert!(content < viewport);
+
+  runtime.dispose();
+}
+
+#[test]
+fn motion_follows_direction_between_items() {
+  let values = ["a", "b", "c"].map(String::from);
+
+  assert_eq!(
+    get_motion_attribute(&values, "c", "c", "a"),
+    Some(Some("from-end"))
+  );
+  assert_eq!(
+    get_motion_attribute(&values, "a", "c", "a"),
+    Some(Some("to-start"))
+  );
+  assert_eq!(
+    get_motion_attribute(&values, "a", "a", "c"),
+    Some(Some("from-start"))
+  );
+  assert_eq!(
+    get_motion_attribute(&values, "c", "a", "c"),
+    Some(Some("to-end"))
+  );
+  assert_eq!(get_motion_attribute(&values, "b", "b", ""), Some(None));
+  assert_eq!(get_motion_attribute(&values, "b", "c", "a"), None);
+}
@@ -0,0 +1,704 @@
+use std::time::Duration;
+
+use leptos::{
+  html::AnyElement,
+  leptos_dom::helpers::{IntervalHandle, TimeoutHandle},
+  *,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+  js_sys::{Array, Intl, Object, Reflect},
+  FocusEvent, HtmlInputElement, KeyboardEvent, MouseEvent, PointerEvent,
+};
+
+use crate::{
+  form::{use_form_control_attrs, use_form_field_name},
+  primitive::Primitive,
+  slider::{get_decimal_count, round_value},
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    Attributes,
+  },
+  Orientation,
+};
+
+const AUTO_REPEAT_DELAY: Duration = Duration::from_millis(400);
+const AUTO_REPEAT_INTERVAL: Duration = Duration::from_millis(60);
+
+#[derive(Clone, Copy)]
+struct NumberFieldContextValue {
+  value: Signal<Option<f64>>,
+  on_value_change: Callback<Option<f64>>,
+  on_value_commit: Callback<Option<f64>>,
+  min: Signal<Option<f64>>,
+  max: Signal<Option<f64>>,
+  step: Signal<f64>,
+  large_step: Signal<f64>,
+  locale: Signal<Option<String>>,
+  disabled: Signal<bool>,
+  read_only: Signal<bool>,
+  required: Signal<bool>,
+  input_ref: NodeRef<AnyElement>,
+}
+
+impl NumberFieldContextValue {
+  fn snap(&self, value: f64) -> f64 {
+    snap_value(
+      value,
+      self.min.get_untracked(),
+      self.max.get_untracked(),
+      self.step.get_untracked(),
+    )
+  }
+
+  fn commit_input(&self) {
+    let Some(input) = self.input() else {
+      return;
+    };
+
+    let locale = self.locale.get_untracked();
+    let (group, decimal) = get_separators(locale.as_deref());
+    let text = input.value();
+
+    let next_value = if text.trim().is_empty() {
+      None
+    } else {
+      parse_number(&text, &group, &decimal)
+        .map(|value| self.snap(value))
+        .or(self.value.get_untracked())
+    };
+
+    if next_value != self.value.get_untracked() {
+      self.on_value_change.call(next_value);
+      self.on_value_commit.call(next_value);
+    }
+
+    input.set_value(
+      &next_value
+        .map(|value| format_number(value, locale.as_deref()))
+        .unwrap_or_default(),
+    );
+  }
+
+  fn increment_by(&self, amount: f64) {
+    if self.disabled.get_untracked() || self.read_only.get_untracked() {
+      return;
+    }
+
+    self.commit_input();
+
+    let current = self
+      .value
+      .get_untracked()
+      .or(self.min.get_untracked())
+      .unwrap_or(0.0);
+
+    self.set_value(current + amount);
+  }
+
+  fn set_value(&self, value: f64) {
+    let next_value = Some(self.snap(value));
+
+    if next_value != self.value.get_untracked() {
+      self.on_value_change.call(next_value);
+      self.on_value_commit.call(next_value);
+    }
+  }
+
+  fn input(&self) -> Option<HtmlInputElement> {
+    self
+      .input_ref
+      .get_untracked()
+      .and_then(|node| node.dyn_ref::<HtmlInputElement>().cloned())
+  }
+}
+
+#[component]
+pub fn NumberFieldRoot(
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] min: MaybeProp<f64>,
+  #[prop(optional, into)] max: MaybeProp<f64>,
+  #[prop(default=1.0f64.into(), into)] step: MaybeSignal<f64>,
+  #[prop(default=10.0f64.into(), into)] large_step: MaybeSignal<f64>,
+  #[prop(optional, into)] value: MaybeProp<f64>,
+  #[prop(optional, into)] default_value: MaybeProp<f64>,
+  #[prop(optional, into)] locale: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] read_only: MaybeSignal<bool>,
+  #[prop(optional, into)] required: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<Option<f64>>,
+  #[prop(default=(|_|{}).into(), into)] on_value_commit: Callback<Option<f64>>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get().map(Some)),
+    default_value: Signal::derive(move || Some(default_value.get())),
+    on_change: on_value_change,
+  });
+
+  let value = Signal::derive(move || value.get().flatten());
+  let disabled = Signal::derive(move || disabled.get());
+  let field_name = use_form_field_name();
+  let name = Signal::derive(move || name.get().or_else(|| field_name.map(|name| name.get())));
+
+  provide_context(NumberFieldContextValue {
+    value,
+    on_value_change: Callback::new(move |value: Option<f64>| {
+      set_value.set(value);
+    }),
+    on_value_commit,
+    min: Signal::derive(move || min.get()),
+    max: Signal::derive(move || max.get()),
+    step: Signal::derive(move || step.get()),
+    large_step: Signal::derive(move || large_step.get()),
+    locale: Signal::derive(move || locale.get()),
+    disabled,
+    read_only: Signal::derive(move || read_only.get()),
+    required: Signal::derive(move || required.get()),
+    input_ref: NodeRef::new(),
+  });
+
+  let mut merged_attrs = vec![
+    ("role", "group".into_attribute()),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+
+      {move || name.get().map(|name| view! {
+        <input
+          type="hidden"
+          name=name
+          value=move || value.get().map(|value| value.to_string()).unwrap_or_default()
+          disabled=move || disabled.get()
+        />
+      })}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn NumberFieldInput(
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_blur: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<NumberFieldContextValue>()
+    .expect("NumberFieldInput must be used in a NumberFieldRoot component");
+  let NumberFieldContextValue {
+    value,
+    min,
+    max,
+    step,
+    large_step,
+    locale,
+    disabled,
+    read_only,
+    required,
+    input_ref,
+    ..
+  } = context;
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      input_ref.load(&node);
+    }
+  });
+
+  let value_text = RwSignal::new(None::<String>);
+
+  Effect::new(move |_| {
+    let formatted = value
+      .get()
+      .map(|value| format_number(value, locale.get().as_deref()));
+    let text = formatted.clone().unwrap_or_default();
+
+    value_text.set(formatted);
+
+    if let Some(input) = context.input() {
+      if input.value() != text {
+        input.set_value(&text);
+      }
+    }
+  });
+
+  let mut merged_attrs = use_form_control_attrs();
+  merged_attrs.extend([
+    ("type", "text".into_attribute()),
+    ("role", "spinbutton".into_attribute()),
+    ("inputmode", "decimal".into_attribute()),
+    ("autocomplete", "off".into_attribute()),
+    ("autocorrect", "off".into_attribute()),
+    ("spellcheck", "false".into_attribute()),
+    ("aria-valuenow", (move || value.get()).into_attribute()),
+    ("aria-valuemin", (move || min.get()).into_attribute()),
+    ("aria-valuemax", (move || max.get()).into_attribute()),
+    (
+      "aria-valuetext",
+      (move || value_text.get()).into_attribute(),
+    ),
+    (
+      "aria-required",
+      (move || required.get().then_some("true")).into_attribute(),
+    ),
+    ("disabled", (move || disabled.get()).into_attribute()),
+    ("readonly", (move || read_only.get()).into_attribute()),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ]);
+
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::input
+      on:keydown=move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        if ev.default_prevented() || disabled.get_untracked() || read_only.get_untracked() {
+          return;
+        }
+
+        let step = step.get_untracked();
+        let large_step = large_step.get_untracked();
+
+        match ev.key().as_str() {
+          "ArrowUp" => context.increment_by(if ev.shift_key() { large_step } else { step }),
+          "ArrowDown" => context.increment_by(-if ev.shift_key() { large_step } else { step }),
+          "PageUp" => context.increment_by(large_step),
+          "PageDown" => context.increment_by(-large_step),
+          "Home" => match min.get_untracked() {
+            Some(min) => context.set_value(min),
+            None => return,
+          },
+          "End" => match max.get_untracked() {
+            Some(max) => context.set_value(max),
+            None => return,
+          },
+          "Enter" => {
+            context.commit_input();
+            return;
+          }
+          _ => return,
+        }
+
+        ev.prevent_default();
+      }
+      on:blur=move |ev: FocusEvent| {
+        on_blur.call(ev);
+        context.commit_input();
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn NumberFieldIncrement(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <NumberFieldStepper
+      direction=1.0
+      label="Increase"
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </NumberFieldStepper>
+  }
+}
+
+#[component]
+pub fn NumberFieldDecrement(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <NumberFieldStepper
+      direction=-1.0
+      label="Decrease"
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </NumberFieldStepper>
+  }
+}
+
+#[component]
+fn NumberFieldStepper(
+  direction: f64,
+  label: &'static str,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<NumberFieldContextValue>().expect(
+    "NumberFieldIncrement and NumberFieldDecrement must be used in a NumberFieldRoot component",
+  );
+  let NumberFieldContextValue {
+    value,
+    min,
+    max,
+    step,
+    disabled,
+    read_only,
+    input_ref,
+    ..
+  } = context;
+
+  let is_at_limit = Signal::derive(move || {
+    let limit = if direction > 0.0 {
+      max.get()
+    } else {
+      min.get()
+    };
+
+    match (value.get(), limit) {
+      (Some(value), Some(limit)) => (value - limit) * direction >= 0.0,
+      _ => false,
+    }
+  });
+  let is_disabled = Signal::derive(move || disabled.get() || read_only.get() || is_at_limit.get());
+
+  let repeat_timeout = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let repeat_interval = StoredValue::<Option<IntervalHandle>>::new(None);
+
+  let stop_repeat = move || {
+    if let Some(handle) = repeat_timeout.try_get_value().flatten() {
+      handle.clear();
+    }
+
+    if let Some(handle) = repeat_interval.try_get_value().flatten() {
+      handle.clear();
+    }
+
+    _ = repeat_timeout.try_set_value(None);
+    _ = repeat_interval.try_set_value(None);
+  };
+
+  on_cleanup(stop_repeat);
+
+  let step_once = move || context.increment_by(step.get_untracked() * direction);
+
+  let mut merged_attrs = vec![
+    ("type", "button".into_attribute()),
+    ("tabindex", (-1).into_attribute()),
+    ("aria-label", label.into_attribute()),
+    (
+      "aria-controls",
+      (move || input_ref.get().map(|input| web_sys::Element::id(&input))).into_attribute(),
+    ),
+    ("disabled", (move || is_disabled.get()).into_attribute()),
+    (
+      "data-disabled",
+      (move || is_disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::button
+      on:pointerdown=move |ev: PointerEvent| {
+        if ev.button() != 0 || is_disabled.get_untracked() {
+          return;
+        }
+
+        ev.prevent_default();
+        stop_repeat();
+        step_once();
+
+        if let Ok(handle) = set_timeout_with_handle(
+          move || {
+            if let Ok(handle) = set_interval_with_handle(
+              move || {
+                if is_disabled.get_untracked() {
+                  stop_repeat();
+                } else {
+                  step_once();
+                }
+              },
+              AUTO_REPEAT_INTERVAL,
+            ) {
+              _ = repeat_interval.try_set_value(Some(handle));
+            }
+          },
+          AUTO_REPEAT_DELAY,
+        ) {
+          repeat_timeout.set_value(Some(handle));
+        }
+      }
+      on:pointerup=move |_: PointerEvent| stop_repeat()
+      on:pointerleave=move |_: PointerEvent| stop_repeat()
+      on:pointercancel=move |_: PointerEvent| stop_repeat()
+      on:click=move |ev: MouseEvent| {
+        // pointer presses are handled on pointerdown, this only covers assistive technology clicks
+        if ev.detail() == 0 && !is_disabled.get_untracked() {
+          step_once();
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn NumberFieldScrubArea(
+  #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
+  #[prop(default=2.0f64.into(), into)] pixel_sensitivity: MaybeSignal<f64>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<NumberFieldContextValue>()
+    .expect("NumberFieldScrubArea must be used in a NumberFieldRoot component");
+  let NumberFieldContextValue {
+    step,
+    large_step,
+    disabled,
+    read_only,
+    ..
+  } = context;
+
+  let is_scrubbing = RwSignal::new(false);
+  let accumulated = StoredValue::new(0.0f64);
+
+  let stop_scrubbing = move || {
+    if !is_scrubbing.get_untracked() {
+      return;
+    }
+
+    is_scrubbing.set(false);
+    accumulated.set_value(0.0);
+
+    if document().pointer_lock_element().is_some() {
+      document().exit_pointer_lock();
+    }
+  };
+
+  let mut merged_attrs = vec![
+    ("role", "presentation".into_attribute()),
+    (
+      "data-orientation",
+      (move || orientation.get().to_string()).into_attribute(),
+    ),
+    (
+      "data-scrubbing",
+      (move || is_scrubbing.get().then_some("")).into_attribute(),
+    ),
+    (
+      "style",
+      "touch-action: none; user-select: none;".into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::span
+      on:pointerdown=move |ev: PointerEvent| {
+        if ev.button() != 0 || disabled.get_untracked() || read_only.get_untracked() {
+          return;
+        }
+
+        ev.prevent_default();
+
+        if let Some(node) = node_ref.get_untracked() {
+          _ = node.set_pointer_capture(ev.pointer_id());
+
+          if ev.pointer_type() == "mouse" {
+            node.request_pointer_lock();
+          }
+        }
+
+        accumulated.set_value(0.0);
+        is_scrubbing.set(true);
+      }
+      on:pointermove=move |ev: PointerEvent| {
+        if !is_scrubbing.get_untracked() {
+          return;
+        }
+
+        let delta = match orientation.get_untracked() {
+          Orientation::Horizontal => ev.movement_x() as f64,
+          Orientation::Vertical => -ev.movement_y() as f64,
+        };
+
+        let sensitivity = pixel_sensitivity.get_untracked().max(1.0);
+        let total = accumulated.get_value() + delta;
+        let steps = (total / sensitivity).trunc();
+
+        accumulated.set_value(total - steps * sensitivity);
+
+        if steps != 0.0 {
+          let amount = if ev.shift_key() {
+            large_step.get_untracked()
+          } else {
+            step.get_untracked()
+          };
+
+          context.increment_by(amount * steps);
+        }
+      }
+      on:pointerup=move |_: PointerEvent| stop_scrubbing()
+      on:pointercancel=move |_: PointerEvent| stop_scrubbing()
+      on:lostpointercapture=move |_: PointerEvent| stop_scrubbing()
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+fn snap_value(value: f64, min: Option<f64>, max: Option<f64>, step: f64) -> f64 {
+  let base = min.unwrap_or(0.0);
+  let snapped = if step > 0.0 {
+    round_value(
+      ((value - base) / step).round() * step + base,
+      get_decimal_count(step) as u32,
+    )
+  } else {
+    value
+  };
+
+  snapped
+    .max(min.unwrap_or(f64::NEG_INFINITY))
+    .min(max.unwrap_or(f64::INFINITY))
+}
+
+fn get_number_format(locale: Option<&str>) -> Intl::NumberFormat {
+  let locales = Array::new();
+  if let Some(locale) = locale {
+    locales.push(&JsValue::from_str(locale));
+  }
+
+  let options = Object::new();
+  _ = Reflect::set(
+    &options,
+    &JsValue::from_str("maximumFractionDigits"),
+    &JsValue::from_f64(20.0),
+  );
+
+  Intl::NumberFormat::new(&locales, &options)
+}
+
+fn format_number(value: f64, locale: Option<&str>) -> String {
+  get_number_format(locale)
+    .format()
+    .call1(&JsValue::NULL, &JsValue::from_f64(value))
+    .ok()
+    .and_then(|formatted| formatted.as_string())
+    .unwrap_or_else(|| value.to_string())
+}
+
+fn get_separators(locale: Option<&str>) -> (String, String) {
+  let parts = get_number_format(locale).format_to_parts(11111.1);
+  let mut group = String::from(",");
+  let mut decimal = String::from(".");
+
+  for part in parts.iter() {
+    let get = |key: &str| {
+      Reflect::get(&part, &JsValue::from_str(key))
+        .ok()
+        .and_then(|value| value.as_string())
+    };
+
+    match (get("type").as_deref(), get("value")) {
+      (Some("group"), Some(value)) => group = value,
+      (Some("decimal"), Some(value)) => decimal = value,
+      _ => {}
+    }
+  }
+
+  (group, decimal)
+}
+
+fn parse_number(text: &str, group: &str, decimal: &str) -> Option<f64> {
+  let normalized = text
+    .trim()
+    .replace(group, "")
+    .replace(['\u{a0}', '\u{202f}', ' '], "")
+    .replace(decimal, ".")
+    .replace('\u{2212}', "-");
+
+  normalized
+    .parse()
+    .ok()
+    .filter(|value: &f64| value.is_finite())
+}
+
+#[test]
+fn values_snap_to_step_within_bounds() {
+  assert_eq!(snap_value(1.26, None, None, 0.1), 1.3);
+  assert_eq!(snap_value(7.0, Some(1.0), None, 5.0), 6.0);
+  assert_eq!(snap_value(120.0, Some(0.0), Some(100.0), 1.0), 100.0);
+  assert_eq!(snap_value(-3.0, Some(0.0), None, 1.0), 0.0);
+}
+
+#[test]
+fn localized_numbers_are_parsed_with_locale_separators() {
+  assert_eq!(parse_number("1,234.5", ",", "."), Some(1234.5));
+  assert_eq!(parse_number("1.234,5", ".", ","), Some(1234.5));
+  assert_eq!(
+    parse_number("1\u{202f}234,5", "\u{202f}", ","),
+    Some(1234.5)
+  );
+  assert_eq!(parse_number("\u{2212}12", ",", "."), Some(-12.0));
+  assert_eq!(parse_number("abc", ",", "."), None);
+}
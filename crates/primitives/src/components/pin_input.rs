@@ -0,0 +1,475 @@
+use std::collections::HashMap;
+
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsCast;
+use web_sys::{ClipboardEvent, Event, FocusEvent, HtmlInputElement, KeyboardEvent};
+
+use crate::{
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  form::use_form_field_name,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    use_id::use_id,
+    Attributes,
+  },
+};
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum PinInputType {
+  #[default]
+  Numeric,
+  Alphanumeric,
+}
+
+impl PinInputType {
+  fn is_valid(&self, char: char) -> bool {
+    match self {
+      Self::Numeric => char.is_ascii_digit(),
+      Self::Alphanumeric => char.is_ascii_alphanumeric(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct PinInputFieldData {
+  id: String,
+}
+
+#[derive(Clone, Copy)]
+struct PinInputContextValue {
+  values: Signal<Vec<String>>,
+  on_values_change: Callback<Vec<String>>,
+  fields: Signal<Vec<(NodeRef<AnyElement>, PinInputFieldData)>>,
+  pin_type: Signal<PinInputType>,
+  mask: Signal<bool>,
+  otp: Signal<bool>,
+  placeholder: Signal<Option<String>>,
+  disabled: Signal<bool>,
+}
+
+impl PinInputContextValue {
+  fn focus_field(&self, index: usize) {
+    let fields = self.fields.get_untracked();
+    let Some((node_ref, _)) = fields.get(index.min(fields.len().saturating_sub(1))) else {
+      return;
+    };
+
+    if let Some(node) = node_ref.get_untracked() {
+      _ = node.focus();
+    }
+  }
+
+  fn fill_from(&self, index: usize, chars: &[char]) -> Option<usize> {
+    let count = self.fields.with_untracked(|fields| fields.len());
+    let (next_values, last_index) =
+      distribute_chars(&self.values.get_untracked(), index, chars, count)?;
+
+    self.on_values_change.call(next_values);
+
+    Some(last_index)
+  }
+}
+
+#[component]
+pub fn PinInputRoot(
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] value: MaybeProp<Vec<String>>,
+  #[prop(optional, into)] default_value: MaybeProp<Vec<String>>,
+  #[prop(optional, into)] pin_type: MaybeSignal<PinInputType>,
+  #[prop(optional, into)] mask: MaybeSignal<bool>,
+  #[prop(default=true.into(), into)] otp: MaybeSignal<bool>,
+  #[prop(optional, into)] placeholder: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<Vec<String>>,
+  #[prop(default=(|_|{}).into(), into)] on_complete: Callback<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (values, set_values) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  provide_context(CollectionContextValue::<PinInputFieldData, AnyElement> {
+    collection_ref: node_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  let fields = use_collection_context::<PinInputFieldData, AnyElement>();
+  let values = Signal::derive(move || values.get().unwrap_or_default());
+  let disabled = Signal::derive(move || disabled.get());
+  let field_name = use_form_field_name();
+  let name = Signal::derive(move || name.get().or_else(|| field_name.map(|name| name.get())));
+
+  provide_context(PinInputContextValue {
+    values,
+    on_values_change: Callback::new(move |next_values: Vec<String>| {
+      let count = fields.with_untracked(|fields| fields.len());
+      let was_complete = values.with_untracked(|values| is_complete(values, count));
+      let is_now_complete = is_complete(&next_values, count);
+
+      set_values.set(next_values.clone());
+
+      if is_now_complete && !was_complete {
+        on_complete.call(next_values.concat());
+      }
+    }),
+    fields,
+    pin_type: Signal::derive(move || pin_type.get()),
+    mask: Signal::derive(move || mask.get()),
+    otp: Signal::derive(move || otp.get()),
+    placeholder: Signal::derive(move || placeholder.get()),
+    disabled,
+  });
+
+  let mut merged_attrs = vec![
+    ("role", "group".into_attribute()),
+    (
+      "data-complete",
+      (move || {
+        let count = fields.with(|fields| fields.len());
+
+        values
+          .with(|values| is_complete(values, count))
+          .then_some("")
+      })
+      .into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+
+      {move || name.get().map(|name| view! {
+        <input
+          type="hidden"
+          name=name
+          value=move || values.get().concat()
+          disabled=move || disabled.get()
+        />
+      })}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn PinInputField(
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let context = use_context::<PinInputContextValue>()
+    .expect("PinInputField must be used in a PinInputRoot component");
+  let PinInputContextValue {
+    values,
+    on_values_change,
+    fields,
+    pin_type,
+    mask,
+    otp,
+    placeholder,
+    disabled,
+  } = context;
+
+  let id = use_id();
+  let data = PinInputFieldData { id: id.clone() };
+
+  use_collection_item_ref::<AnyElement, PinInputFieldData>(node_ref, data);
+
+  let index =
+    Signal::derive(move || fields.with(|fields| fields.iter().position(|(_, data)| data.id == id)));
+  let value = Signal::derive(move || {
+    index
+      .get()
+      .and_then(|index| values.with(|values| values.get(index).cloned()))
+      .unwrap_or_default()
+  });
+
+  Effect::new(move |_| {
+    let value = value.get();
+
+    if let Some(input) = node_ref
+      .get()
+      .and_then(|node| node.dyn_ref::<HtmlInputElement>().cloned())
+    {
+      if input.value() != value {
+        input.set_value(&value);
+      }
+    }
+  });
+
+  let set_value = move |value: String| {
+    let Some(index) = index.get_untracked() else {
+      return;
+    };
+
+    let mut next_values = values.get_untracked();
+    if next_values.len() <= index {
+      next_values.resize(index + 1, String::new());
+    }
+
+    next_values[index] = value;
+    on_values_change.call(next_values);
+  };
+
+  let mut merged_attrs = vec![
+    (
+      "type",
+      (move || if mask.get() { "password" } else { "text" }).into_attribute(),
+    ),
+    (
+      "inputmode",
+      (move || match pin_type.get() {
+        PinInputType::Numeric => "numeric",
+        PinInputType::Alphanumeric => "text",
+      })
+      .into_attribute(),
+    ),
+    (
+      "autocomplete",
+      (move || {
+        if otp.get() && index.get() == Some(0) {
+          "one-time-code"
+        } else {
+          "off"
+        }
+      })
+      .into_attribute(),
+    ),
+    (
+      "aria-label",
+      (move || {
+        index.get().map(|index| {
+          format!(
+            "Character {} of {}",
+            index + 1,
+            fields.with(|fields| fields.len())
+          )
+        })
+      })
+      .into_attribute(),
+    ),
+    ("placeholder", (move || placeholder.get()).into_attribute()),
+    ("disabled", (move || disabled.get()).into_attribute()),
+    (
+      "data-complete",
+      (move || (!value.with(|value| value.is_empty())).then_some("")).into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::input
+      on:input=move |ev: Event| {
+        let Some(index) = index.get_untracked() else {
+          return;
+        };
+
+        let text = event_target_value(&ev);
+        let chars = text
+          .chars()
+          .filter(|char| pin_type.get_untracked().is_valid(*char))
+          .collect::<Vec<_>>();
+
+        let previous = value.get_untracked();
+        let chars = match chars.as_slice() {
+          [] => {
+            if let Some(input) = node_ref
+              .get_untracked()
+              .and_then(|node| node.dyn_ref::<HtmlInputElement>().cloned())
+            {
+              input.set_value(if text.is_empty() { "" } else { &previous });
+            }
+
+            if text.is_empty() {
+              set_value(String::new());
+            }
+
+            return;
+          }
+          [_, second] if previous.chars().next() == chars.first().copied() => vec![*second],
+          chars => chars.to_vec(),
+        };
+
+        if let Some(last_index) = context.fill_from(index, &chars) {
+          context.focus_field(last_index + 1);
+        }
+      }
+      on:keydown=move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        let Some(index) = index.get_untracked() else {
+          return;
+        };
+
+        if ev.default_prevented() {
+          return;
+        }
+
+        match ev.key().as_str() {
+          "Backspace" => {
+            ev.prevent_default();
+
+            if value.with_untracked(|value| value.is_empty()) && index > 0 {
+              let mut next_values = values.get_untracked();
+              if let Some(previous) = next_values.get_mut(index - 1) {
+                previous.clear();
+              }
+
+              on_values_change.call(next_values);
+              context.focus_field(index - 1);
+            } else {
+              set_value(String::new());
+            }
+          }
+          "Delete" => {
+            ev.prevent_default();
+            set_value(String::new());
+          }
+          "ArrowLeft" if index > 0 => {
+            ev.prevent_default();
+            context.focus_field(index - 1);
+          }
+          "ArrowRight" => {
+            ev.prevent_default();
+            context.focus_field(index + 1);
+          }
+          "Home" => {
+            ev.prevent_default();
+            context.focus_field(0);
+          }
+          "End" => {
+            ev.prevent_default();
+            context.focus_field(usize::MAX);
+          }
+          _ => {}
+        }
+      }
+      on:paste=move |ev: Event| {
+        ev.prevent_default();
+
+        let Some(index) = index.get_untracked() else {
+          return;
+        };
+
+        let chars = ev
+          .unchecked_ref::<ClipboardEvent>()
+          .clipboard_data()
+          .and_then(|data| data.get_data("text").ok())
+          .unwrap_or_default()
+          .chars()
+          .filter(|char| pin_type.get_untracked().is_valid(*char))
+          .collect::<Vec<_>>();
+
+        if let Some(last_index) = context.fill_from(index, &chars) {
+          context.focus_field(last_index + 1);
+        }
+      }
+      on:focus=move |ev: FocusEvent| {
+        on_focus.call(ev);
+
+        if let Some(input) = node_ref
+          .get_untracked()
+          .and_then(|node| node.dyn_ref::<HtmlInputElement>().cloned())
+        {
+          input.select();
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </Primitive>
+  }
+}
+
+fn is_complete(values: &[String], count: usize) -> bool {
+  count > 0 && values.len() >= count && values.iter().take(count).all(|value| !value.is_empty())
+}
+
+fn distribute_chars(
+  values: &[String],
+  index: usize,
+  chars: &[char],
+  count: usize,
+) -> Option<(Vec<String>, usize)> {
+  if chars.is_empty() || index >= count {
+    return None;
+  }
+
+  let mut next_values = values.to_vec();
+  next_values.resize(count.max(values.len()), String::new());
+
+  let written = chars.iter().take(count - index).enumerate();
+  let mut last_index = index;
+
+  for (offset, char) in written {
+    next_values[index + offset] = char.to_string();
+    last_index = index + offset;
+  }
+
+  Some((next_values, last_index))
+}
+
+#[test]
+fn pasted_characters_are_distributed_across_remaining_cells() {
+  let values = vec!["1".to_string(), String::new()];
+
+  assert_eq!(
+    distribute_chars(&values, 1, &['2', '3', '4'], 4),
+    Some((["1", "2", "3", "4"].map(String::from).to_vec(), 3))
+  );
+  assert_eq!(
+    distribute_chars(&values, 2, &['9', '8', '7'], 4),
+    Some((["1", "", "9", "8"].map(String::from).to_vec(), 3))
+  );
+  assert_eq!(distribute_chars(&values, 0, &[], 4), None);
+  assert!(PinInputType::Numeric.is_valid('7'));
+  assert!(!PinInputType::Numeric.is_valid('a'));
+  assert!(PinInputType::Alphanumeric.is_valid('K'));
+  assert!(!PinInputType::Alphanumeric.is_valid('é'));
+  assert!(!PinInputType::Alphanumeric.is_valid('٣'));
+}
+
+#[test]
+fn completion_requires_every_registered_cell() {
+  let values = ["1", "2", "3"].map(String::from).to_vec();
+
+  assert!(is_complete(&values, 3));
+  assert!(!is_complete(&values, 4));
+  assert!(!is_complete(&["1", ""].map(String::from), 2));
+  assert!(!is_complete(&[], 0));
+}
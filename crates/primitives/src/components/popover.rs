@@ -0,0 +1,468 @@
+use leptos::{html::AnyElement, *};
+use wasm_bindgen::JsCast;
+use web_sys::{Event, FocusEvent, HtmlElement, KeyboardEvent, MouseEvent, Node, PointerEvent};
+
+use crate::{
+  dismissable_layer::DismissableLayer,
+  focus_scope::FocusScope,
+  popper::{
+    Align, PopperAnchor, PopperArrow, PopperArrowProps, PopperContent, PopperRoot, Side, Sticky,
+  },
+  portal::{Portal, PortalContainer, PortalProps},
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    hide_others::{create_hide_others, HideOthersMode},
+    scroll_lock::create_scroll_lock,
+    use_id::{create_id, create_part_id},
+    Attributes,
+  },
+};
+
+#[derive(Clone)]
+struct PopoverContextValue {
+  trigger_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  open: Signal<bool>,
+  on_open_change: Callback<bool>,
+  on_open_toggle: Callback<()>,
+  has_custom_anchor: RwSignal<bool>,
+  modal: Signal<bool>,
+}
+
+#[component]
+pub fn PopoverRoot(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] modal: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let id = create_id(id);
+
+  provide_context(PopoverContextValue {
+    trigger_ref: NodeRef::new(),
+    content_id: create_part_id(id, "content"),
+    open: Signal::derive(move || open.get().unwrap_or(false)),
+    on_open_change: Callback::new(move |open| {
+      set_open.set(open);
+    }),
+    on_open_toggle: Callback::new(move |_| {
+      set_open.update(|open| *open = Some(!open.unwrap_or(false)));
+    }),
+    has_custom_anchor: RwSignal::new(false),
+    modal: Signal::derive(move || modal.get()),
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub fn PopoverAnchor(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopoverContextValue {
+    has_custom_anchor, ..
+  } = use_context().expect("PopoverAnchor must be used in a PopoverRoot component");
+
+  has_custom_anchor.set(true);
+
+  on_cleanup(move || {
+    _ = has_custom_anchor.try_set(false);
+  });
+
+  view! {
+    <PopperAnchor
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </PopperAnchor>
+  }
+}
+
+#[component]
+pub fn PopoverTrigger(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopoverContextValue {
+    trigger_ref,
+    content_id,
+    open,
+    on_open_toggle,
+    has_custom_anchor,
+    ..
+  } = use_context().expect("PopoverTrigger must be used in a PopoverRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let mut merged_attrs = vec![
+    ("type", "button".into_attribute()),
+    ("aria-haspopup", "dialog".into_attribute()),
+    ("aria-expanded", (move || open.get()).into_attribute()),
+    ("aria-controls", (move || content_id.get()).into_attribute()),
+    (
+      "data-state",
+      (move || get_state(open.get())).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  let trigger = move || {
+    view! {
+      <Primitive
+        element=html::button
+        on:click=move |ev: MouseEvent| {
+          on_click.call(ev);
+          on_open_toggle.call(());
+        }
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    }
+  };
+
+  view! {
+    <Show
+      when=move || has_custom_anchor.get()
+      fallback=move || view! {
+        <PopperAnchor as_child=true node_ref=node_ref>
+          {trigger()}
+        </PopperAnchor>
+      }
+    >
+      {trigger()}
+    </Show>
+  }
+}
+
+#[derive(Clone)]
+struct PopoverPortalContextValue {
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub fn PopoverPortal(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(PopoverPortalContextValue {
+    force_mount: Signal::derive(move || force_mount.get()),
+  });
+
+  Portal(PortalProps {
+    container,
+    children,
+  })
+}
+
+#[component]
+pub fn PopoverContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus_outside: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopoverContextValue {
+    trigger_ref,
+    content_id,
+    open,
+    on_open_change,
+    modal,
+    ..
+  } = use_context().expect("PopoverContent must be used in a PopoverRoot component");
+  let portal_force_mount = use_context::<PopoverPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  let is_present =
+    Signal::derive(move || force_mount.get() || portal_force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+  let layer_ref = NodeRef::<AnyElement>::new();
+
+  let has_interacted_outside = StoredValue::new(false);
+
+  let is_modal_open = Signal::derive(move || modal.get() && open.get());
+  create_scroll_lock(is_modal_open);
+  create_hide_others(node_ref, is_modal_open, HideOthersMode::AriaHidden);
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-popover-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-popover-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-popover-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-popover-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-popover-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let mut merged_attrs = vec![
+    ("role", "dialog".into_attribute()),
+    ("id", (move || content_id.get()).into_attribute()),
+    (
+      "data-state",
+      (move || get_state(open.get())).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let collision_boundary = StoredValue::new(collision_boundary);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <FocusScope
+        as_child=true
+        should_loop=true
+        trapped=is_modal_open
+        on_mount_auto_focus=on_open_auto_focus
+        on_unmount_auto_focus=Callback::new(move |ev: Event| {
+          on_close_auto_focus.call(ev.clone());
+
+          if ev.default_prevented() {
+            return;
+          }
+
+          if modal.get_untracked() || !has_interacted_outside.get_value() {
+            if let Some(trigger) = trigger_ref.get_untracked() {
+              _ = trigger.focus();
+            }
+          }
+
+          ev.prevent_default();
+        })
+        node_ref=layer_ref
+      >
+        <DismissableLayer
+          as_child=true
+          disable_outside_pointer_events=modal
+          on_escape_key_down=on_escape_key_down
+          on_pointer_down_outside=Callback::new(move |ev: PointerEvent| {
+            on_pointer_down_outside.call(ev.clone());
+
+            if modal.get_untracked() {
+              let is_right_click = ev.button() == 2 || (ev.button() == 0 && ev.ctrl_key());
+
+              if is_right_click {
+                ev.prevent_default();
+              }
+
+              return;
+            }
+
+            if !ev.default_prevented() {
+              has_interacted_outside.set_value(true);
+            }
+
+            if is_trigger(trigger_ref, ev.target()) {
+              ev.prevent_default();
+            }
+          })
+          on_focus_outside=Callback::new(move |ev: FocusEvent| {
+            on_focus_outside.call(ev.clone());
+
+            if modal.get_untracked() {
+              ev.prevent_default();
+              return;
+            }
+
+            if !ev.default_prevented() {
+              has_interacted_outside.set_value(true);
+            }
+
+            if is_trigger(trigger_ref, ev.target()) {
+              ev.prevent_default();
+            }
+          })
+          on_dismiss=Callback::new(move |_| on_open_change.call(false))
+          node_ref=layer_ref
+        >
+          <PopperContent
+            side=side
+            side_offset=side_offset
+            align=align
+            align_offset=align_offset
+            arrow_padding=arrow_padding
+            avoid_collisions=avoid_collisions
+            collision_boundary=collision_boundary.get_value()
+            collision_padding=collision_padding
+            sticky=sticky
+            hide_when_detached=hide_when_detached
+            node_ref=node_ref
+            attrs=merged_attrs.get_value()
+            as_child=as_child
+          >
+            {children.with_value(|children| children())}
+          </PopperContent>
+        </DismissableLayer>
+      </FocusScope>
+    </Show>
+  }
+}
+
+#[component]
+pub fn PopoverClose(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopoverContextValue { on_open_change, .. } =
+    use_context().expect("PopoverClose must be used in a PopoverRoot component");
+
+  let mut merged_attrs = vec![("type", "button".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::button
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev);
+        on_open_change.call(false);
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn PopoverArrow(
+  #[prop(default=10.0f64.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0f64.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+  PopperArrow(PopperArrowProps {
+    width,
+    height,
+    attrs,
+    children,
+  })
+}
+
+fn get_state(open: bool) -> &'static str {
+  if open {
+    "open"
+  } else {
+    "closed"
+  }
+}
+
+fn is_trigger(trigger_ref: NodeRef<AnyElement>, target: Option<web_sys::EventTarget>) -> bool {
+  let Some(trigger) = trigger_ref.get_untracked() else {
+    return false;
+  };
+
+  let target = target.and_then(|target| target.dyn_into::<Node>().ok());
+  let trigger: &HtmlElement = &trigger;
+
+  trigger.contains(target.as_ref())
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn default_open_popover_renders_on_the_server() {
+  let runtime = create_runtime();
+
+  let html = ssr::render_to_string(|| {
+    view! {
+      <PopoverRoot default_open=true>
+        <PopoverTrigger>"Filters"</PopoverTrigger>
+        <PopoverContent>"Panel"</PopoverContent>
+      </PopoverRoot>
+    }
+  })
+  .to_string();
+
+  assert!(html.contains("role=\"dialog\""));
+  assert!(html.contains("data-state=\"open\""));
+  assert!(html.contains("Panel"));
+
+  runtime.dispose();
+}
@@ -0,0 +1,798 @@
+use leptos::{
+  ev::{resize, scroll},
+  html::AnyElement,
+  *,
+};
+use leptos_use::{
+  use_event_listener_with_options, use_resize_observer, use_window, UseEventListenerOptions,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{DomRect, HtmlElement};
+
+use crate::{primitive::Primitive, util::Attributes};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+  pub x: f64,
+  pub y: f64,
+  pub width: f64,
+  pub height: f64,
+}
+
+impl Rect {
+  pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+    Self {
+      x,
+      y,
+      width,
+      height,
+    }
+  }
+
+  pub fn right(&self) -> f64 {
+    self.x + self.width
+  }
+
+  pub fn bottom(&self) -> f64 {
+    self.y + self.height
+  }
+
+  fn inset(&self, padding: f64) -> Self {
+    Self {
+      x: self.x + padding,
+      y: self.y + padding,
+      width: (self.width - padding * 2.).max(0.),
+      height: (self.height - padding * 2.).max(0.),
+    }
+  }
+
+  fn intersect(&self, other: &Self) -> Self {
+    let x = self.x.max(other.x);
+    let y = self.y.max(other.y);
+
+    Self {
+      x,
+      y,
+      width: (self.right().min(other.right()) - x).max(0.),
+      height: (self.bottom().min(other.bottom()) - y).max(0.),
+    }
+  }
+}
+
+impl From<DomRect> for Rect {
+  fn from(rect: DomRect) -> Self {
+    Self::new(rect.x(), rect.y(), rect.width(), rect.height())
+  }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display)]
+pub enum Side {
+  #[strum(to_string = "top")]
+  Top,
+  #[strum(to_string = "right")]
+  Right,
+  #[default]
+  #[strum(to_string = "bottom")]
+  Bottom,
+  #[strum(to_string = "left")]
+  Left,
+}
+
+impl Side {
+  pub fn opposite(&self) -> Self {
+    match self {
+      Self::Top => Self::Bottom,
+      Self::Right => Self::Left,
+      Self::Bottom => Self::Top,
+      Self::Left => Self::Right,
+    }
+  }
+
+  fn is_vertical(&self) -> bool {
+    matches!(self, Self::Top | Self::Bottom)
+  }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, strum_macros::Display)]
+pub enum Align {
+  #[strum(to_string = "start")]
+  Start,
+  #[default]
+  #[strum(to_string = "center")]
+  Center,
+  #[strum(to_string = "end")]
+  End,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Sticky {
+  #[default]
+  Partial,
+  Always,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PositionOptions {
+  pub side: Side,
+  pub align: Align,
+  pub side_offset: f64,
+  pub align_offset: f64,
+  pub arrow: Option<(f64, f64)>,
+  pub arrow_padding: f64,
+  pub avoid_collisions: bool,
+  pub collision_padding: f64,
+  pub sticky: Sticky,
+}
+
+impl Default for PositionOptions {
+  fn default() -> Self {
+    Self {
+      side: Side::default(),
+      align: Align::default(),
+      side_offset: 0.,
+      align_offset: 0.,
+      arrow: None,
+      arrow_padding: 0.,
+      avoid_collisions: true,
+      collision_padding: 0.,
+      sticky: Sticky::default(),
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Position {
+  pub x: f64,
+  pub y: f64,
+  pub side: Side,
+  pub align: Align,
+  pub arrow_x: Option<f64>,
+  pub arrow_y: Option<f64>,
+  pub should_hide_arrow: bool,
+  pub available_width: f64,
+  pub available_height: f64,
+  pub anchor_hidden: bool,
+}
+
+pub fn compute_position(
+  anchor: Rect,
+  (width, height): (f64, f64),
+  boundary: Rect,
+  options: PositionOptions,
+) -> Position {
+  let floating = Rect::new(0., 0., width, height);
+  let boundary = boundary.inset(options.collision_padding);
+  let arrow_height = options.arrow.map(|(_, height)| height).unwrap_or(0.);
+  let main_offset = options.side_offset + arrow_height;
+
+  let place = |side: Side| {
+    let (x, y) = get_coords(&anchor, &floating, side, options.align);
+    apply_offset(
+      (x, y),
+      side,
+      options.align,
+      main_offset,
+      options.align_offset,
+    )
+  };
+
+  let mut side = options.side;
+  let (mut x, mut y) = place(side);
+
+  if options.avoid_collisions {
+    let overflow = get_side_overflow(Rect::new(x, y, width, height), &boundary, side);
+
+    if overflow > 0. {
+      let flipped = side.opposite();
+      let (flipped_x, flipped_y) = place(flipped);
+      let flipped_overflow = get_side_overflow(
+        Rect::new(flipped_x, flipped_y, width, height),
+        &boundary,
+        flipped,
+      );
+
+      if flipped_overflow < overflow {
+        side = flipped;
+        (x, y) = (flipped_x, flipped_y);
+      }
+    }
+
+    if side.is_vertical() {
+      x = shift(
+        x,
+        width,
+        (anchor.x, anchor.right()),
+        (boundary.x, boundary.right()),
+        options.sticky,
+      );
+    } else {
+      y = shift(
+        y,
+        height,
+        (anchor.y, anchor.bottom()),
+        (boundary.y, boundary.bottom()),
+        options.sticky,
+      );
+    }
+  }
+
+  let (available_width, available_height) = match side {
+    Side::Top => (boundary.width, y + height - boundary.y),
+    Side::Bottom => (boundary.width, boundary.bottom() - y),
+    Side::Left => (x + width - boundary.x, boundary.height),
+    Side::Right => (boundary.right() - x, boundary.height),
+  };
+
+  let (arrow_x, arrow_y, should_hide_arrow) = match options.arrow {
+    Some((arrow_width, _)) => {
+      let (anchor_center, floating_start, floating_size) = if side.is_vertical() {
+        (anchor.x + anchor.width / 2., x, width)
+      } else {
+        (anchor.y + anchor.height / 2., y, height)
+      };
+
+      let center = anchor_center - floating_start - arrow_width / 2.;
+      let max = (floating_size - arrow_width - options.arrow_padding).max(options.arrow_padding);
+      let offset = center.clamp(options.arrow_padding, max);
+      let should_hide_arrow = (center - offset).abs() > f64::EPSILON;
+
+      if side.is_vertical() {
+        (Some(offset), None, should_hide_arrow)
+      } else {
+        (None, Some(offset), should_hide_arrow)
+      }
+    }
+    None => (None, None, false),
+  };
+
+  Position {
+    x,
+    y,
+    side,
+    align: options.align,
+    arrow_x,
+    arrow_y,
+    should_hide_arrow,
+    available_width: available_width.max(0.),
+    available_height: available_height.max(0.),
+    anchor_hidden: anchor.bottom() <= boundary.y
+      || anchor.y >= boundary.bottom()
+      || anchor.right() <= boundary.x
+      || anchor.x >= boundary.right(),
+  }
+}
+
+fn get_coords(anchor: &Rect, floating: &Rect, side: Side, align: Align) -> (f64, f64) {
+  let center_x = anchor.x + anchor.width / 2. - floating.width / 2.;
+  let center_y = anchor.y + anchor.height / 2. - floating.height / 2.;
+
+  let (x, y) = match side {
+    Side::Top => (center_x, anchor.y - floating.height),
+    Side::Bottom => (center_x, anchor.bottom()),
+    Side::Left => (anchor.x - floating.width, center_y),
+    Side::Right => (anchor.right(), center_y),
+  };
+
+  match (align, side.is_vertical()) {
+    (Align::Center, _) => (x, y),
+    (Align::Start, true) => (anchor.x, y),
+    (Align::Start, false) => (x, anchor.y),
+    (Align::End, true) => (anchor.right() - floating.width, y),
+    (Align::End, false) => (x, anchor.bottom() - floating.height),
+  }
+}
+
+fn apply_offset(
+  (x, y): (f64, f64),
+  side: Side,
+  align: Align,
+  side_offset: f64,
+  align_offset: f64,
+) -> (f64, f64) {
+  let align_offset = if align == Align::End {
+    -align_offset
+  } else {
+    align_offset
+  };
+
+  match side {
+    Side::Top => (x + align_offset, y - side_offset),
+    Side::Bottom => (x + align_offset, y + side_offset),
+    Side::Left => (x - side_offset, y + align_offset),
+    Side::Right => (x + side_offset, y + align_offset),
+  }
+}
+
+fn get_side_overflow(floating: Rect, boundary: &Rect, side: Side) -> f64 {
+  match side {
+    Side::Top => boundary.y - floating.y,
+    Side::Bottom => floating.bottom() - boundary.bottom(),
+    Side::Left => boundary.x - floating.x,
+    Side::Right => floating.right() - boundary.right(),
+  }
+}
+
+fn shift(
+  value: f64,
+  size: f64,
+  (anchor_start, anchor_end): (f64, f64),
+  (boundary_start, boundary_end): (f64, f64),
+  sticky: Sticky,
+) -> f64 {
+  let value = value.min(boundary_end - size).max(boundary_start);
+
+  match sticky {
+    Sticky::Always => value,
+    Sticky::Partial => value.min(anchor_end).max(anchor_start - size),
+  }
+}
+
+#[derive(Clone)]
+struct PopperContextValue {
+  anchor_ref: NodeRef<AnyElement>,
+  virtual_anchor: RwSignal<Option<Rect>>,
+}
+
+#[component]
+pub fn PopperRoot(children: ChildrenFn) -> impl IntoView {
+  provide_context(PopperContextValue {
+    anchor_ref: NodeRef::new(),
+    virtual_anchor: RwSignal::new(None),
+  });
+
+  children()
+}
+
+#[component]
+pub fn PopperAnchor(
+  #[prop(optional, into)] virtual_ref: MaybeProp<Rect>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopperContextValue {
+    anchor_ref,
+    virtual_anchor,
+  } = use_context().expect("PopperAnchor must be used in a PopperRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      anchor_ref.load(&node);
+    }
+  });
+
+  Effect::new(move |_| {
+    virtual_anchor.set(virtual_ref.get());
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || virtual_ref.get().is_none()>
+      <Primitive
+        element=html::div
+        node_ref=node_ref
+        attrs=attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[derive(Clone)]
+struct PopperContentContextValue {
+  placed_side: Signal<Side>,
+  arrow_x: Signal<Option<f64>>,
+  arrow_y: Signal<Option<f64>>,
+  should_hide_arrow: Signal<bool>,
+  arrow_size: RwSignal<Option<(f64, f64)>>,
+}
+
+#[component]
+pub fn PopperContent(
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_placed: Callback<()>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let PopperContextValue {
+    anchor_ref,
+    virtual_anchor,
+  } = use_context().expect("PopperContent must be used in a PopperRoot component");
+
+  let arrow_size = RwSignal::new(None::<(f64, f64)>);
+  let (position, set_position) = create_signal(None::<(Position, Rect)>);
+  let collision_boundary = StoredValue::new(collision_boundary);
+
+  let update_position = move || {
+    let Some(content) = node_ref.get_untracked() else {
+      return;
+    };
+
+    let Some(anchor_rect) = virtual_anchor.get_untracked().or_else(|| {
+      anchor_ref
+        .get_untracked()
+        .map(|anchor| Rect::from(anchor.get_bounding_client_rect()))
+    }) else {
+      return;
+    };
+
+    let Some(content) = content.dyn_ref::<HtmlElement>() else {
+      return;
+    };
+
+    let Some(document_element) = document().document_element() else {
+      return;
+    };
+
+    let boundary = collision_boundary.with_value(|boundary| {
+      boundary
+        .iter()
+        .filter_map(|node| node.get_untracked())
+        .fold(
+          Rect::new(
+            0.,
+            0.,
+            document_element.client_width() as f64,
+            document_element.client_height() as f64,
+          ),
+          |boundary, node| boundary.intersect(&node.get_bounding_client_rect().into()),
+        )
+    });
+
+    let next_position = compute_position(
+      anchor_rect,
+      (
+        content.offset_width() as f64,
+        content.offset_height() as f64,
+      ),
+      boundary,
+      PositionOptions {
+        side: side.get_untracked(),
+        align: align.get_untracked(),
+        side_offset: side_offset.get_untracked(),
+        align_offset: align_offset.get_untracked(),
+        arrow: arrow_size.get_untracked(),
+        arrow_padding: arrow_padding.get_untracked(),
+        avoid_collisions: avoid_collisions.get_untracked(),
+        collision_padding: collision_padding.get_untracked(),
+        sticky: sticky.get_untracked(),
+      },
+    );
+
+    if position.get_untracked() != Some((next_position, anchor_rect)) {
+      set_position.set(Some((next_position, anchor_rect)));
+    }
+  };
+
+  Effect::new(move |_| {
+    _ = (anchor_ref.get(), node_ref.get(), arrow_size.get());
+    _ = virtual_anchor.get();
+    _ = (
+      side.get(),
+      side_offset.get(),
+      align.get(),
+      align_offset.get(),
+    );
+    _ = (
+      arrow_padding.get(),
+      avoid_collisions.get(),
+      collision_padding.get(),
+    );
+    _ = sticky.get();
+
+    update_position();
+  });
+
+  use_resize_observer(node_ref, move |_, _| update_position());
+  use_resize_observer(anchor_ref, move |_, _| update_position());
+
+  _ = use_event_listener_with_options(
+    use_window(),
+    scroll,
+    move |_| update_position(),
+    UseEventListenerOptions::default()
+      .capture(true)
+      .passive(true),
+  );
+
+  _ = use_event_listener_with_options(
+    use_window(),
+    resize,
+    move |_| update_position(),
+    UseEventListenerOptions::default().passive(true),
+  );
+
+  let is_positioned = Signal::derive(move || position.with(Option::is_some));
+  let placed_side = Signal::derive(move || {
+    position
+      .get()
+      .map(|(position, _)| position.side)
+      .unwrap_or(side.get())
+  });
+  let placed_align = Signal::derive(move || {
+    position
+      .get()
+      .map(|(position, _)| position.align)
+      .unwrap_or(align.get())
+  });
+
+  Effect::new(move |_| {
+    if is_positioned.get() {
+      on_placed.call(());
+    }
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node.style("animation", (!is_positioned.get()).then_some("none"));
+  });
+
+  let content_z_index = Signal::derive(move || {
+    node_ref
+      .get()
+      .and_then(|node| window().get_computed_style(&node).ok().flatten())
+      .and_then(|style| style.get_property_value("z-index").ok())
+  });
+
+  provide_context(PopperContentContextValue {
+    placed_side,
+    arrow_x: Signal::derive(move || position.get().and_then(|(position, _)| position.arrow_x)),
+    arrow_y: Signal::derive(move || position.get().and_then(|(position, _)| position.arrow_y)),
+    should_hide_arrow: Signal::derive(move || {
+      position
+        .get()
+        .map(|(position, _)| position.should_hide_arrow)
+        .unwrap_or(false)
+    }),
+    arrow_size,
+  });
+
+  let wrapper_style = move || {
+    let Some((position, anchor)) = position.get() else {
+      return "position: fixed; left: 0; top: 0; transform: translate(0, -200%); min-width: max-content;".to_string();
+    };
+
+    let (arrow_width, arrow_height) = arrow_size.get().unwrap_or((0., 0.));
+    let origin_x = match (position.arrow_x, position.align) {
+      (Some(arrow_x), _) => format!("{}px", arrow_x + arrow_width / 2.),
+      (None, Align::Start) => "0%".to_string(),
+      (None, Align::Center) => "50%".to_string(),
+      (None, Align::End) => "100%".to_string(),
+    };
+    let origin_y = match (position.arrow_y, position.align) {
+      (Some(arrow_y), _) => format!("{}px", arrow_y + arrow_width / 2.),
+      (None, Align::Start) => "0%".to_string(),
+      (None, Align::Center) => "50%".to_string(),
+      (None, Align::End) => "100%".to_string(),
+    };
+    let transform_origin = match position.side {
+      Side::Top => format!("{origin_x} calc(100% + {arrow_height}px)"),
+      Side::Bottom => format!("{origin_x} {}px", -arrow_height),
+      Side::Left => format!("calc(100% + {arrow_height}px) {origin_y}"),
+      Side::Right => format!("{}px {origin_y}", -arrow_height),
+    };
+
+    let is_hidden = hide_when_detached.get() && position.anchor_hidden;
+
+    format!(
+      "position: fixed; left: 0; top: 0; transform: translate({}px, {}px); min-width: max-content;{} --leptix-popper-transform-origin: {transform_origin}; --leptix-popper-available-width: {}px; --leptix-popper-available-height: {}px; --leptix-popper-anchor-width: {}px; --leptix-popper-anchor-height: {}px;{}",
+      position.x.round(),
+      position.y.round(),
+      content_z_index
+        .get()
+        .map(|z_index| format!(" z-index: {z_index};"))
+        .unwrap_or_default(),
+      position.available_width,
+      position.available_height,
+      anchor.width,
+      anchor.height,
+      if is_hidden {
+        " visibility: hidden; pointer-events: none;"
+      } else {
+        ""
+      }
+    )
+  };
+
+  let mut merged_attrs = vec![
+    (
+      "data-side",
+      (move || placed_side.get().to_string()).into_attribute(),
+    ),
+    (
+      "data-align",
+      (move || placed_align.get().to_string()).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <div data-leptix-popper-content-wrapper="" style=wrapper_style>
+      <Primitive
+        element=html::div
+        node_ref=node_ref
+        attrs=merged_attrs
+        as_child=as_child
+      >
+        {children()}
+      </Primitive>
+    </div>
+  }
+}
+
+#[component]
+pub fn PopperArrow(
+  #[prop(default=10.0f64.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0f64.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+  let PopperContentContextValue {
+    placed_side,
+    arrow_x,
+    arrow_y,
+    should_hide_arrow,
+    arrow_size,
+  } = use_context().expect("PopperArrow must be used in a PopperContent component");
+
+  Effect::new(move |_| {
+    arrow_size.set(Some((width.get(), height.get())));
+  });
+
+  on_cleanup(move || {
+    _ = arrow_size.try_set(None);
+  });
+
+  let wrapper_style = move || {
+    let side = placed_side.get();
+
+    let (transform_origin, transform) = match side {
+      Side::Top => ("", "translateY(100%)"),
+      Side::Right => ("0 0", "translateY(50%) rotate(90deg) translateX(-50%)"),
+      Side::Bottom => ("center 0", "rotate(180deg)"),
+      Side::Left => ("100% 0", "translateY(50%) rotate(-90deg) translateX(50%)"),
+    };
+
+    format!(
+      "position: absolute;{}{} {}: 0; transform-origin: {transform_origin}; transform: {transform};{}",
+      arrow_x
+        .get()
+        .map(|x| format!(" left: {x}px;"))
+        .unwrap_or_default(),
+      arrow_y
+        .get()
+        .map(|y| format!(" top: {y}px;"))
+        .unwrap_or_default(),
+      side.opposite(),
+      if should_hide_arrow.get() {
+        " visibility: hidden;"
+      } else {
+        ""
+      }
+    )
+  };
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <span style=wrapper_style>
+      {children.with_value(|children| match children {
+        Some(children) => children().into_view(),
+        None => view! {
+          <svg
+            {..attrs.clone()}
+            width=move || width.get()
+            height=move || height.get()
+            viewBox="0 0 30 10"
+            preserveAspectRatio="none"
+            style="display: block"
+          >
+            <polygon points="0,0 30,0 15,10" />
+          </svg>
+        }
+        .into_view(),
+      })}
+    </span>
+  }
+}
+
+#[test]
+fn positions_below_anchor_by_default() {
+  let position = compute_position(
+    Rect::new(100., 100., 50., 20.),
+    (80., 40.),
+    Rect::new(0., 0., 1000., 1000.),
+    PositionOptions {
+      side_offset: 4.,
+      ..Default::default()
+    },
+  );
+
+  assert_eq!(position.side, Side::Bottom);
+  assert_eq!((position.x, position.y), (85., 124.));
+  assert_eq!(position.available_height, 876.);
+}
+
+#[test]
+fn flips_to_opposite_side_on_collision() {
+  let position = compute_position(
+    Rect::new(100., 950., 50., 20.),
+    (80., 40.),
+    Rect::new(0., 0., 1000., 1000.),
+    PositionOptions::default(),
+  );
+
+  assert_eq!(position.side, Side::Top);
+  assert_eq!(position.y, 910.);
+}
+
+#[test]
+fn shifts_along_alignment_axis_within_boundary() {
+  let position = compute_position(
+    Rect::new(0., 100., 20., 20.),
+    (100., 40.),
+    Rect::new(0., 0., 1000., 1000.),
+    PositionOptions {
+      align: Align::Center,
+      collision_padding: 8.,
+      ..Default::default()
+    },
+  );
+
+  assert_eq!(position.x, 8.);
+
+  let position = compute_position(
+    Rect::new(-50., 100., 20., 20.),
+    (100., 40.),
+    Rect::new(0., 0., 1000., 1000.),
+    PositionOptions::default(),
+  );
+
+  assert_eq!(position.x, -30.);
+  assert!(position.anchor_hidden);
+}
+
+#[test]
+fn centers_arrow_on_anchor() {
+  let position = compute_position(
+    Rect::new(100., 100., 40., 20.),
+    (200., 40.),
+    Rect::new(0., 0., 1000., 1000.),
+    PositionOptions {
+      side: Side::Right,
+      align: Align::Start,
+      arrow: Some((10., 5.)),
+      ..Default::default()
+    },
+  );
+
+  assert_eq!((position.x, position.y), (145., 100.));
+  assert_eq!(position.arrow_y, Some(5.));
+  assert!(!position.should_hide_arrow);
+}
@@ -0,0 +1,75 @@
+use leptos::{html::AnyElement, *};
+
+#[derive(Clone)]
+pub enum PortalContainer {
+  Element(web_sys::Element),
+  NodeRef(NodeRef<AnyElement>),
+  Selector(String),
+}
+
+impl From<web_sys::Element> for PortalContainer {
+  fn from(element: web_sys::Element) -> Self {
+    Self::Element(element)
+  }
+}
+
+impl From<NodeRef<AnyElement>> for PortalContainer {
+  fn from(node_ref: NodeRef<AnyElement>) -> Self {
+    Self::NodeRef(node_ref)
+  }
+}
+
+impl From<String> for PortalContainer {
+  fn from(selector: String) -> Self {
+    Self::Selector(selector)
+  }
+}
+
+impl From<&str> for PortalContainer {
+  fn from(selector: &str) -> Self {
+    Self::Selector(selector.to_string())
+  }
+}
+
+#[component]
+pub fn Portal(
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let is_mounted = RwSignal::new(false);
+
+  Effect::new(move |_| {
+    is_mounted.set(true);
+  });
+
+  let container = StoredValue::new(container);
+
+  let mount = Signal::derive(move || {
+    if !is_mounted.get() {
+      return None;
+    }
+
+    container.with_value(|container| match container {
+      None => document().body().map(Into::into),
+      Some(PortalContainer::Element(element)) => Some(element.clone()),
+      Some(PortalContainer::NodeRef(node_ref)) => node_ref.get().map(|node| (**node).clone()),
+      Some(PortalContainer::Selector(selector)) => {
+        document().query_selector(selector).ok().flatten()
+      }
+    })
+  });
+
+  let children = StoredValue::new(children);
+
+  move || {
+    mount.get().map(|mount| {
+      leptos::Portal(leptos::PortalProps {
+        mount: Some(mount),
+        use_shadow: false,
+        is_svg: false,
+        children: children.get_value(),
+      })
+    })
+  }
+}
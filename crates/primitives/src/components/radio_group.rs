@@ -8,6 +8,7 @@ use wasm_bindgen::JsCast;
 use web_sys::{FocusEvent, HtmlButtonElement, KeyboardEvent};
 
 use crate::{
+  form::{use_form_control_attrs, use_form_field_name},
   primitive::Primitive,
   radio::{Radio, RadioIndicator},
   roving_focus::{RovingFocusGroup, RovingFocusGroupItem},
@@ -20,7 +21,7 @@ use crate::{
 
 #[derive(Clone)]
 struct RadioGroupContextValue {
-  name: MaybeProp<String>,
+  name: Signal<Option<String>>,
   required: Signal<bool>,
   disabled: Signal<bool>,
   value: Signal<Option<String>>,
@@ -52,8 +53,10 @@ pub fn RadioGroupRoot(
     on_change: on_value_change,
   });
 
+  let field_name = use_form_field_name();
+
   provide_context(RadioGroupContextValue {
-    name,
+    name: Signal::derive(move || name.get().or_else(|| field_name.map(|name| name.get()))),
     required: Signal::derive(move || required.get()),
     disabled: Signal::derive(move || disabled.get()),
     value: Signal::derive(move || value.get()),
@@ -76,6 +79,7 @@ pub fn RadioGroupRoot(
     ),
   ];
 
+  merged_attrs.extend(use_form_control_attrs());
   merged_attrs.extend(attrs);
 
   let children = StoredValue::new(children);
@@ -151,7 +155,7 @@ pub fn RadioGroupItem(
         disabled=is_disabled
         required=required
         checked=is_checked
-        name=name.clone()
+        name=name
         on_check=Callback::new(move |_| on_value_change.call(value.get_value().get()))
         on:keydown=move |ev: KeyboardEvent| {
           on_key_down.call(ev.clone());
@@ -12,7 +12,7 @@ use crate::{
   primitive::Primitive,
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
-    create_id::create_id,
+    use_id::use_id,
     Attributes,
   },
   Direction, Orientation,
@@ -83,12 +83,13 @@ pub(crate) fn RovingFocusGroup(
   #[prop(default=(|_|{}).into(), into)] on_focus: Callback<FocusEvent>,
   #[prop(default=(|_|{}).into(), into)] on_blur: Callback<FocusEvent>,
 
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
   #[prop(attrs)] attrs: Attributes,
   children: ChildrenFn,
 
   #[prop(optional, into)] as_child: MaybeProp<bool>,
 ) -> impl IntoView {
-  let collection_ref = NodeRef::<html::AnyElement>::new();
+  let collection_ref = node_ref;
 
   provide_context(CollectionContextValue::<ItemData, _> {
     collection_ref,
@@ -243,7 +244,8 @@ pub(crate) fn RovingFocusGroupItem(
   } = use_context::<RovingContextValue>()
     .expect("RovingFocusGroupItem must be used in a RovingFocusGroup component");
 
-  let id = Signal::derive(move || tab_stop_id.get().unwrap_or(create_id().get()));
+  let generated_id = use_id();
+  let id = Signal::derive(move || tab_stop_id.get().unwrap_or_else(|| generated_id.clone()));
 
   use_collection_item_ref::<html::AnyElement, ItemData>(
     node_ref,
@@ -0,0 +1,2112 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use leptos::{
+  ev::{blur, contextmenu, keydown, pointermove, pointerup, resize},
+  html::{AnyElement, Select},
+  leptos_dom::helpers::{IntervalHandle, TimeoutHandle},
+  *,
+};
+use leptos_use::{
+  use_document, use_event_listener, use_event_listener_with_options, use_window,
+  UseEventListenerOptions,
+};
+use wasm_bindgen::{closure::Closure, JsCast};
+use web_sys::{
+  Event, EventInit, FocusEvent, HtmlButtonElement, KeyboardEvent, MouseEvent, PointerEvent,
+};
+
+use crate::{
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  dismissable_layer::DismissableLayer,
+  focus_scope::{focus_first, FocusScope},
+  menu::get_next_match,
+  popper::{
+    Align, PopperAnchor, PopperArrow, PopperArrowProps, PopperContent, PopperRoot, Side, Sticky,
+  },
+  portal::{Portal, PortalContainer, PortalProps},
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    hide_others::{create_hide_others, HideOthersMode},
+    scroll_lock::create_scroll_lock,
+    use_id::{create_id, create_part_id},
+    Attributes,
+  },
+  Direction,
+};
+
+const OPEN_KEYS: [&str; 4] = [" ", "Enter", "ArrowUp", "ArrowDown"];
+const SELECTION_KEYS: [&str; 2] = [" ", "Enter"];
+const CONTENT_MARGIN: f64 = 10.0;
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum SelectPosition {
+  #[default]
+  ItemAligned,
+  Popper,
+}
+
+#[derive(Clone)]
+struct SelectItemData {
+  value: String,
+  disabled: Signal<bool>,
+  text_value: Option<String>,
+  text_ref: NodeRef<AnyElement>,
+}
+
+impl std::fmt::Debug for SelectItemData {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("SelectItemData")
+      .field("value", &self.value)
+      .field("text_value", &self.text_value)
+      .finish()
+  }
+}
+
+impl PartialEq for SelectItemData {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
+impl Eq for SelectItemData {}
+
+impl Ord for SelectItemData {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.value.cmp(&other.value)
+  }
+}
+
+impl PartialOrd for SelectItemData {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[derive(Clone, PartialEq)]
+struct NativeOption {
+  text: String,
+  disabled: bool,
+}
+
+#[derive(Clone)]
+struct SelectContextValue {
+  trigger_ref: NodeRef<AnyElement>,
+  value_node_ref: NodeRef<AnyElement>,
+  content_id: Signal<String>,
+  value: Signal<String>,
+  on_value_change: Callback<String>,
+  open: Signal<bool>,
+  on_open_change: Callback<bool>,
+  required: Signal<bool>,
+  disabled: Signal<bool>,
+  direction: Signal<Direction>,
+  trigger_pointer_down_pos: StoredValue<Option<(f64, f64)>>,
+  native_options: RwSignal<BTreeMap<String, NativeOption>>,
+}
+
+#[component]
+pub fn SelectRoot(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] value: MaybeProp<String>,
+  #[prop(optional, into)] default_value: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] direction: MaybeProp<Direction>,
+  #[prop(optional, into)] name: MaybeProp<String>,
+  #[prop(optional, into)] auto_complete: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] required: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_value_change: Callback<String>,
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || value.get()),
+    default_value: Signal::derive(move || default_value.get()),
+    on_change: on_value_change,
+  });
+
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: on_open_change,
+  });
+
+  let initial_value = StoredValue::new(value.get_untracked());
+
+  let on_value_change = Callback::new(move |value: String| {
+    set_value.set(value);
+  });
+
+  let on_open_change = Callback::new(move |open: bool| {
+    set_open.set(open);
+  });
+
+  let value = Signal::derive(move || value.get().unwrap_or_default());
+  let trigger_ref = NodeRef::<AnyElement>::new();
+  let native_options = RwSignal::new(BTreeMap::new());
+
+  Effect::new(move |_| {
+    let Some(node) = trigger_ref.get() else {
+      return;
+    };
+
+    let Some(button) = node.dyn_ref::<HtmlButtonElement>() else {
+      return;
+    };
+
+    let Some(form) = button.form() else {
+      return;
+    };
+
+    let reset = Closure::<dyn FnMut()>::new(move || {
+      on_value_change.call(initial_value.get_value().unwrap_or_default());
+    });
+
+    _ = form.add_event_listener_with_callback("reset", reset.as_ref().unchecked_ref());
+
+    on_cleanup(move || {
+      _ = form.remove_event_listener_with_callback("reset", reset.as_ref().unchecked_ref());
+    });
+  });
+
+  let name = Signal::derive(move || name.get());
+  let auto_complete = Signal::derive(move || auto_complete.get());
+
+  let is_form_control = Signal::derive(move || {
+    trigger_ref
+      .get()
+      .map(|trigger| trigger.closest("form").ok().flatten().is_some())
+      .unwrap_or(true)
+  });
+
+  provide_context(CollectionContextValue::<SelectItemData, AnyElement> {
+    collection_ref: NodeRef::new(),
+    item_map: RwSignal::new(Default::default()),
+  });
+
+  let id = create_id(id);
+
+  provide_context(SelectContextValue {
+    trigger_ref,
+    value_node_ref: NodeRef::new(),
+    content_id: create_part_id(id, "content"),
+    value,
+    on_value_change,
+    open: Signal::derive(move || open.get().unwrap_or(false)),
+    on_open_change,
+    required: Signal::derive(move || required.get()),
+    disabled: Signal::derive(move || disabled.get()),
+    direction: Signal::derive(move || direction.get().unwrap_or_default()),
+    trigger_pointer_down_pos: StoredValue::new(None),
+    native_options,
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+
+    <Show when=move || is_form_control.get()>
+      <BubbleSelect
+        value=value
+        name=name
+        auto_complete=auto_complete
+        required=Signal::derive(move || required.get())
+        disabled=Signal::derive(move || disabled.get())
+        native_options=native_options
+        on_change=on_value_change
+      />
+    </Show>
+  }
+}
+
+#[component]
+pub fn SelectTrigger(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue {
+    trigger_ref,
+    content_id,
+    value,
+    on_value_change,
+    open,
+    on_open_change,
+    required,
+    disabled,
+    direction,
+    trigger_pointer_down_pos,
+    ..
+  } = use_context().expect("SelectTrigger must be used in a SelectRoot component");
+
+  let get_items = use_collection_context::<SelectItemData, AnyElement>();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  let pointer_type = StoredValue::new(String::from("touch"));
+  let search = StoredValue::new(String::new());
+  let search_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  let handle_typeahead_search = move |key: String| {
+    let search_value = format!("{}{key}", search.get_value());
+    search.set_value(search_value.clone());
+
+    if let Some(handle) = search_timer.get_value() {
+      handle.clear();
+    }
+
+    if let Ok(handle) = set_timeout_with_handle(
+      move || _ = search.try_set_value(String::new()),
+      Duration::from_millis(1000),
+    ) {
+      search_timer.set_value(Some(handle));
+    }
+
+    let items = get_items
+      .get_untracked()
+      .into_iter()
+      .filter(|(_, data)| !data.disabled.get_untracked())
+      .collect::<Vec<_>>();
+
+    let text_values = items
+      .iter()
+      .map(|(node, data)| get_item_text_value(*node, data))
+      .collect::<Vec<_>>();
+
+    let current_value = value.get_untracked();
+    let current_index = items
+      .iter()
+      .position(|(_, data)| data.value == current_value);
+
+    if let Some(next_index) = get_next_match(&text_values, &search_value, current_index) {
+      on_value_change.call(items[next_index].1.value.clone());
+    }
+  };
+
+  let handle_open = move |pointer_position: Option<(f64, f64)>| {
+    if disabled.get_untracked() {
+      return;
+    }
+
+    on_open_change.call(true);
+    search.set_value(String::new());
+
+    if let Some(pointer_position) = pointer_position {
+      trigger_pointer_down_pos.set_value(Some(pointer_position));
+    }
+  };
+
+  let mut merged_attrs = vec![
+    ("type", "button".into_attribute()),
+    ("role", "combobox".into_attribute()),
+    ("aria-controls", (move || content_id.get()).into_attribute()),
+    ("aria-expanded", (move || open.get()).into_attribute()),
+    ("aria-required", (move || required.get()).into_attribute()),
+    ("aria-autocomplete", "none".into_attribute()),
+    (
+      "dir",
+      (move || direction.get().to_string()).into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || get_open_state(open.get())).into_attribute(),
+    ),
+    ("disabled", (move || disabled.get()).into_attribute()),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+    (
+      "data-placeholder",
+      (move || value.get().is_empty().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <PopperAnchor as_child=true node_ref=node_ref>
+      <Primitive
+        element=html::button
+        on:click=move |ev: MouseEvent| {
+          on_click.call(ev.clone());
+
+          if let Some(node) = node_ref.get_untracked() {
+            _ = node.focus();
+          }
+
+          if pointer_type.get_value() != "mouse" {
+            handle_open(Some((ev.page_x() as f64, ev.page_y() as f64)));
+          }
+        }
+        on:pointerdown=move |ev: PointerEvent| {
+          pointer_type.set_value(ev.pointer_type());
+          on_pointer_down.call(ev.clone());
+
+          if let Some(target) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+          {
+            if target.has_pointer_capture(ev.pointer_id()) {
+              _ = target.release_pointer_capture(ev.pointer_id());
+            }
+          }
+
+          if ev.button() == 0 && !ev.ctrl_key() && ev.pointer_type() == "mouse" {
+            handle_open(Some((ev.page_x() as f64, ev.page_y() as f64)));
+            ev.prevent_default();
+          }
+        }
+        on:keydown=move |ev: KeyboardEvent| {
+          on_key_down.call(ev.clone());
+
+          let key = ev.key();
+          let is_typing_ahead = !search.get_value().is_empty();
+          let is_modifier_key = ev.ctrl_key() || ev.alt_key() || ev.meta_key();
+
+          if !is_modifier_key && key.chars().count() == 1 {
+            handle_typeahead_search(key.clone());
+          }
+
+          if is_typing_ahead && key == " " {
+            return;
+          }
+
+          if OPEN_KEYS.contains(&key.as_str()) {
+            handle_open(None);
+            ev.prevent_default();
+          }
+        }
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </PopperAnchor>
+  }
+}
+
+#[component]
+pub fn SelectValue(
+  #[prop(optional, into)] placeholder: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue {
+    value_node_ref,
+    value,
+    native_options,
+    ..
+  } = use_context().expect("SelectValue must be used in a SelectRoot component");
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      value_node_ref.load(&node);
+    }
+  });
+
+  let placeholder = Signal::derive(move || placeholder.get());
+
+  let selected_text = Signal::derive(move || {
+    native_options.with(|native_options| {
+      native_options
+        .get(&value.get())
+        .map(|native_option| native_option.text.clone())
+    })
+  });
+
+  let mut merged_attrs = vec![("style", "pointer-events: none;".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::span
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {move || {
+        if value.get().is_empty() {
+          return placeholder.get().into_view();
+        }
+
+        children.with_value(|children| match children {
+          Some(children) => children().into_view(),
+          None => selected_text.get().into_view(),
+        })
+      }}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectIcon(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let mut merged_attrs = vec![("aria-hidden", "true".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::span
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {match children.as_ref() {
+        Some(children) => children().into_view(),
+        None => "▼".into_view(),
+      }}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectPortal(
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  Portal(PortalProps {
+    container,
+    children,
+  })
+}
+
+#[derive(Clone)]
+struct SelectContentContextValue {
+  viewport_ref: NodeRef<AnyElement>,
+  selected_item: Signal<Option<web_sys::HtmlElement>>,
+  position: SelectPosition,
+  is_positioned: RwSignal<bool>,
+  search: StoredValue<String>,
+  on_item_leave: Callback<()>,
+  focus_selected_item: Callback<()>,
+}
+
+#[component]
+pub fn SelectContent(
+  #[prop(optional, into)] position: MaybeSignal<SelectPosition>,
+
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(default=Align::Start.into(), into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(default=CONTENT_MARGIN.into(), into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue { open, .. } =
+    use_context().expect("SelectContent must be used in a SelectRoot component");
+
+  let fragment = RwSignal::<Option<web_sys::Element>>::new(None);
+
+  Effect::new(move |_| {
+    fragment.set(document().create_element("div").ok());
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let collision_boundary = StoredValue::new(collision_boundary);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show
+      when=move || open.get()
+      fallback=move || {
+        fragment
+          .get()
+          .map(|fragment| {
+            view! {
+              <Portal container=fragment>
+                <SelectContentFragment>
+                  {children.with_value(|children| children())}
+                </SelectContentFragment>
+              </Portal>
+            }
+          })
+      }
+    >
+      {move || {
+        let position = position.get();
+
+        view! {
+          <SelectContentImpl
+            position=position
+            side=side
+            side_offset=side_offset
+            align=align
+            align_offset=align_offset
+            arrow_padding=arrow_padding
+            avoid_collisions=avoid_collisions
+            collision_boundary=collision_boundary.get_value()
+            collision_padding=collision_padding
+            sticky=sticky
+            hide_when_detached=hide_when_detached
+            on_close_auto_focus=on_close_auto_focus
+            on_escape_key_down=on_escape_key_down
+            on_pointer_down_outside=on_pointer_down_outside
+            node_ref=node_ref
+            attrs=attrs.get_value()
+            as_child=as_child
+          >
+            {children.with_value(|children| children())}
+          </SelectContentImpl>
+        }
+      }}
+    </Show>
+  }
+}
+
+#[component]
+fn SelectContentFragment(children: ChildrenFn) -> impl IntoView {
+  let CollectionContextValue::<SelectItemData, AnyElement> { collection_ref, .. } =
+    use_context().expect("SelectContent must be used in a SelectRoot component");
+
+  let node_ref = NodeRef::<AnyElement>::new();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      collection_ref.load(&node);
+    }
+  });
+
+  provide_context(SelectContentContextValue {
+    viewport_ref: NodeRef::new(),
+    selected_item: Signal::derive(|| None),
+    position: SelectPosition::default(),
+    is_positioned: RwSignal::new(false),
+    search: StoredValue::new(String::new()),
+    on_item_leave: Callback::new(|_| {}),
+    focus_selected_item: Callback::new(|_| {}),
+  });
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+fn SelectContentImpl(
+  position: SelectPosition,
+
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_close_auto_focus: Callback<Event>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue {
+    trigger_ref,
+    content_id,
+    value,
+    open,
+    on_open_change,
+    direction,
+    trigger_pointer_down_pos,
+    ..
+  } = use_context().expect("SelectContent must be used in a SelectRoot component");
+  let CollectionContextValue::<SelectItemData, AnyElement> { collection_ref, .. } =
+    use_context().expect("SelectContent must be used in a SelectRoot component");
+
+  let get_items = use_collection_context::<SelectItemData, AnyElement>();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      collection_ref.load(&node);
+    }
+  });
+
+  let content_wrapper_ref = NodeRef::<AnyElement>::new();
+  let viewport_ref = NodeRef::<AnyElement>::new();
+  let is_positioned = RwSignal::new(false);
+
+  let layer_ref = match position {
+    SelectPosition::ItemAligned => content_wrapper_ref,
+    SelectPosition::Popper => NodeRef::new(),
+  };
+
+  create_scroll_lock(open);
+  create_hide_others(layer_ref, open, HideOthersMode::AriaHidden);
+
+  let selected_item = Signal::derive(move || {
+    let value = value.get();
+    let items = get_items.get();
+
+    items
+      .iter()
+      .find(|(_, data)| data.value == value)
+      .or_else(|| items.iter().find(|(_, data)| !data.disabled.get()))
+      .and_then(|(node, _)| node.get())
+      .map(|node| (*node).clone())
+  });
+
+  let selected_item_text = Signal::derive(move || {
+    let value = value.get();
+    let items = get_items.get();
+
+    items
+      .iter()
+      .find(|(_, data)| data.value == value)
+      .or_else(|| items.iter().find(|(_, data)| !data.disabled.get()))
+      .and_then(|(_, data)| data.text_ref.get())
+      .map(|node| (*node).clone())
+  });
+
+  let focus_selected_item = move || {
+    let candidates = [
+      selected_item.get_untracked(),
+      node_ref.get_untracked().map(|node| (*node).clone()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    focus_first(&candidates);
+  };
+
+  Effect::new(move |_| {
+    if is_positioned.get() {
+      focus_selected_item();
+    }
+  });
+
+  let pointer_move_delta = StoredValue::new((0.0f64, 0.0f64));
+
+  _ = use_event_listener(use_document(), pointermove, move |ev: PointerEvent| {
+    let Some((x, y)) = trigger_pointer_down_pos.get_value() else {
+      return;
+    };
+
+    pointer_move_delta.set_value((
+      (ev.page_x() as f64 - x).abs(),
+      (ev.page_y() as f64 - y).abs(),
+    ));
+  });
+
+  _ = use_event_listener_with_options(
+    use_document(),
+    pointerup,
+    move |ev: PointerEvent| {
+      if trigger_pointer_down_pos.get_value().is_none() {
+        return;
+      }
+
+      let (delta_x, delta_y) = pointer_move_delta.get_value();
+
+      if delta_x <= 10. && delta_y <= 10. {
+        ev.prevent_default();
+      } else {
+        let target = ev
+          .target()
+          .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+        let is_inside = node_ref
+          .get_untracked()
+          .map(|content| content.contains(target.as_ref()))
+          .unwrap_or(false);
+
+        if !is_inside {
+          on_open_change.call(false);
+        }
+      }
+
+      pointer_move_delta.set_value((0., 0.));
+      trigger_pointer_down_pos.set_value(None);
+    },
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  _ = use_event_listener(use_window(), blur, move |_| {
+    on_open_change.call(false);
+  });
+
+  _ = use_event_listener(use_window(), resize, move |_| {
+    on_open_change.call(false);
+  });
+
+  let search = StoredValue::new(String::new());
+  let search_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  let get_enabled_items = move || {
+    get_items
+      .get_untracked()
+      .into_iter()
+      .filter(|(_, data)| !data.disabled.get_untracked())
+      .collect::<Vec<_>>()
+  };
+
+  let handle_typeahead_search = move |key: String| {
+    let search_value = format!("{}{key}", search.get_value());
+    search.set_value(search_value.clone());
+
+    if let Some(handle) = search_timer.get_value() {
+      handle.clear();
+    }
+
+    if let Ok(handle) = set_timeout_with_handle(
+      move || _ = search.try_set_value(String::new()),
+      Duration::from_millis(1000),
+    ) {
+      search_timer.set_value(Some(handle));
+    }
+
+    let items = get_enabled_items();
+    let text_values = items
+      .iter()
+      .map(|(node, data)| get_item_text_value(*node, data))
+      .collect::<Vec<_>>();
+
+    let active_element = document().active_element();
+    let current_index = items.iter().position(|(node, _)| {
+      node
+        .get_untracked()
+        .map(|node| {
+          let node: &web_sys::Element = &node;
+          Some(node) == active_element.as_ref()
+        })
+        .unwrap_or(false)
+    });
+
+    if let Some(next_index) = get_next_match(&text_values, &search_value, current_index) {
+      if let Some(node) = items[next_index].0.get_untracked() {
+        set_timeout(move || _ = node.focus(), Duration::ZERO);
+      }
+    }
+  };
+
+  _ = use_event_listener(node_ref, keydown, move |ev: KeyboardEvent| {
+    let key = ev.key();
+    let is_modifier_key = ev.ctrl_key() || ev.alt_key() || ev.meta_key();
+
+    if key == "Tab" {
+      ev.prevent_default();
+    }
+
+    if !is_modifier_key && key.chars().count() == 1 {
+      handle_typeahead_search(key.clone());
+    }
+
+    if !["ArrowUp", "ArrowDown", "Home", "End"].contains(&key.as_str()) {
+      return;
+    }
+
+    let mut candidates = get_enabled_items()
+      .into_iter()
+      .filter_map(|(node, _)| node.get_untracked())
+      .map(|node| (*node).clone())
+      .collect::<Vec<_>>();
+
+    if key == "ArrowUp" || key == "End" {
+      candidates.reverse();
+    }
+
+    if key == "ArrowUp" || key == "ArrowDown" {
+      let target = ev
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+
+      if let Some(index) = candidates
+        .iter()
+        .position(|candidate| candidate.is_same_node(target.as_ref()))
+      {
+        candidates = candidates.split_off(index + 1);
+      }
+    }
+
+    set_timeout(
+      move || {
+        focus_first(&candidates);
+      },
+      Duration::ZERO,
+    );
+
+    ev.prevent_default();
+  });
+
+  _ = use_event_listener(node_ref, contextmenu, |ev: MouseEvent| {
+    ev.prevent_default();
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style("display", "flex")
+      .style("flex-direction", "column")
+      .style("outline", "none");
+  });
+
+  provide_context(SelectContentContextValue {
+    viewport_ref,
+    selected_item,
+    position,
+    is_positioned,
+    search,
+    on_item_leave: Callback::new(move |_| {
+      if let Some(node) = node_ref.get_untracked() {
+        _ = node.focus();
+      }
+    }),
+    focus_selected_item: Callback::new(move |_| focus_selected_item()),
+  });
+
+  let mut merged_attrs = vec![
+    ("role", "listbox".into_attribute()),
+    ("id", (move || content_id.get()).into_attribute()),
+    (
+      "data-state",
+      (move || get_open_state(open.get())).into_attribute(),
+    ),
+    (
+      "dir",
+      (move || direction.get().to_string()).into_attribute(),
+    ),
+    ("tabindex", (-1).into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let collision_boundary = StoredValue::new(collision_boundary);
+  let children = StoredValue::new(children);
+
+  view! {
+    <FocusScope
+      as_child=true
+      trapped=true
+      on_mount_auto_focus=Callback::new(|ev: Event| {
+        ev.prevent_default();
+      })
+      on_unmount_auto_focus=Callback::new(move |ev: Event| {
+        on_close_auto_focus.call(ev.clone());
+
+        if ev.default_prevented() {
+          return;
+        }
+
+        if let Some(trigger) = trigger_ref.get_untracked() {
+          _ = trigger.focus();
+        }
+
+        ev.prevent_default();
+      })
+      node_ref=layer_ref
+    >
+      <DismissableLayer
+        as_child=true
+        disable_outside_pointer_events=true
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        on_focus_outside=Callback::new(|ev: FocusEvent| {
+          ev.prevent_default();
+        })
+        on_dismiss=Callback::new(move |_| {
+          on_open_change.call(false);
+        })
+        node_ref=layer_ref
+      >
+        {match position {
+          SelectPosition::ItemAligned => view! {
+            <SelectItemAlignedPosition
+              content_wrapper_ref=content_wrapper_ref
+              selected_item=selected_item
+              selected_item_text=selected_item_text
+              node_ref=node_ref
+              attrs=merged_attrs.get_value()
+              as_child=as_child
+            >
+              {children.with_value(|children| children())}
+            </SelectItemAlignedPosition>
+          },
+          SelectPosition::Popper => view! {
+            <SelectPopperPosition
+              side=side
+              side_offset=side_offset
+              align=align
+              align_offset=align_offset
+              arrow_padding=arrow_padding
+              avoid_collisions=avoid_collisions
+              collision_boundary=collision_boundary.get_value()
+              collision_padding=collision_padding
+              sticky=sticky
+              hide_when_detached=hide_when_detached
+              node_ref=node_ref
+              attrs=merged_attrs.get_value()
+              as_child=as_child
+            >
+              {children.with_value(|children| children())}
+            </SelectPopperPosition>
+          },
+        }}
+      </DismissableLayer>
+    </FocusScope>
+  }
+}
+
+#[derive(Clone)]
+struct SelectViewportContextValue {
+  content_wrapper_ref: NodeRef<AnyElement>,
+  should_expand_on_scroll: StoredValue<bool>,
+  on_scroll_button_change: Callback<()>,
+}
+
+#[component]
+fn SelectItemAlignedPosition(
+  content_wrapper_ref: NodeRef<AnyElement>,
+  selected_item: Signal<Option<web_sys::HtmlElement>>,
+  selected_item_text: Signal<Option<web_sys::HtmlElement>>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue {
+    trigger_ref,
+    value_node_ref,
+    direction,
+    ..
+  } = use_context().expect("SelectContent must be used in a SelectRoot component");
+  let SelectContentContextValue {
+    viewport_ref,
+    is_positioned,
+    focus_selected_item,
+    ..
+  } = use_context().expect("SelectContent must be used in a SelectRoot component");
+
+  let get_items = use_collection_context::<SelectItemData, AnyElement>();
+  let should_expand_on_scroll = StoredValue::new(false);
+  let should_reposition = StoredValue::new(true);
+
+  let position = move || {
+    let (
+      Some(trigger),
+      Some(value_node),
+      Some(content_wrapper),
+      Some(content),
+      Some(viewport),
+      Some(selected_item),
+      Some(selected_item_text),
+    ) = (
+      trigger_ref.get_untracked(),
+      value_node_ref.get_untracked(),
+      content_wrapper_ref.get_untracked(),
+      node_ref.get_untracked(),
+      viewport_ref.get_untracked(),
+      selected_item.get_untracked(),
+      selected_item_text.get_untracked(),
+    )
+    else {
+      return;
+    };
+
+    let window = window();
+    let inner_width = window
+      .inner_width()
+      .ok()
+      .and_then(|width| width.as_f64())
+      .unwrap_or_default();
+    let inner_height = window
+      .inner_height()
+      .ok()
+      .and_then(|height| height.as_f64())
+      .unwrap_or_default();
+
+    let wrapper_style = web_sys::HtmlElement::style(&content_wrapper);
+
+    let trigger_rect = trigger.get_bounding_client_rect();
+    let content_rect = content.get_bounding_client_rect();
+    let value_node_rect = value_node.get_bounding_client_rect();
+    let item_text_rect = selected_item_text.get_bounding_client_rect();
+
+    match direction.get_untracked() {
+      Direction::LeftToRight => {
+        let item_text_offset = item_text_rect.left() - content_rect.left();
+        let left = value_node_rect.left() - item_text_offset;
+        let left_delta = trigger_rect.left() - left;
+        let min_content_width = trigger_rect.width() + left_delta;
+        let content_width = min_content_width.max(content_rect.width());
+        let right_edge = inner_width - CONTENT_MARGIN;
+        let clamped_left = left.clamp(
+          CONTENT_MARGIN,
+          CONTENT_MARGIN.max(right_edge - content_width),
+        );
+
+        _ = wrapper_style.set_property("min-width", &format!("{min_content_width}px"));
+        _ = wrapper_style.set_property("left", &format!("{clamped_left}px"));
+      }
+      Direction::RightToLeft => {
+        let item_text_offset = content_rect.right() - item_text_rect.right();
+        let right = inner_width - value_node_rect.right() - item_text_offset;
+        let right_delta = inner_width - trigger_rect.right() - right;
+        let min_content_width = trigger_rect.width() + right_delta;
+        let content_width = min_content_width.max(content_rect.width());
+        let left_edge = inner_width - CONTENT_MARGIN;
+        let clamped_right = right.clamp(
+          CONTENT_MARGIN,
+          CONTENT_MARGIN.max(left_edge - content_width),
+        );
+
+        _ = wrapper_style.set_property("min-width", &format!("{min_content_width}px"));
+        _ = wrapper_style.set_property("right", &format!("{clamped_right}px"));
+      }
+    }
+
+    let items = get_items.get_untracked();
+    let available_height = inner_height - CONTENT_MARGIN * 2.;
+    let items_height = viewport.scroll_height() as f64;
+
+    let content_styles = get_computed_style(&content);
+    let content_border_top_width = content_styles("border-top-width");
+    let content_padding_top = content_styles("padding-top");
+    let content_border_bottom_width = content_styles("border-bottom-width");
+    let content_padding_bottom = content_styles("padding-bottom");
+
+    let full_content_height = content_border_top_width
+      + content_padding_top
+      + items_height
+      + content_padding_bottom
+      + content_border_bottom_width;
+    let min_content_height = (selected_item.offset_height() as f64 * 5.).min(full_content_height);
+
+    let viewport_styles = get_computed_style(&viewport);
+    let viewport_padding_top = viewport_styles("padding-top");
+    let viewport_padding_bottom = viewport_styles("padding-bottom");
+
+    let top_edge_to_trigger_middle =
+      trigger_rect.top() + trigger_rect.height() / 2. - CONTENT_MARGIN;
+    let trigger_middle_to_bottom_edge = available_height - top_edge_to_trigger_middle;
+
+    let selected_item_half_height = selected_item.offset_height() as f64 / 2.;
+    let item_offset_middle = selected_item.offset_top() as f64 + selected_item_half_height;
+    let content_top_to_item_middle =
+      content_border_top_width + content_padding_top + item_offset_middle;
+    let item_middle_to_content_bottom = full_content_height - content_top_to_item_middle;
+
+    let is_same_item = |item: Option<&(NodeRef<AnyElement>, SelectItemData)>| {
+      item
+        .and_then(|(node, _)| node.get_untracked())
+        .map(|node| node.is_same_node(Some(&selected_item)))
+        .unwrap_or(false)
+    };
+
+    if content_top_to_item_middle <= top_edge_to_trigger_middle {
+      let is_last_item = is_same_item(items.last());
+
+      _ = wrapper_style.set_property("bottom", "0px");
+
+      let viewport_offset_bottom =
+        (content.client_height() - viewport.offset_top() - viewport.offset_height()) as f64;
+      let clamped_trigger_middle_to_bottom_edge = trigger_middle_to_bottom_edge.max(
+        selected_item_half_height
+          + if is_last_item {
+            viewport_padding_bottom
+          } else {
+            0.
+          }
+          + viewport_offset_bottom
+          + content_border_bottom_width,
+      );
+
+      let height = content_top_to_item_middle + clamped_trigger_middle_to_bottom_edge;
+      _ = wrapper_style.set_property("height", &format!("{height}px"));
+    } else {
+      let is_first_item = is_same_item(items.first());
+
+      _ = wrapper_style.set_property("top", "0px");
+
+      let clamped_top_edge_to_trigger_middle = top_edge_to_trigger_middle.max(
+        content_border_top_width
+          + viewport.offset_top() as f64
+          + if is_first_item {
+            viewport_padding_top
+          } else {
+            0.
+          }
+          + selected_item_half_height,
+      );
+
+      let height = clamped_top_edge_to_trigger_middle + item_middle_to_content_bottom;
+      _ = wrapper_style.set_property("height", &format!("{height}px"));
+
+      viewport.set_scroll_top(
+        (content_top_to_item_middle - top_edge_to_trigger_middle + viewport.offset_top() as f64)
+          as i32,
+      );
+    }
+
+    _ = wrapper_style.set_property("margin", &format!("{CONTENT_MARGIN}px 0"));
+    _ = wrapper_style.set_property("min-height", &format!("{min_content_height}px"));
+    _ = wrapper_style.set_property("max-height", &format!("{available_height}px"));
+
+    is_positioned.set(true);
+
+    request_animation_frame(move || should_expand_on_scroll.set_value(true));
+  };
+
+  Effect::new(move |_| {
+    _ = trigger_ref.get();
+    _ = value_node_ref.get();
+    _ = content_wrapper_ref.get();
+    _ = node_ref.get();
+    _ = viewport_ref.get();
+    selected_item.track();
+    selected_item_text.track();
+    direction.track();
+
+    position();
+  });
+
+  Effect::new(move |_| {
+    let Some(wrapper) = content_wrapper_ref.get() else {
+      return;
+    };
+
+    let z_index = node_ref
+      .get()
+      .and_then(|content| window().get_computed_style(&content).ok().flatten())
+      .and_then(|styles| styles.get_property_value("z-index").ok())
+      .unwrap_or_default();
+
+    let wrapper_style = web_sys::HtmlElement::style(&wrapper);
+    _ = wrapper_style.set_property("display", "flex");
+    _ = wrapper_style.set_property("flex-direction", "column");
+    _ = wrapper_style.set_property("position", "fixed");
+    _ = wrapper_style.set_property("z-index", &z_index);
+  });
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style("box-sizing", "border-box")
+      .style("max-height", "100%");
+  });
+
+  provide_context(SelectViewportContextValue {
+    content_wrapper_ref,
+    should_expand_on_scroll,
+    on_scroll_button_change: Callback::new(move |_| {
+      if should_reposition.get_value() {
+        position();
+        focus_selected_item.call(());
+        should_reposition.set_value(false);
+      }
+    }),
+  });
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=content_wrapper_ref
+    >
+      <Primitive
+        element=html::div
+        node_ref=node_ref
+        attrs=attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Primitive>
+  }
+}
+
+#[component]
+fn SelectPopperPosition(
+  #[prop(optional, into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContentContextValue { is_positioned, .. } =
+    use_context().expect("SelectContent must be used in a SelectRoot component");
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style("box-sizing", "border-box")
+      .style(
+        "--leptix-select-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-select-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-select-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-select-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-select-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  view! {
+    <PopperContent
+      side=side
+      side_offset=side_offset
+      align=align
+      align_offset=align_offset
+      arrow_padding=arrow_padding
+      avoid_collisions=avoid_collisions
+      collision_boundary=collision_boundary
+      collision_padding=collision_padding
+      sticky=sticky
+      hide_when_detached=hide_when_detached
+      on_placed=Callback::new(move |_| {
+        is_positioned.set(true);
+      })
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </PopperContent>
+  }
+}
+
+#[component]
+pub fn SelectViewport(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContentContextValue { viewport_ref, .. } =
+    use_context().expect("SelectViewport must be used in a SelectContent component");
+  let viewport_context = use_context::<SelectViewportContextValue>();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      viewport_ref.load(&node);
+    }
+  });
+
+  let prev_scroll_top = StoredValue::new(0);
+
+  let mut merged_attrs = vec![
+    ("data-leptix-select-viewport", "".into_attribute()),
+    ("role", "presentation".into_attribute()),
+    (
+      "style",
+      "position: relative; flex: 1; overflow: auto;".into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <style>
+      "[data-leptix-select-viewport]{scrollbar-width:none;-ms-overflow-style:none;-webkit-overflow-scrolling:touch;}[data-leptix-select-viewport]::-webkit-scrollbar{display:none}"
+    </style>
+    <Primitive
+      element=html::div
+      on:scroll=move |ev: Event| {
+        let Some(viewport) = ev
+          .current_target()
+          .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+        else {
+          return;
+        };
+
+        let Some(SelectViewportContextValue {
+          content_wrapper_ref,
+          should_expand_on_scroll,
+          ..
+        }) = viewport_context.clone() else {
+          return;
+        };
+
+        if let (true, Some(content_wrapper)) = (
+          should_expand_on_scroll.get_value(),
+          content_wrapper_ref.get_untracked(),
+        ) {
+          let scrolled_by = (prev_scroll_top.get_value() - viewport.scroll_top()).abs() as f64;
+
+          if scrolled_by > 0. {
+            let available_height = window()
+              .inner_height()
+              .ok()
+              .and_then(|height| height.as_f64())
+              .unwrap_or_default()
+              - CONTENT_MARGIN * 2.;
+
+            let wrapper_style = web_sys::HtmlElement::style(&content_wrapper);
+            let css_min_height = parse_px(&wrapper_style.get_property_value("min-height").unwrap_or_default());
+            let css_height = parse_px(&wrapper_style.get_property_value("height").unwrap_or_default());
+            let prev_height = css_min_height.max(css_height);
+
+            if prev_height < available_height {
+              let next_height = prev_height + scrolled_by;
+              let clamped_next_height = available_height.min(next_height);
+              let height_diff = next_height - clamped_next_height;
+
+              _ = wrapper_style.set_property("height", &format!("{clamped_next_height}px"));
+
+              if wrapper_style.get_property_value("bottom").unwrap_or_default() == "0px" {
+                viewport.set_scroll_top(height_diff.max(0.) as i32);
+                _ = wrapper_style.set_property("justify-content", "flex-end");
+              }
+            }
+          }
+        }
+
+        prev_scroll_top.set_value(viewport.scroll_top());
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[derive(Clone)]
+struct SelectGroupContextValue {
+  id: Signal<String>,
+}
+
+#[component]
+pub fn SelectGroup(
+  #[prop(optional, into)] id: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let label_id = create_part_id(create_id(id.clone()), "label");
+
+  provide_context(SelectGroupContextValue { id: label_id });
+
+  let mut merged_attrs = vec![
+    ("id", id.into_attribute()),
+    ("role", "group".into_attribute()),
+    ("aria-labelledby", (move || label_id.get()).into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectLabel(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let id = use_context::<SelectGroupContextValue>().map(|group| group.id);
+
+  let mut merged_attrs = vec![("id", (move || id.map(|id| id.get())).into_attribute())];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[derive(Clone)]
+struct SelectItemContextValue {
+  value: Signal<String>,
+  disabled: Signal<bool>,
+  text_id: Signal<String>,
+  text_ref: NodeRef<AnyElement>,
+  is_selected: Signal<bool>,
+}
+
+#[component]
+pub fn SelectItem(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(into)] value: MaybeSignal<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue {
+    value: selected_value,
+    on_value_change,
+    on_open_change,
+    ..
+  } = use_context().expect("SelectItem must be used in a SelectRoot component");
+  let SelectContentContextValue {
+    search,
+    on_item_leave,
+    ..
+  } = use_context().expect("SelectItem must be used in a SelectContent component");
+
+  let value = Signal::derive(move || value.get());
+  let text_id = create_part_id(create_id(id.clone()), "text");
+  let text_ref = NodeRef::<AnyElement>::new();
+  let is_selected = Signal::derive(move || selected_value.get() == value.get());
+  let is_focused = RwSignal::new(false);
+  let pointer_type = StoredValue::new(String::from("touch"));
+
+  use_collection_item_ref::<AnyElement, SelectItemData>(
+    node_ref,
+    SelectItemData {
+      value: value.get_untracked(),
+      disabled: Signal::derive(move || disabled.get()),
+      text_value: text_value.get_untracked(),
+      text_ref,
+    },
+  );
+
+  provide_context(SelectItemContextValue {
+    value,
+    disabled: Signal::derive(move || disabled.get()),
+    text_id,
+    text_ref,
+    is_selected,
+  });
+
+  let handle_select = move || {
+    if disabled.get_untracked() {
+      return;
+    }
+
+    on_value_change.call(value.get_untracked());
+    on_open_change.call(false);
+  };
+
+  let mut merged_attrs = vec![
+    ("id", id.into_attribute()),
+    ("role", "option".into_attribute()),
+    ("aria-labelledby", (move || text_id.get()).into_attribute()),
+    (
+      "data-highlighted",
+      (move || is_focused.get().then_some("")).into_attribute(),
+    ),
+    (
+      "aria-selected",
+      (move || is_selected.get() && is_focused.get()).into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || {
+        if is_selected.get() {
+          "checked"
+        } else {
+          "unchecked"
+        }
+      })
+      .into_attribute(),
+    ),
+    ("aria-disabled", (move || disabled.get()).into_attribute()),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+    (
+      "tabindex",
+      (move || (!disabled.get()).then_some(-1)).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      on:focus=move |_| {
+        is_focused.set(true);
+      }
+      on:blur=move |_| {
+        is_focused.set(false);
+      }
+      on:click=move |_: MouseEvent| {
+        if pointer_type.get_value() != "mouse" {
+          handle_select();
+        }
+      }
+      on:pointerup=move |ev: PointerEvent| {
+        if pointer_type.get_value() == "mouse" && !ev.default_prevented() {
+          handle_select();
+        }
+      }
+      on:pointerdown=move |ev: PointerEvent| {
+        pointer_type.set_value(ev.pointer_type());
+      }
+      on:pointermove=move |ev: PointerEvent| {
+        pointer_type.set_value(ev.pointer_type());
+
+        if disabled.get_untracked() {
+          on_item_leave.call(());
+        } else if ev.pointer_type() == "mouse" {
+          if let Some(node) = node_ref.get_untracked() {
+            _ = node.focus();
+          }
+        }
+      }
+      on:pointerleave=move |ev: PointerEvent| {
+        let is_active = ev
+          .current_target()
+          .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+          .map(|target| Some(target) == document().active_element())
+          .unwrap_or(false);
+
+        if is_active {
+          on_item_leave.call(());
+        }
+      }
+      on:keydown=move |ev: KeyboardEvent| {
+        let key = ev.key();
+
+        if !search.get_value().is_empty() && key == " " {
+          return;
+        }
+
+        if SELECTION_KEYS.contains(&key.as_str()) {
+          handle_select();
+        }
+
+        if key == " " {
+          ev.prevent_default();
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectItemText(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContextValue { native_options, .. } =
+    use_context().expect("SelectItemText must be used in a SelectRoot component");
+  let SelectItemContextValue {
+    value,
+    disabled,
+    text_id,
+    text_ref,
+    ..
+  } = use_context().expect("SelectItemText must be used in a SelectItem component");
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    text_ref.load(&node);
+
+    let native_option = NativeOption {
+      text: node.text_content().unwrap_or_default(),
+      disabled: disabled.get(),
+    };
+
+    native_options.update(|native_options| {
+      native_options.insert(value.get_untracked(), native_option);
+    });
+  });
+
+  on_cleanup(move || {
+    _ = native_options.try_update(|native_options| {
+      native_options.remove(&value.get_untracked());
+    });
+  });
+
+  let mut merged_attrs = vec![("id", (move || text_id.get()).into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::span
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectItemIndicator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectItemContextValue { is_selected, .. } =
+    use_context().expect("SelectItemIndicator must be used in a SelectItem component");
+
+  let mut merged_attrs = vec![("aria-hidden", "true".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || is_selected.get()>
+      <Primitive
+        element=html::span
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn SelectScrollUpButton(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContentContextValue {
+    viewport_ref,
+    selected_item,
+    is_positioned,
+    ..
+  } = use_context().expect("SelectScrollUpButton must be used in a SelectContent component");
+
+  let can_scroll_up = RwSignal::new(false);
+
+  let handle_scroll = move || {
+    if let Some(viewport) = viewport_ref.get_untracked() {
+      can_scroll_up.set(viewport.scroll_top() > 0);
+    }
+  };
+
+  Effect::new(move |_| {
+    if viewport_ref.get().is_some() && is_positioned.get() {
+      handle_scroll();
+    }
+  });
+
+  _ = use_event_listener(viewport_ref, leptos::ev::scroll, move |_| handle_scroll());
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || can_scroll_up.get()>
+      {SelectScrollButtonImpl(SelectScrollButtonImplProps {
+        on_auto_scroll: Callback::new(move |_| {
+          if let (Some(viewport), Some(selected_item)) =
+            (viewport_ref.get_untracked(), selected_item.get_untracked())
+          {
+            viewport.set_scroll_top(viewport.scroll_top() - selected_item.offset_height());
+          }
+        }),
+        node_ref,
+        attrs: attrs.get_value(),
+        children: children.get_value(),
+        as_child,
+      })}
+    </Show>
+  }
+}
+
+#[component]
+pub fn SelectScrollDownButton(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContentContextValue {
+    viewport_ref,
+    selected_item,
+    is_positioned,
+    ..
+  } = use_context().expect("SelectScrollDownButton must be used in a SelectContent component");
+
+  let can_scroll_down = RwSignal::new(false);
+
+  let handle_scroll = move || {
+    if let Some(viewport) = viewport_ref.get_untracked() {
+      let max_scroll = viewport.scroll_height() - viewport.client_height();
+      can_scroll_down.set((viewport.scroll_top() as f64).ceil() < max_scroll as f64);
+    }
+  };
+
+  Effect::new(move |_| {
+    if viewport_ref.get().is_some() && is_positioned.get() {
+      handle_scroll();
+    }
+  });
+
+  _ = use_event_listener(viewport_ref, leptos::ev::scroll, move |_| handle_scroll());
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || can_scroll_down.get()>
+      {SelectScrollButtonImpl(SelectScrollButtonImplProps {
+        on_auto_scroll: Callback::new(move |_| {
+          if let (Some(viewport), Some(selected_item)) =
+            (viewport_ref.get_untracked(), selected_item.get_untracked())
+          {
+            viewport.set_scroll_top(viewport.scroll_top() + selected_item.offset_height());
+          }
+        }),
+        node_ref,
+        attrs: attrs.get_value(),
+        children: children.get_value(),
+        as_child,
+      })}
+    </Show>
+  }
+}
+
+#[component]
+fn SelectScrollButtonImpl(
+  on_auto_scroll: Callback<()>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let SelectContentContextValue { on_item_leave, .. } =
+    use_context().expect("SelectScrollButton must be used in a SelectContent component");
+  let viewport_context = use_context::<SelectViewportContextValue>();
+
+  let auto_scroll_timer = StoredValue::<Option<IntervalHandle>>::new(None);
+
+  let clear_auto_scroll_timer = move || {
+    if let Some(handle) = auto_scroll_timer.get_value() {
+      handle.clear();
+      auto_scroll_timer.set_value(None);
+    }
+  };
+
+  let start_auto_scroll_timer = move || {
+    if auto_scroll_timer.get_value().is_some() {
+      return;
+    }
+
+    if let Ok(handle) =
+      set_interval_with_handle(move || on_auto_scroll.call(()), Duration::from_millis(50))
+    {
+      auto_scroll_timer.set_value(Some(handle));
+    }
+  };
+
+  on_cleanup(clear_auto_scroll_timer);
+
+  Effect::new(move |_| {
+    if node_ref.get().is_some() {
+      if let Some(SelectViewportContextValue {
+        on_scroll_button_change,
+        ..
+      }) = viewport_context.clone()
+      {
+        on_scroll_button_change.call(());
+      }
+    }
+  });
+
+  let mut merged_attrs = vec![
+    ("aria-hidden", "true".into_attribute()),
+    ("style", "flex-shrink: 0;".into_attribute()),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      on:pointerdown=move |_| {
+        start_auto_scroll_timer();
+      }
+      on:pointermove=move |_| {
+        on_item_leave.call(());
+        start_auto_scroll_timer();
+      }
+      on:pointerleave=move |_| {
+        clear_auto_scroll_timer();
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.as_ref().map(|children| children())}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectSeparator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let mut merged_attrs = vec![("aria-hidden", "true".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.as_ref().map(|children| children())}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn SelectArrow(
+  #[prop(default=10.0f64.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0f64.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+  let SelectContentContextValue { position, .. } =
+    use_context().expect("SelectArrow must be used in a SelectContent component");
+
+  (position == SelectPosition::Popper).then(|| {
+    PopperArrow(PopperArrowProps {
+      width,
+      height,
+      attrs,
+      children,
+    })
+  })
+}
+
+#[component]
+fn BubbleSelect(
+  value: Signal<String>,
+  name: Signal<Option<String>>,
+  auto_complete: Signal<Option<String>>,
+  required: Signal<bool>,
+  disabled: Signal<bool>,
+  native_options: RwSignal<BTreeMap<String, NativeOption>>,
+  on_change: Callback<String>,
+) -> impl IntoView {
+  let node_ref = NodeRef::<Select>::new();
+
+  Effect::new(move |prev_value: Option<String>| {
+    let value = value.get();
+    native_options.track();
+
+    let Some(select) = node_ref.get() else {
+      return value;
+    };
+
+    select.set_value(&value);
+
+    if prev_value.is_some_and(|prev_value| prev_value != value) {
+      let mut ev_options = EventInit::new();
+      ev_options.bubbles(true);
+
+      if let Ok(ev) = Event::new_with_event_init_dict("change", &ev_options) {
+        _ = select.dispatch_event(&ev);
+      }
+    }
+
+    value
+  });
+
+  view! {
+    <select
+      aria-hidden="true"
+      tabindex="-1"
+      name=move || name.get()
+      autocomplete=move || auto_complete.get()
+      required=move || required.get()
+      disabled=move || disabled.get()
+      style="position: absolute; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; word-wrap: normal;"
+      on:change=move |ev| {
+        let next_value = event_target_value(&ev);
+
+        if next_value != value.get_untracked() {
+          on_change.call(next_value);
+        }
+      }
+      node_ref=node_ref
+    >
+      <Show when=move || value.get().is_empty()>
+        <option value=""></option>
+      </Show>
+      <For
+        each=move || native_options.get().into_iter()
+        key=|(value, native_option)| (value.clone(), native_option.text.clone(), native_option.disabled)
+        children=|(value, native_option)| {
+          view! {
+            <option value=value disabled=native_option.disabled>
+              {native_option.text}
+            </option>
+          }
+        }
+      />
+    </select>
+  }
+}
+
+fn get_item_text_value(node: NodeRef<AnyElement>, data: &SelectItemData) -> String {
+  data.text_value.clone().unwrap_or_else(|| {
+    node
+      .get_untracked()
+      .and_then(|node| node.text_content())
+      .unwrap_or_default()
+  })
+}
+
+fn get_computed_style(element: &web_sys::Element) -> impl Fn(&str) -> f64 {
+  let styles = window().get_computed_style(element).ok().flatten();
+
+  move |property| {
+    styles
+      .as_ref()
+      .and_then(|styles| styles.get_property_value(property).ok())
+      .map(|value| parse_px(&value))
+      .unwrap_or_default()
+  }
+}
+
+fn parse_px(value: &str) -> f64 {
+  value.trim_end_matches("px").parse().unwrap_or_default()
+}
+
+fn get_open_state(open: bool) -> &'static str {
+  if open {
+    "open"
+  } else {
+    "closed"
+  }
+}
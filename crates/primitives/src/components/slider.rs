@@ -16,10 +16,12 @@ use web_sys::{
 
 use crate::{
   collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  form::{use_form_control_attrs, use_form_field_name},
   primitive::Primitive,
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
     create_previous::create_previous,
+    form_data::get_form_field_name,
     linear_scale, Attributes,
   },
   Direction, Orientation,
@@ -150,8 +152,10 @@ pub fn SliderRoot(
     }
   });
 
+  let field_name = use_form_field_name();
+
   provide_context(SliderContextValue {
-    name: Signal::derive(move || name.get()),
+    name: Signal::derive(move || name.get().or_else(|| field_name.map(|name| name.get()))),
     disabled: Signal::derive(move || disabled.get()),
     min: Signal::derive(move || min.get()),
     max: Signal::derive(move || max.get()),
@@ -166,7 +170,8 @@ pub fn SliderRoot(
     item_map: RwSignal::new(HashMap::new()),
   });
 
-  let mut merged_attrs = attrs.clone();
+  let mut merged_attrs = use_form_control_attrs();
+  merged_attrs.extend(attrs.clone());
   merged_attrs.extend([
     ("aria-disabled", disabled.into_attribute()),
     (
@@ -981,13 +986,34 @@ pub fn SliderThumb(
   }
 }
 
+fn get_thumb_field_name(
+  thumb_name: Option<String>,
+  root_name: Option<String>,
+  multiple: bool,
+) -> Option<String> {
+  thumb_name
+    .or(root_name)
+    .map(|name| get_form_field_name(&name, multiple))
+}
+
 #[component]
 fn BubbleInput(
   #[prop(optional, into)] name: MaybeProp<String>,
   value: Signal<f64>,
 ) -> impl IntoView {
-  let SliderContextValue { values, .. } =
-    use_context().expect("SliderThumb must be used in a SliderRoot component");
+  let SliderContextValue {
+    name: root_name,
+    values,
+    ..
+  } = use_context().expect("SliderThumb must be used in a SliderRoot component");
+
+  let name = Signal::derive(move || {
+    get_thumb_field_name(
+      name.get(),
+      root_name.get(),
+      values.with(|values| values.len() > 1),
+    )
+  });
 
   let node_ref = NodeRef::<Input>::new();
   let prev_value = create_previous(Signal::derive(move || value.get()));
@@ -1037,7 +1063,7 @@ fn BubbleInput(
   view! {
     <input
       aria-hidden
-      name=Signal::derive(move || name.get().map(|name| format!("{}{}", name, if values.get().len() > 1 { "[]" } else { "" }))).into_attribute()
+      name=name.into_attribute()
       value=value.into_attribute()
       node_ref=node_ref
       style:display="none"
@@ -1097,11 +1123,11 @@ fn has_min_steps_between_values(values: &[f64], min_steps_between_values: f64) -
     .unwrap_or(false)
 }
 
-fn get_decimal_count(value: f64) -> usize {
+pub(crate) fn get_decimal_count(value: f64) -> usize {
   value.to_string().split('.').nth(1).unwrap_or("").len()
 }
 
-fn round_value(value: f64, decimal_count: u32) -> f64 {
+pub(crate) fn round_value(value: f64, decimal_count: u32) -> f64 {
   let rounder = 10_f64.powi(decimal_count as i32);
   (value * rounder).round() / rounder
 }
@@ -1113,3 +1139,20 @@ fn get_thumb_in_bounds_offset(width: f64, left: f64, direction: f64) -> f64 {
 
   (half_width - offset(left) * direction) * direction
 }
+
+#[test]
+fn thumb_and_root_names_are_suffixed_for_multiple_values() {
+  assert_eq!(
+    get_thumb_field_name(Some("low".into()), Some("range".into()), true),
+    Some("low[]".into())
+  );
+  assert_eq!(
+    get_thumb_field_name(None, Some("range".into()), true),
+    Some("range[]".into())
+  );
+  assert_eq!(
+    get_thumb_field_name(Some("volume".into()), None, false),
+    Some("volume".into())
+  );
+  assert_eq!(get_thumb_field_name(None, None, true), None);
+}
@@ -10,6 +10,7 @@ use web_sys::{
 };
 
 use crate::{
+  form::{use_form_control_attrs, use_form_field_name},
   primitive::Primitive,
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
@@ -66,7 +67,11 @@ pub fn SwitchRoot(
     disabled: Signal::derive(move || disabled.get()),
   });
 
-  let mut merged_attrs = attrs.clone();
+  let field_name = use_form_field_name();
+  let name = Signal::derive(move || name.get().or_else(|| field_name.map(|name| name.get())));
+
+  let mut merged_attrs = use_form_control_attrs();
+  merged_attrs.extend(attrs.clone());
   merged_attrs.extend([
     ("type", "button".into_attribute()),
     ("role", "switch".into_attribute()),
@@ -117,7 +122,7 @@ pub fn SwitchRoot(
       <BubbleInput
         checked=Signal::derive(move || checked.get().unwrap_or(false))
         bubbles=Signal::derive(move || !has_consumer_stopped_propagation.get_value())
-        name=name.clone()
+        name=name
         value=value.clone()
         disabled=Signal::derive(move || disabled.get())
         required=Signal::derive(move || required.get())
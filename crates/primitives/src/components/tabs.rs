@@ -7,7 +7,7 @@ use crate::{
   roving_focus::{RovingFocusGroup, RovingFocusGroupItem},
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
-    create_id::create_id,
+    use_id::create_id,
     Attributes,
   },
   Direction, Orientation,
@@ -32,6 +32,7 @@ pub enum ActivationMode {
 
 #[component]
 pub fn TabsRoot(
+  #[prop(optional, into)] id: MaybeProp<String>,
   #[prop(optional, into)] value: MaybeProp<String>,
   #[prop(optional, into)] default_value: MaybeProp<String>,
   #[prop(optional, into)] orientation: MaybeSignal<Orientation>,
@@ -52,8 +53,10 @@ pub fn TabsRoot(
     on_change: on_value_change,
   });
 
+  let base_id = create_id(id.clone());
+
   provide_context(TabsContextValue {
-    base_id: create_id(),
+    base_id,
     value: Signal::derive(move || value.get()),
     on_value_change: Callback::new(move |value| {
       set_value.set(value);
@@ -63,11 +66,14 @@ pub fn TabsRoot(
     activation_mode: Signal::derive(move || activation_mode.get()),
   });
 
+  let mut merged_attrs = vec![("id", id.into_attribute())];
+  merged_attrs.extend(attrs);
+
   view! {
     <Primitive
       element=html::div
       node_ref=node_ref
-      attrs=attrs
+      attrs=merged_attrs
       as_child=as_child
     >
       {children()}
@@ -0,0 +1,981 @@
+use std::{collections::HashMap, time::Duration};
+
+use leptos::{
+  ev::{blur, click, focus, focusin, focusout, keydown, pointerleave, pointermove},
+  html::AnyElement,
+  leptos_dom::helpers::TimeoutHandle,
+  *,
+};
+use leptos_use::{
+  use_document, use_event_listener, use_event_listener_with_options, use_window,
+  UseEventListenerOptions,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{
+  js_sys::Date, FocusEvent, HtmlElement, KeyboardEvent, MouseEvent, Node, PointerEvent,
+};
+
+use crate::{
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  dismissable_layer::DismissableLayerBranch,
+  focus_scope::{focus_first, get_tabbable_candidates},
+  portal::Portal,
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    Attributes,
+  },
+};
+
+const ANNOUNCE_EXCLUDE_ATTRIBUTE: &str = "data-leptix-toast-announce-exclude";
+const ANNOUNCE_ALT_ATTRIBUTE: &str = "data-leptix-toast-announce-alt";
+
+#[derive(Clone, Copy, Default, PartialEq, strum_macros::Display)]
+pub enum SwipeDirection {
+  #[strum(to_string = "up")]
+  Up,
+  #[strum(to_string = "down")]
+  Down,
+  #[strum(to_string = "left")]
+  Left,
+  #[default]
+  #[strum(to_string = "right")]
+  Right,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum ToastType {
+  #[default]
+  Foreground,
+  Background,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct ToastItemData;
+
+#[derive(Clone)]
+struct ToastProviderContextValue {
+  label: Signal<String>,
+  duration: Signal<u64>,
+  swipe_direction: Signal<SwipeDirection>,
+  swipe_threshold: Signal<f64>,
+  viewport_ref: NodeRef<AnyElement>,
+  toast_count: RwSignal<usize>,
+  is_paused: RwSignal<bool>,
+}
+
+#[component]
+pub fn ToastProvider(
+  #[prop(default="Notification".to_string().into(), into)] label: MaybeSignal<String>,
+  #[prop(default=5000.into(), into)] duration: MaybeSignal<u64>,
+  #[prop(optional, into)] swipe_direction: MaybeSignal<SwipeDirection>,
+  #[prop(default=50.0.into(), into)] swipe_threshold: MaybeSignal<f64>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(CollectionContextValue::<ToastItemData, AnyElement> {
+    collection_ref: NodeRef::new(),
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  provide_context(ToastProviderContextValue {
+    label: Signal::derive(move || label.get()),
+    duration: Signal::derive(move || duration.get()),
+    swipe_direction: Signal::derive(move || swipe_direction.get()),
+    swipe_threshold: Signal::derive(move || swipe_threshold.get()),
+    viewport_ref: NodeRef::new(),
+    toast_count: RwSignal::new(0),
+    is_paused: RwSignal::new(false),
+  });
+
+  children()
+}
+
+#[component]
+pub fn ToastViewport(
+  #[prop(default=vec![String::from("F8")], into)] hotkey: Vec<String>,
+  #[prop(default="Notifications ({hotkey})".to_string().into(), into)] label: MaybeSignal<String>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ToastProviderContextValue {
+    viewport_ref,
+    toast_count,
+    is_paused,
+    ..
+  } = use_context().expect("ToastViewport must be used in a ToastProvider component");
+  let CollectionContextValue::<ToastItemData, AnyElement> { collection_ref, .. } =
+    use_context().expect("ToastViewport must be used in a ToastProvider component");
+
+  let get_items = use_collection_context::<ToastItemData, AnyElement>();
+
+  let wrapper_ref = NodeRef::<AnyElement>::new();
+  let head_focus_proxy_ref = NodeRef::<html::Span>::new();
+  let tail_focus_proxy_ref = NodeRef::<html::Span>::new();
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      viewport_ref.load(&node);
+      collection_ref.load(&node);
+    }
+  });
+
+  let hotkey = StoredValue::new(hotkey);
+  let label = Signal::derive(move || {
+    let hotkey_label =
+      hotkey.with_value(|hotkey| hotkey.join("+").replace("Key", "").replace("Digit", ""));
+
+    label.get().replace("{hotkey}", &hotkey_label)
+  });
+
+  _ = use_event_listener(use_document(), keydown, move |ev: KeyboardEvent| {
+    let is_hotkey_pressed = hotkey.with_value(|hotkey| {
+      !hotkey.is_empty()
+        && hotkey.iter().all(|key| match key.as_str() {
+          "altKey" => ev.alt_key(),
+          "ctrlKey" => ev.ctrl_key(),
+          "metaKey" => ev.meta_key(),
+          "shiftKey" => ev.shift_key(),
+          key => ev.code() == key,
+        })
+    });
+
+    if is_hotkey_pressed {
+      if let Some(node) = node_ref.get_untracked() {
+        _ = node.focus();
+      }
+    }
+  });
+
+  let is_inside_wrapper = move |target: Option<Node>| {
+    wrapper_ref
+      .get_untracked()
+      .map(|wrapper| wrapper.contains(target.as_ref()))
+      .unwrap_or(false)
+  };
+
+  let handle_pause = move || {
+    if !is_paused.get_untracked() {
+      is_paused.set(true);
+    }
+  };
+
+  let handle_resume = move || {
+    if is_paused.get_untracked() {
+      is_paused.set(false);
+    }
+  };
+
+  _ = use_event_listener(wrapper_ref, pointermove, move |_| handle_pause());
+  _ = use_event_listener(wrapper_ref, focusin, move |_| handle_pause());
+
+  _ = use_event_listener(wrapper_ref, pointerleave, move |_| {
+    let is_focus_inside = is_inside_wrapper(document().active_element().map(Node::from));
+
+    if !is_focus_inside {
+      handle_resume();
+    }
+  });
+
+  _ = use_event_listener(wrapper_ref, focusout, move |ev: FocusEvent| {
+    let is_focus_moving_outside = !is_inside_wrapper(
+      ev.related_target()
+        .and_then(|target| target.dyn_into::<Node>().ok()),
+    );
+
+    if is_focus_moving_outside {
+      handle_resume();
+    }
+  });
+
+  _ = use_event_listener(use_window(), blur, move |_| handle_pause());
+  _ = use_event_listener(use_window(), focus, move |_| handle_resume());
+
+  let get_sorted_tabbable_candidates = move |is_tabbing_backwards: bool| {
+    let mut tabbable_candidates = get_items
+      .get_untracked()
+      .into_iter()
+      .filter_map(|(node, _)| node.get_untracked())
+      .map(|node| {
+        let node: HtmlElement = (*node).clone();
+        let mut candidates = vec![node.clone()];
+        candidates.extend(get_tabbable_candidates(&node));
+
+        if is_tabbing_backwards {
+          candidates.reverse();
+        }
+
+        candidates
+      })
+      .collect::<Vec<_>>();
+
+    if !is_tabbing_backwards {
+      tabbable_candidates.reverse();
+    }
+
+    tabbable_candidates
+      .into_iter()
+      .flatten()
+      .collect::<Vec<_>>()
+  };
+
+  let handle_focus_proxy_focus = move |ev: FocusEvent, is_tabbing_backwards: bool| {
+    let previously_focused = ev
+      .related_target()
+      .and_then(|target| target.dyn_into::<Node>().ok());
+
+    let is_focus_from_outside_viewport = !node_ref
+      .get_untracked()
+      .map(|viewport| viewport.contains(previously_focused.as_ref()))
+      .unwrap_or(false);
+
+    if is_focus_from_outside_viewport {
+      focus_first(&get_sorted_tabbable_candidates(is_tabbing_backwards));
+    }
+  };
+
+  _ = use_event_listener(node_ref, keydown, move |ev: KeyboardEvent| {
+    let is_meta_key = ev.alt_key() || ev.ctrl_key() || ev.meta_key();
+
+    if ev.key() != "Tab" || is_meta_key {
+      return;
+    }
+
+    let is_tabbing_backwards = ev.shift_key();
+    let target_is_viewport = node_ref
+      .get_untracked()
+      .map(|viewport| {
+        viewport.is_same_node(
+          ev.target()
+            .and_then(|target| target.dyn_into::<Node>().ok())
+            .as_ref(),
+        )
+      })
+      .unwrap_or(false);
+
+    if target_is_viewport && is_tabbing_backwards {
+      if let Some(head_focus_proxy) = head_focus_proxy_ref.get_untracked() {
+        _ = head_focus_proxy.focus();
+      }
+
+      return;
+    }
+
+    let candidates = get_sorted_tabbable_candidates(is_tabbing_backwards);
+    let active_element = document().active_element();
+    let next_index = candidates
+      .iter()
+      .position(|candidate| {
+        let candidate: &web_sys::Element = candidate;
+        Some(candidate) == active_element.as_ref()
+      })
+      .map(|index| index + 1)
+      .unwrap_or(0);
+
+    if focus_first(&candidates[next_index..]) {
+      ev.prevent_default();
+    } else {
+      let focus_proxy = if is_tabbing_backwards {
+        head_focus_proxy_ref.get_untracked()
+      } else {
+        tail_focus_proxy_ref.get_untracked()
+      };
+
+      if let Some(focus_proxy) = focus_proxy {
+        _ = focus_proxy.focus();
+      }
+    }
+  });
+
+  let wrapper_attrs = vec![
+    ("role", "region".into_attribute()),
+    ("aria-label", (move || label.get()).into_attribute()),
+    ("tabindex", (-1).into_attribute()),
+    (
+      "style",
+      (move || (toast_count.get() == 0).then_some("pointer-events: none;")).into_attribute(),
+    ),
+  ];
+
+  let mut merged_attrs = vec![("tabindex", (-1).into_attribute())];
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <DismissableLayerBranch
+      node_ref=wrapper_ref
+      attrs=wrapper_attrs
+    >
+      <Show when=move || { toast_count.get() > 0 }>
+        <span
+          aria-hidden="true"
+          tabindex="0"
+          style="position: fixed; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; word-wrap: normal;"
+          node_ref=head_focus_proxy_ref
+          on:focus=move |ev: FocusEvent| handle_focus_proxy_focus(ev, false)
+        />
+      </Show>
+
+      <Primitive
+        element=html::ol
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children.as_ref().map(|children| children()))}
+      </Primitive>
+
+      <Show when=move || { toast_count.get() > 0 }>
+        <span
+          aria-hidden="true"
+          tabindex="0"
+          style="position: fixed; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; word-wrap: normal;"
+          node_ref=tail_focus_proxy_ref
+          on:focus=move |ev: FocusEvent| handle_focus_proxy_focus(ev, true)
+        />
+      </Show>
+    </DismissableLayerBranch>
+  }
+}
+
+#[derive(Clone)]
+struct ToastInteractiveContextValue {
+  on_close: Callback<()>,
+}
+
+#[component]
+pub fn ToastRoot(
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] toast_type: MaybeSignal<ToastType>,
+  #[prop(optional, into)] duration: MaybeProp<u64>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pause: Callback<()>,
+  #[prop(default=(|_|{}).into(), into)] on_resume: Callback<()>,
+  #[prop(default=(|_|{}).into(), into)] on_swipe_start: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_swipe_move: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_swipe_cancel: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_swipe_end: Callback<PointerEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || Some(default_open.get().unwrap_or(true))),
+    on_change: on_open_change,
+  });
+
+  let open = Signal::derive(move || open.get().unwrap_or(false));
+  let is_present = Signal::derive(move || force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+
+  let toast_type = Signal::derive(move || toast_type.get());
+  let duration = Signal::derive(move || duration.get());
+
+  let attrs = StoredValue::new(attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <ToastImpl
+        open=open
+        toast_type=toast_type
+        duration=duration
+        on_close=Callback::new(move |_| {
+          set_open.set(false);
+        })
+        on_escape_key_down=on_escape_key_down
+        on_pause=on_pause
+        on_resume=on_resume
+        on_swipe_start=on_swipe_start
+        on_swipe_move=on_swipe_move
+        on_swipe_cancel=on_swipe_cancel
+        on_swipe_end=on_swipe_end
+        node_ref=node_ref
+        attrs=attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </ToastImpl>
+    </Show>
+  }
+}
+
+#[component]
+fn ToastImpl(
+  open: Signal<bool>,
+  toast_type: Signal<ToastType>,
+  duration: Signal<Option<u64>>,
+  on_close: Callback<()>,
+
+  on_escape_key_down: Callback<KeyboardEvent>,
+  on_pause: Callback<()>,
+  on_resume: Callback<()>,
+  on_swipe_start: Callback<PointerEvent>,
+  on_swipe_move: Callback<PointerEvent>,
+  on_swipe_cancel: Callback<PointerEvent>,
+  on_swipe_end: Callback<PointerEvent>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ToastProviderContextValue {
+    label,
+    duration: provider_duration,
+    swipe_direction,
+    swipe_threshold,
+    viewport_ref,
+    toast_count,
+    is_paused,
+  } = use_context().expect("ToastRoot must be used in a ToastProvider component");
+
+  use_collection_item_ref::<AnyElement, ToastItemData>(node_ref, ToastItemData);
+
+  let duration = Signal::derive(move || duration.get().unwrap_or(provider_duration.get()));
+
+  let close_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let close_timer_start_time = StoredValue::new(0.0f64);
+  let close_timer_remaining_time = StoredValue::new(duration.get_untracked());
+  let pointer_start = StoredValue::<Option<(f64, f64)>>::new(None);
+  let swipe_delta = StoredValue::<Option<(f64, f64)>>::new(None);
+  let prevent_click = StoredValue::new(false);
+
+  let handle_close = move || {
+    let is_focus_in_toast = node_ref
+      .get_untracked()
+      .map(|node| node.contains(document().active_element().map(Node::from).as_ref()))
+      .unwrap_or(false);
+
+    if is_focus_in_toast {
+      if let Some(viewport) = viewport_ref.get_untracked() {
+        _ = viewport.focus();
+      }
+    }
+
+    on_close.call(());
+  };
+
+  let clear_close_timer = move || {
+    if let Some(handle) = close_timer.get_value() {
+      handle.clear();
+      close_timer.set_value(None);
+    }
+  };
+
+  let start_close_timer = move |duration: u64| {
+    if duration == 0 || duration == u64::MAX {
+      return;
+    }
+
+    clear_close_timer();
+    close_timer_start_time.set_value(Date::now());
+
+    if let Ok(handle) = set_timeout_with_handle(handle_close, Duration::from_millis(duration)) {
+      close_timer.set_value(Some(handle));
+    }
+  };
+
+  Effect::new(move |_| {
+    let duration = duration.get();
+
+    if open.get() && !is_paused.get_untracked() {
+      close_timer_remaining_time.set_value(duration);
+      start_close_timer(duration);
+    }
+  });
+
+  Effect::new(move |prev_paused: Option<bool>| {
+    let paused = is_paused.get();
+
+    if prev_paused.is_none() || prev_paused == Some(paused) {
+      return paused;
+    }
+
+    if paused {
+      if close_timer.get_value().is_some() {
+        let elapsed_time = (Date::now() - close_timer_start_time.get_value()).max(0.) as u64;
+
+        close_timer_remaining_time.set_value(
+          close_timer_remaining_time
+            .get_value()
+            .saturating_sub(elapsed_time),
+        );
+        clear_close_timer();
+      }
+
+      on_pause.call(());
+    } else {
+      start_close_timer(close_timer_remaining_time.get_value());
+      on_resume.call(());
+    }
+
+    paused
+  });
+
+  Effect::new(move |_| {
+    toast_count.update(|toast_count| *toast_count += 1);
+  });
+
+  on_cleanup(move || {
+    clear_close_timer();
+    _ = toast_count.try_update(|toast_count| *toast_count = toast_count.saturating_sub(1));
+  });
+
+  let announce_text_content = RwSignal::<Option<String>>::new(None);
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      let text_content = get_announce_text_content(&node).join(" ");
+
+      announce_text_content.set((!text_content.is_empty()).then_some(text_content));
+    }
+  });
+
+  _ = use_event_listener_with_options(
+    node_ref,
+    click,
+    move |ev: MouseEvent| {
+      if prevent_click.get_value() {
+        prevent_click.set_value(false);
+        ev.prevent_default();
+        ev.stop_propagation();
+      }
+    },
+    UseEventListenerOptions::default().capture(true),
+  );
+
+  provide_context(ToastInteractiveContextValue {
+    on_close: Callback::new(move |_| handle_close()),
+  });
+
+  let set_swipe_state = move |state: &str| {
+    if let Some(node) = node_ref.get_untracked() {
+      _ = node.set_attribute("data-swipe", state);
+    }
+  };
+
+  let set_swipe_vars = move |kind: &str, (x, y): (f64, f64)| {
+    if let Some(node) = node_ref.get_untracked() {
+      let style = HtmlElement::style(&node);
+
+      _ = style.set_property(&format!("--leptix-toast-swipe-{kind}-x"), &format!("{x}px"));
+      _ = style.set_property(&format!("--leptix-toast-swipe-{kind}-y"), &format!("{y}px"));
+    }
+  };
+
+  let remove_swipe_vars = move |kind: &str| {
+    if let Some(node) = node_ref.get_untracked() {
+      let style = HtmlElement::style(&node);
+
+      _ = style.remove_property(&format!("--leptix-toast-swipe-{kind}-x"));
+      _ = style.remove_property(&format!("--leptix-toast-swipe-{kind}-y"));
+    }
+  };
+
+  let mut merged_attrs = vec![
+    ("role", "status".into_attribute()),
+    ("aria-live", "off".into_attribute()),
+    ("aria-atomic", "true".into_attribute()),
+    ("tabindex", "0".into_attribute()),
+    (
+      "data-state",
+      (move || if open.get() { "open" } else { "closed" }).into_attribute(),
+    ),
+    (
+      "data-swipe-direction",
+      (move || swipe_direction.get().to_string()).into_attribute(),
+    ),
+    (
+      "style",
+      "user-select: none; touch-action: none;".into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    {move || {
+      announce_text_content
+        .get()
+        .map(|text_content| {
+          view! {
+            <ToastAnnounce
+              toast_type=toast_type
+              label=label
+              text_content=text_content
+            />
+          }
+        })
+    }}
+
+    <Portal container=viewport_ref>
+      <Primitive
+        element=html::li
+        on:keydown=move |ev: KeyboardEvent| {
+          if ev.key() != "Escape" {
+            return;
+          }
+
+          on_escape_key_down.call(ev.clone());
+
+          if !ev.default_prevented() {
+            ev.prevent_default();
+            handle_close();
+          }
+        }
+        on:pointerdown=move |ev: PointerEvent| {
+          if ev.button() != 0 {
+            return;
+          }
+
+          pointer_start.set_value(Some((ev.client_x() as f64, ev.client_y() as f64)));
+        }
+        on:pointermove=move |ev: PointerEvent| {
+          let Some((start_x, start_y)) = pointer_start.get_value() else {
+            return;
+          };
+
+          let x = ev.client_x() as f64 - start_x;
+          let y = ev.client_y() as f64 - start_y;
+          let swipe_direction = swipe_direction.get_untracked();
+          let delta = clamp_swipe_delta((x, y), swipe_direction);
+          let move_start_buffer = if ev.pointer_type() == "touch" { 10. } else { 2. };
+
+          if swipe_delta.get_value().is_some() {
+            swipe_delta.set_value(Some(delta));
+            on_swipe_move.call(ev.clone());
+
+            if !ev.default_prevented() {
+              set_swipe_state("move");
+              set_swipe_vars("move", delta);
+            }
+          } else if is_delta_in_direction(delta, swipe_direction, move_start_buffer) {
+            swipe_delta.set_value(Some(delta));
+            on_swipe_start.call(ev.clone());
+
+            if !ev.default_prevented() {
+              set_swipe_state("start");
+            }
+
+            if let Some(target) = ev
+              .target()
+              .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            {
+              _ = target.set_pointer_capture(ev.pointer_id());
+            }
+          } else if x.abs() > move_start_buffer || y.abs() > move_start_buffer {
+            pointer_start.set_value(None);
+          }
+        }
+        on:pointerup=move |ev: PointerEvent| {
+          let delta = swipe_delta.get_value();
+
+          if let Some(target) = ev
+            .target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+          {
+            if target.has_pointer_capture(ev.pointer_id()) {
+              _ = target.release_pointer_capture(ev.pointer_id());
+            }
+          }
+
+          swipe_delta.set_value(None);
+          pointer_start.set_value(None);
+
+          let Some(delta) = delta else {
+            return;
+          };
+
+          if is_delta_in_direction(
+            delta,
+            swipe_direction.get_untracked(),
+            swipe_threshold.get_untracked(),
+          ) {
+            on_swipe_end.call(ev.clone());
+
+            if !ev.default_prevented() {
+              set_swipe_state("end");
+              remove_swipe_vars("move");
+              set_swipe_vars("end", delta);
+              handle_close();
+            }
+          } else {
+            on_swipe_cancel.call(ev.clone());
+
+            if !ev.default_prevented() {
+              set_swipe_state("cancel");
+              remove_swipe_vars("move");
+              remove_swipe_vars("end");
+            }
+          }
+
+          prevent_click.set_value(true);
+        }
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </Portal>
+  }
+}
+
+#[component]
+fn ToastAnnounce(
+  toast_type: Signal<ToastType>,
+  label: Signal<String>,
+  text_content: String,
+) -> impl IntoView {
+  let render_announce_text = RwSignal::new(false);
+  let is_announced = RwSignal::new(false);
+
+  Effect::new(move |_| {
+    request_animation_frame(move || _ = render_announce_text.try_set(true));
+    set_timeout(
+      move || _ = is_announced.try_set(true),
+      Duration::from_millis(1000),
+    );
+  });
+
+  let text_content = StoredValue::new(text_content);
+
+  view! {
+    <Show when=move || !is_announced.get()>
+      <Portal>
+        <div
+          role="status"
+          aria-live=move || match toast_type.get() {
+            ToastType::Foreground => "assertive",
+            ToastType::Background => "polite",
+          }
+          aria-atomic="true"
+          style="position: absolute; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; word-wrap: normal;"
+        >
+          <Show when=move || render_announce_text.get()>
+            {label.get()} " " {text_content.get_value()}
+          </Show>
+        </div>
+      </Portal>
+    </Show>
+  }
+}
+
+#[component]
+pub fn ToastTitle(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ToastDescription(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <Primitive
+      element=html::div
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn ToastAction(
+  #[prop(into)] alt_text: MaybeSignal<String>,
+
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let mut merged_attrs = vec![
+    (ANNOUNCE_EXCLUDE_ATTRIBUTE, "".into_attribute()),
+    (
+      ANNOUNCE_ALT_ATTRIBUTE,
+      (move || alt_text.get()).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  ToastClose(ToastCloseProps {
+    on_click,
+    node_ref,
+    attrs: merged_attrs,
+    children,
+    as_child,
+  })
+}
+
+#[component]
+pub fn ToastClose(
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let ToastInteractiveContextValue { on_close } =
+    use_context().expect("ToastClose must be used in a ToastRoot component");
+
+  let mut merged_attrs = vec![("type", "button".into_attribute())];
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::button
+      on:click=move |ev: MouseEvent| {
+        on_click.call(ev.clone());
+
+        if !ev.default_prevented() {
+          on_close.call(());
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+fn get_announce_text_content(container: &Node) -> Vec<String> {
+  let mut text_content = vec![];
+  let child_nodes = container.child_nodes();
+
+  for index in 0..child_nodes.length() {
+    let Some(node) = child_nodes.item(index) else {
+      continue;
+    };
+
+    if node.node_type() == Node::TEXT_NODE {
+      if let Some(text) = node.text_content().filter(|text| !text.trim().is_empty()) {
+        text_content.push(text);
+      }
+
+      continue;
+    }
+
+    let Some(element) = node.dyn_ref::<HtmlElement>() else {
+      continue;
+    };
+
+    let is_hidden = element.hidden()
+      || element.get_attribute("aria-hidden").as_deref() == Some("true")
+      || element.style().get_property_value("display").as_deref() == Ok("none");
+
+    if is_hidden {
+      continue;
+    }
+
+    if element.has_attribute(ANNOUNCE_EXCLUDE_ATTRIBUTE) {
+      if let Some(alt_text) = element
+        .get_attribute(ANNOUNCE_ALT_ATTRIBUTE)
+        .filter(|alt_text| !alt_text.is_empty())
+      {
+        text_content.push(alt_text);
+      }
+    } else {
+      text_content.extend(get_announce_text_content(&node));
+    }
+  }
+
+  text_content
+}
+
+fn clamp_swipe_delta((x, y): (f64, f64), direction: SwipeDirection) -> (f64, f64) {
+  match direction {
+    SwipeDirection::Left => (x.min(0.), 0.),
+    SwipeDirection::Right => (x.max(0.), 0.),
+    SwipeDirection::Up => (0., y.min(0.)),
+    SwipeDirection::Down => (0., y.max(0.)),
+  }
+}
+
+fn is_delta_in_direction((x, y): (f64, f64), direction: SwipeDirection, threshold: f64) -> bool {
+  let delta_x = x.abs();
+  let delta_y = y.abs();
+  let is_delta_x = delta_x > delta_y;
+
+  match direction {
+    SwipeDirection::Left | SwipeDirection::Right => is_delta_x && delta_x > threshold,
+    SwipeDirection::Up | SwipeDirection::Down => !is_delta_x && delta_y > threshold,
+  }
+}
+
+#[test]
+fn swipe_delta_only_counts_towards_swipe_direction() {
+  assert_eq!(
+    clamp_swipe_delta((-20., 5.), SwipeDirection::Right),
+    (0., 0.)
+  );
+  assert_eq!(
+    clamp_swipe_delta((-20., 5.), SwipeDirection::Left),
+    (-20., 0.)
+  );
+  assert_eq!(
+    clamp_swipe_delta((3., 40.), SwipeDirection::Down),
+    (0., 40.)
+  );
+
+  assert!(is_delta_in_direction((60., 0.), SwipeDirection::Right, 50.));
+  assert!(!is_delta_in_direction(
+    (40., 0.),
+    SwipeDirection::Right,
+    50.
+  ));
+  assert!(!is_delta_in_direction(
+    (0., 60.),
+    SwipeDirection::Right,
+    50.
+  ));
+  assert!(is_delta_in_direction((0., -60.), SwipeDirection::Up, 50.));
+}
@@ -8,6 +8,7 @@ use crate::{
   },
   util::{
     create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    form_data::get_form_field_name,
     Attributes,
   },
   Direction, Orientation,
@@ -45,6 +46,7 @@ impl ToggleGroupMultiple {
 pub fn ToggleGroupRoot(
   kind: ToggleGroupKind,
 
+  #[prop(optional, into)] name: MaybeProp<String>,
   #[prop(optional, into)] disabled: MaybeSignal<bool>,
   #[prop(default=true.into(), into)] roving_focus: MaybeSignal<bool>,
   #[prop(default=true.into(), into)] should_loop: MaybeSignal<bool>,
@@ -64,6 +66,7 @@ pub fn ToggleGroupRoot(
       on_value_change,
     } => view! {
       <ToggleGroupSingleImpl
+        name=name.clone()
         disabled=disabled
         roving_focus=roving_focus
         should_loop=should_loop
@@ -85,6 +88,7 @@ pub fn ToggleGroupRoot(
       on_value_change,
     } => view! {
       <ToggleGroupMultipleImpl
+        name=name.clone()
         disabled=disabled
         roving_focus=roving_focus
         should_loop=should_loop
@@ -119,6 +123,7 @@ struct ToggleGroupValueContextValue {
 
 #[component]
 fn ToggleGroupSingleImpl(
+  name: MaybeProp<String>,
   disabled: MaybeSignal<bool>,
   roving_focus: MaybeSignal<bool>,
   should_loop: MaybeSignal<bool>,
@@ -154,6 +159,7 @@ fn ToggleGroupSingleImpl(
 
   view! {
     <ToggleGroup
+      name=name
       disabled=disabled
       roving_focus=roving_focus
       should_loop=should_loop
@@ -170,6 +176,7 @@ fn ToggleGroupSingleImpl(
 
 #[component]
 fn ToggleGroupMultipleImpl(
+  name: MaybeProp<String>,
   disabled: MaybeSignal<bool>,
   roving_focus: MaybeSignal<bool>,
   should_loop: MaybeSignal<bool>,
@@ -222,6 +229,7 @@ fn ToggleGroupMultipleImpl(
 
   view! {
     <ToggleGroup
+      name=name
       disabled=disabled
       roving_focus=roving_focus
       should_loop=should_loop
@@ -244,6 +252,7 @@ struct ToggleGroupStateContextValue {
 
 #[component]
 fn ToggleGroup(
+  name: MaybeProp<String>,
   disabled: MaybeSignal<bool>,
   roving_focus: MaybeSignal<bool>,
   should_loop: MaybeSignal<bool>,
@@ -261,6 +270,10 @@ fn ToggleGroup(
     disabled: Signal::derive(move || disabled.get()),
   });
 
+  let ToggleGroupValueContextValue { kind, value, .. } =
+    use_context().expect("ToggleGroup must be used in a ToggleGroupRoot component");
+  let is_multiple = kind == ToggleGroupValueKind::Multiple;
+
   let mut merged_attrs = vec![
     ("role", "group".into_attribute()),
     (
@@ -304,6 +317,26 @@ fn ToggleGroup(
         </Primitive>
       </RovingFocusGroup>
     </Show>
+
+    {move || {
+      name.get().map(|name| {
+        let name = get_form_field_name(&name, is_multiple);
+
+        value
+          .get()
+          .into_iter()
+          .filter(|value| !value.is_empty())
+          .map(|value| view! {
+            <input
+              type="hidden"
+              name=name.clone()
+              value=value
+              disabled=disabled.get()
+            />
+          })
+          .collect_view()
+      })
+    }}
   }
 }
 
@@ -0,0 +1,689 @@
+use std::time::Duration;
+
+use leptos::{
+  ev::{pointerleave, pointermove, pointerup, scroll, Custom},
+  html::AnyElement,
+  leptos_dom::helpers::TimeoutHandle,
+  *,
+};
+use leptos_use::{
+  use_document, use_event_listener, use_event_listener_with_options, use_window,
+  UseEventListenerOptions,
+};
+use wasm_bindgen::JsCast;
+use web_sys::{CustomEvent, Event, FocusEvent, KeyboardEvent, MouseEvent, Node, PointerEvent};
+
+use crate::{
+  dismissable_layer::DismissableLayer,
+  popper::{
+    Align, PopperAnchor, PopperArrow, PopperArrowProps, PopperContent, PopperRoot, Rect, Side,
+    Sticky,
+  },
+  portal::{Portal, PortalContainer, PortalProps},
+  presence::create_presence,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    polygon::{get_hull, is_point_in_polygon, Point},
+    use_id::{create_id, create_part_id},
+    Attributes,
+  },
+};
+
+const TOOLTIP_OPEN: &str = "tooltip.open";
+
+#[derive(Clone)]
+struct TooltipProviderContextValue {
+  is_open_delayed: Signal<bool>,
+  delay_duration: Signal<u64>,
+  on_open: Callback<()>,
+  on_close: Callback<()>,
+  is_pointer_in_transit: StoredValue<bool>,
+  disable_hoverable_content: Signal<bool>,
+}
+
+#[component]
+pub fn TooltipProvider(
+  #[prop(default=700.into(), into)] delay_duration: MaybeSignal<u64>,
+  #[prop(default=300.into(), into)] skip_delay_duration: MaybeSignal<u64>,
+  #[prop(optional, into)] disable_hoverable_content: MaybeSignal<bool>,
+
+  children: Children,
+) -> impl IntoView {
+  let (is_open_delayed, set_is_open_delayed) = create_signal(true);
+  let skip_delay_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  on_cleanup(move || {
+    if let Some(handle) = skip_delay_timer.get_value() {
+      handle.clear();
+    }
+  });
+
+  provide_context(TooltipProviderContextValue {
+    is_open_delayed: is_open_delayed.into(),
+    delay_duration: Signal::derive(move || delay_duration.get()),
+    on_open: Callback::new(move |_| {
+      if let Some(handle) = skip_delay_timer.get_value() {
+        handle.clear();
+      }
+
+      set_is_open_delayed.set(false);
+    }),
+    on_close: Callback::new(move |_| {
+      if let Some(handle) = skip_delay_timer.get_value() {
+        handle.clear();
+      }
+
+      if let Ok(handle) = set_timeout_with_handle(
+        move || set_is_open_delayed.set(true),
+        Duration::from_millis(skip_delay_duration.get_untracked()),
+      ) {
+        skip_delay_timer.set_value(Some(handle));
+      }
+    }),
+    is_pointer_in_transit: StoredValue::new(false),
+    disable_hoverable_content: Signal::derive(move || disable_hoverable_content.get()),
+  });
+
+  children()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum TooltipState {
+  Closed,
+  DelayedOpen,
+  InstantOpen,
+}
+
+impl TooltipState {
+  fn as_str(&self) -> &'static str {
+    match self {
+      Self::Closed => "closed",
+      Self::DelayedOpen => "delayed-open",
+      Self::InstantOpen => "instant-open",
+    }
+  }
+}
+
+#[derive(Clone)]
+struct TooltipContextValue {
+  content_id: Signal<String>,
+  open: Signal<bool>,
+  state: Signal<TooltipState>,
+  trigger_ref: NodeRef<AnyElement>,
+  on_trigger_enter: Callback<()>,
+  on_trigger_leave: Callback<()>,
+  on_open: Callback<()>,
+  on_close: Callback<()>,
+  disable_hoverable_content: Signal<bool>,
+}
+
+#[component]
+pub fn TooltipRoot(
+  #[prop(optional, into)] id: MaybeProp<String>,
+  #[prop(optional, into)] open: MaybeProp<bool>,
+  #[prop(optional, into)] default_open: MaybeProp<bool>,
+  #[prop(optional, into)] delay_duration: MaybeProp<u64>,
+  #[prop(optional, into)] disable_hoverable_content: MaybeProp<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_open_change: Callback<bool>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  let provider = use_context::<TooltipProviderContextValue>()
+    .expect("TooltipRoot must be used in a TooltipProvider component");
+
+  let open_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+  let was_open_delayed = RwSignal::new(false);
+
+  let (open, set_open) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || open.get()),
+    default_value: Signal::derive(move || default_open.get()),
+    on_change: Callback::new(move |open| {
+      if open {
+        provider.on_open.call(());
+
+        if let Ok(ev) = CustomEvent::new(TOOLTIP_OPEN) {
+          _ = document().dispatch_event(&ev);
+        }
+      } else {
+        provider.on_close.call(());
+      }
+
+      on_open_change.call(open);
+    }),
+  });
+
+  let open = Signal::derive(move || open.get().unwrap_or(false));
+  let delay_duration = Signal::derive(move || {
+    delay_duration
+      .get()
+      .unwrap_or(provider.delay_duration.get())
+  });
+  let disable_hoverable_content = Signal::derive(move || {
+    disable_hoverable_content
+      .get()
+      .unwrap_or(provider.disable_hoverable_content.get())
+  });
+
+  let clear_open_timer = move || {
+    if let Some(handle) = open_timer.get_value() {
+      handle.clear();
+      open_timer.set_value(None);
+    }
+  };
+
+  on_cleanup(clear_open_timer);
+
+  let handle_open = Callback::new(move |_: ()| {
+    clear_open_timer();
+    was_open_delayed.set(false);
+    set_open.set(true);
+  });
+
+  let handle_close = Callback::new(move |_: ()| {
+    clear_open_timer();
+    set_open.set(false);
+  });
+
+  let handle_delayed_open = move || {
+    clear_open_timer();
+
+    if let Ok(handle) = set_timeout_with_handle(
+      move || {
+        was_open_delayed.set(true);
+        set_open.set(true);
+      },
+      Duration::from_millis(delay_duration.get_untracked()),
+    ) {
+      open_timer.set_value(Some(handle));
+    }
+  };
+
+  let id = create_id(id);
+
+  provide_context(TooltipContextValue {
+    content_id: create_part_id(id, "content"),
+    open,
+    state: Signal::derive(move || {
+      if !open.get() {
+        TooltipState::Closed
+      } else if was_open_delayed.get() {
+        TooltipState::DelayedOpen
+      } else {
+        TooltipState::InstantOpen
+      }
+    }),
+    trigger_ref: NodeRef::new(),
+    on_trigger_enter: Callback::new(move |_| {
+      if provider.is_open_delayed.get_untracked() {
+        handle_delayed_open();
+      } else {
+        handle_open.call(());
+      }
+    }),
+    on_trigger_leave: Callback::new(move |_| {
+      if disable_hoverable_content.get_untracked() {
+        handle_close.call(());
+      } else {
+        clear_open_timer();
+      }
+    }),
+    on_open: handle_open,
+    on_close: handle_close,
+    disable_hoverable_content,
+  });
+
+  view! {
+    <PopperRoot>
+      {children()}
+    </PopperRoot>
+  }
+}
+
+#[component]
+pub fn TooltipTrigger(
+  #[prop(default=(|_|{}).into(), into)] on_pointer_move: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_leave: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down: Callback<PointerEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus: Callback<FocusEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_blur: Callback<FocusEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let TooltipProviderContextValue {
+    is_pointer_in_transit,
+    ..
+  } = use_context().expect("TooltipTrigger must be used in a TooltipProvider component");
+  let TooltipContextValue {
+    content_id,
+    open,
+    state,
+    trigger_ref,
+    on_trigger_enter,
+    on_trigger_leave,
+    on_open,
+    on_close,
+    ..
+  } = use_context().expect("TooltipTrigger must be used in a TooltipRoot component");
+
+  let is_pointer_down = StoredValue::new(false);
+  let has_pointer_move_opened = StoredValue::new(false);
+
+  Effect::new(move |_| {
+    if let Some(node) = node_ref.get() {
+      trigger_ref.load(&node);
+    }
+  });
+
+  _ = use_event_listener(use_document(), pointerup, move |_| {
+    is_pointer_down.set_value(false);
+  });
+
+  let mut merged_attrs = vec![
+    (
+      "aria-describedby",
+      (move || open.get().then(|| content_id.get())).into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || state.get().as_str()).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <PopperAnchor as_child=true node_ref=node_ref>
+      <Primitive
+        element=html::button
+        on:pointermove=move |ev: PointerEvent| {
+          on_pointer_move.call(ev.clone());
+
+          if ev.pointer_type() == "touch" {
+            return;
+          }
+
+          if !has_pointer_move_opened.get_value() && !is_pointer_in_transit.get_value() {
+            on_trigger_enter.call(());
+            has_pointer_move_opened.set_value(true);
+          }
+        }
+        on:pointerleave=move |ev: PointerEvent| {
+          on_pointer_leave.call(ev);
+          on_trigger_leave.call(());
+          has_pointer_move_opened.set_value(false);
+        }
+        on:pointerdown=move |ev: PointerEvent| {
+          on_pointer_down.call(ev);
+          is_pointer_down.set_value(true);
+        }
+        on:focus=move |ev: FocusEvent| {
+          on_focus.call(ev);
+
+          if !is_pointer_down.get_value() {
+            on_open.call(());
+          }
+        }
+        on:blur=move |ev: FocusEvent| {
+          on_blur.call(ev);
+          on_close.call(());
+        }
+        on:click=move |ev: MouseEvent| {
+          on_click.call(ev);
+          on_close.call(());
+        }
+        node_ref=node_ref
+        attrs=merged_attrs.clone()
+        as_child=as_child
+      >
+        {children.with_value(|children| children())}
+      </Primitive>
+    </PopperAnchor>
+  }
+}
+
+#[derive(Clone)]
+struct TooltipPortalContextValue {
+  force_mount: Signal<bool>,
+}
+
+#[component]
+pub fn TooltipPortal(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] container: Option<PortalContainer>,
+
+  children: ChildrenFn,
+) -> impl IntoView {
+  provide_context(TooltipPortalContextValue {
+    force_mount: Signal::derive(move || force_mount.get()),
+  });
+
+  Portal(PortalProps {
+    container,
+    children,
+  })
+}
+
+#[component]
+pub fn TooltipContent(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+  #[prop(optional, into)] aria_label: MaybeProp<String>,
+
+  #[prop(default=Side::Top.into(), into)] side: MaybeSignal<Side>,
+  #[prop(optional, into)] side_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] align: MaybeSignal<Align>,
+  #[prop(optional, into)] align_offset: MaybeSignal<f64>,
+  #[prop(optional, into)] arrow_padding: MaybeSignal<f64>,
+  #[prop(default=true.into(), into)] avoid_collisions: MaybeSignal<bool>,
+  #[prop(optional, into)] collision_boundary: Vec<NodeRef<AnyElement>>,
+  #[prop(optional, into)] collision_padding: MaybeSignal<f64>,
+  #[prop(optional, into)] sticky: MaybeSignal<Sticky>,
+  #[prop(optional, into)] hide_when_detached: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_escape_key_down: Callback<KeyboardEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_pointer_down_outside: Callback<PointerEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let TooltipProviderContextValue {
+    is_pointer_in_transit,
+    ..
+  } = use_context().expect("TooltipContent must be used in a TooltipProvider component");
+  let TooltipContextValue {
+    content_id,
+    open,
+    state,
+    trigger_ref,
+    on_close,
+    disable_hoverable_content,
+    ..
+  } = use_context().expect("TooltipContent must be used in a TooltipRoot component");
+  let portal_force_mount = use_context::<TooltipPortalContextValue>()
+    .map(|portal| portal.force_mount)
+    .unwrap_or(Signal::derive(|| false));
+
+  let is_present =
+    Signal::derive(move || force_mount.get() || portal_force_mount.get() || open.get());
+  let presence = create_presence(is_present, node_ref);
+  let layer_ref = NodeRef::<AnyElement>::new();
+  let aria_label = Signal::derive(move || aria_label.get());
+
+  let pointer_grace_area = StoredValue::<Option<Vec<Point>>>::new(None);
+
+  let remove_grace_area = move || {
+    pointer_grace_area.set_value(None);
+    is_pointer_in_transit.set_value(false);
+  };
+
+  let create_grace_area = move |ev: &PointerEvent, hover_target: NodeRef<AnyElement>| {
+    let (Some(current_target), Some(hover_target)) = (
+      ev.current_target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok()),
+      hover_target.get_untracked(),
+    ) else {
+      return;
+    };
+
+    let exit_point = Point::new(ev.client_x() as f64, ev.client_y() as f64);
+    let exit_side = get_exit_side_from_rect(
+      exit_point,
+      &current_target.get_bounding_client_rect().into(),
+    );
+
+    let mut points = get_padded_exit_points(exit_point, exit_side, 5.);
+    points.extend(get_points_from_rect(
+      &hover_target.get_bounding_client_rect().into(),
+    ));
+
+    pointer_grace_area.set_value(Some(get_hull(points)));
+    is_pointer_in_transit.set_value(true);
+  };
+
+  _ = use_event_listener(trigger_ref, pointerleave, move |ev: PointerEvent| {
+    if !disable_hoverable_content.get_untracked() {
+      create_grace_area(&ev, node_ref);
+    }
+  });
+
+  _ = use_event_listener(node_ref, pointerleave, move |ev: PointerEvent| {
+    if !disable_hoverable_content.get_untracked() {
+      create_grace_area(&ev, trigger_ref);
+    }
+  });
+
+  _ = use_event_listener(use_document(), pointermove, move |ev: PointerEvent| {
+    let Some(grace_area) = pointer_grace_area.get_value() else {
+      return;
+    };
+
+    let target = ev
+      .target()
+      .and_then(|target| target.dyn_into::<Node>().ok());
+    let contains_target = |node: NodeRef<AnyElement>| {
+      node
+        .get_untracked()
+        .map(|node| node.contains(target.as_ref()))
+        .unwrap_or(false)
+    };
+
+    let point = Point::new(ev.client_x() as f64, ev.client_y() as f64);
+
+    if contains_target(trigger_ref) || contains_target(node_ref) {
+      remove_grace_area();
+    } else if !is_point_in_polygon(point, &grace_area) {
+      remove_grace_area();
+      on_close.call(());
+    }
+  });
+
+  _ = use_event_listener(
+    use_document(),
+    Custom::<Event>::new(TOOLTIP_OPEN),
+    move |_| {
+      if open.get_untracked() {
+        on_close.call(());
+      }
+    },
+  );
+
+  _ = use_event_listener_with_options(
+    use_window(),
+    scroll,
+    move |ev: Event| {
+      let target = ev
+        .target()
+        .and_then(|target| target.dyn_into::<Node>().ok());
+
+      let Some(trigger) = trigger_ref.get_untracked() else {
+        return;
+      };
+
+      let trigger: &Node = &trigger;
+
+      if target.map(|target| target.contains(Some(trigger))) == Some(true) {
+        on_close.call(());
+      }
+    },
+    UseEventListenerOptions::default()
+      .capture(true)
+      .passive(true),
+  );
+
+  Effect::new(move |_| {
+    let Some(node) = node_ref.get() else {
+      return;
+    };
+
+    _ = node
+      .style(
+        "--leptix-tooltip-content-transform-origin",
+        "var(--leptix-popper-transform-origin)",
+      )
+      .style(
+        "--leptix-tooltip-content-available-width",
+        "var(--leptix-popper-available-width)",
+      )
+      .style(
+        "--leptix-tooltip-content-available-height",
+        "var(--leptix-popper-available-height)",
+      )
+      .style(
+        "--leptix-tooltip-trigger-width",
+        "var(--leptix-popper-anchor-width)",
+      )
+      .style(
+        "--leptix-tooltip-trigger-height",
+        "var(--leptix-popper-anchor-height)",
+      );
+  });
+
+  let mut merged_attrs = vec![(
+    "data-state",
+    (move || state.get().as_str()).into_attribute(),
+  )];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let collision_boundary = StoredValue::new(collision_boundary);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || presence.get()>
+      <DismissableLayer
+        as_child=true
+        on_escape_key_down=on_escape_key_down
+        on_pointer_down_outside=on_pointer_down_outside
+        on_focus_outside=Callback::new(|ev: FocusEvent| ev.prevent_default())
+        on_dismiss=Callback::new(move |_| on_close.call(()))
+        node_ref=layer_ref
+      >
+        <PopperContent
+          side=side
+          side_offset=side_offset
+          align=align
+          align_offset=align_offset
+          arrow_padding=arrow_padding
+          avoid_collisions=avoid_collisions
+          collision_boundary=collision_boundary.get_value()
+          collision_padding=collision_padding
+          sticky=sticky
+          hide_when_detached=hide_when_detached
+          node_ref=node_ref
+          attrs=merged_attrs.get_value()
+          as_child=as_child
+        >
+          {children.with_value(|children| children())}
+          <span
+            role="tooltip"
+            id=move || content_id.get()
+            style="position: absolute; border: 0; width: 1px; height: 1px; padding: 0; margin: -1px; overflow: hidden; clip: rect(0, 0, 0, 0); white-space: nowrap; word-wrap: normal;"
+          >
+            {move || match aria_label.get() {
+              Some(aria_label) => aria_label.into_view(),
+              None => children.with_value(|children| children()).into_view(),
+            }}
+          </span>
+        </PopperContent>
+      </DismissableLayer>
+    </Show>
+  }
+}
+
+#[component]
+pub fn TooltipArrow(
+  #[prop(default=10.0f64.into(), into)] width: MaybeSignal<f64>,
+  #[prop(default=5.0f64.into(), into)] height: MaybeSignal<f64>,
+
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+  PopperArrow(PopperArrowProps {
+    width,
+    height,
+    attrs,
+    children,
+  })
+}
+
+fn get_exit_side_from_rect(point: Point, rect: &Rect) -> Side {
+  let top = (rect.y - point.y).abs();
+  let bottom = (rect.bottom() - point.y).abs();
+  let right = (rect.right() - point.x).abs();
+  let left = (rect.x - point.x).abs();
+
+  let min = top.min(bottom).min(right).min(left);
+
+  if min == left {
+    Side::Left
+  } else if min == right {
+    Side::Right
+  } else if min == top {
+    Side::Top
+  } else {
+    Side::Bottom
+  }
+}
+
+fn get_padded_exit_points(exit_point: Point, exit_side: Side, padding: f64) -> Vec<Point> {
+  let Point { x, y } = exit_point;
+
+  match exit_side {
+    Side::Top => vec![
+      Point::new(x - padding, y + padding),
+      Point::new(x + padding, y + padding),
+    ],
+    Side::Bottom => vec![
+      Point::new(x - padding, y - padding),
+      Point::new(x + padding, y - padding),
+    ],
+    Side::Left => vec![
+      Point::new(x + padding, y - padding),
+      Point::new(x + padding, y + padding),
+    ],
+    Side::Right => vec![
+      Point::new(x - padding, y - padding),
+      Point::new(x - padding, y + padding),
+    ],
+  }
+}
+
+fn get_points_from_rect(rect: &Rect) -> [Point; 4] {
+  [
+    Point::new(rect.x, rect.y),
+    Point::new(rect.right(), rect.y),
+    Point::new(rect.right(), rect.bottom()),
+    Point::new(rect.x, rect.bottom()),
+  ]
+}
+
+#[test]
+fn grace_area_covers_gap_between_trigger_and_content() {
+  let trigger = Rect::new(100., 100., 40., 20.);
+  let content = Rect::new(80., 40., 80., 40.);
+  let exit_point = Point::new(120., 100.);
+
+  let mut points = get_padded_exit_points(
+    exit_point,
+    get_exit_side_from_rect(exit_point, &trigger),
+    5.,
+  );
+  points.extend(get_points_from_rect(&content));
+
+  let grace_area = get_hull(points);
+
+  assert!(is_point_in_polygon(Point::new(120., 90.), &grace_area));
+  assert!(!is_point_in_polygon(Point::new(60., 95.), &grace_area));
+}
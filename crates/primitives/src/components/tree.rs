@@ -0,0 +1,840 @@
+use std::{collections::HashMap, future::Future, pin::Pin, rc::Rc, time::Duration};
+
+use leptos::{html::AnyElement, leptos_dom::helpers::TimeoutHandle, *};
+use wasm_bindgen::JsCast;
+use web_sys::{Element, FocusEvent, KeyboardEvent, MouseEvent, Node};
+
+use crate::{
+  collection::{use_collection_context, use_collection_item_ref, CollectionContextValue},
+  menu::get_next_match,
+  primitive::Primitive,
+  util::{
+    create_controllable_signal::{create_controllable_signal, CreateControllableSignalProps},
+    Attributes,
+  },
+};
+
+pub enum TreeKind {
+  Single {
+    value: MaybeProp<String>,
+    default_value: MaybeProp<String>,
+    on_value_change: Option<Callback<String>>,
+  },
+  Multiple {
+    value: MaybeProp<Vec<String>>,
+    default_value: MaybeProp<Vec<String>>,
+    on_value_change: Option<Callback<Vec<String>>>,
+  },
+}
+
+pub struct TreeSingle;
+pub struct TreeMultiple;
+
+impl TreeSingle {
+  pub fn none() -> Option<String> {
+    None
+  }
+}
+
+impl TreeMultiple {
+  pub fn none() -> Option<Vec<String>> {
+    None
+  }
+}
+
+type LoadChildren = Rc<dyn Fn(String) -> Pin<Box<dyn Future<Output = bool>>>>;
+
+#[derive(Clone)]
+pub struct TreeLoader(LoadChildren);
+
+impl TreeLoader {
+  pub fn new<F, Fut>(loader: F) -> Self
+  where
+    F: Fn(String) -> Fut + 'static,
+    Fut: Future<Output = bool> + 'static,
+  {
+    Self(Rc::new(move |value| Box::pin(loader(value))))
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum TreeLoadState {
+  Idle,
+  Loading,
+  Loaded,
+}
+
+#[derive(Clone)]
+struct TreeItemData {
+  value: String,
+  parent: Option<String>,
+  is_branch: bool,
+  disabled: Signal<bool>,
+  text_value: Option<String>,
+}
+
+impl std::fmt::Debug for TreeItemData {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("TreeItemData")
+      .field("value", &self.value)
+      .field("parent", &self.parent)
+      .field("text_value", &self.text_value)
+      .finish()
+  }
+}
+
+impl PartialEq for TreeItemData {
+  fn eq(&self, other: &Self) -> bool {
+    self.value == other.value
+  }
+}
+
+impl Eq for TreeItemData {}
+
+impl Ord for TreeItemData {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.value.cmp(&other.value)
+  }
+}
+
+impl PartialOrd for TreeItemData {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+#[derive(Clone, Copy)]
+struct TreeContextValue {
+  value: Signal<Vec<String>>,
+  on_item_select: Callback<String>,
+  expanded: Signal<Vec<String>>,
+  on_expanded_change: Callback<(String, bool)>,
+  focused: RwSignal<Option<String>>,
+  tab_stop: Memo<Option<String>>,
+  positions: Memo<HashMap<String, (usize, usize)>>,
+  disabled: Signal<bool>,
+}
+
+#[derive(Clone)]
+struct TreeLevelContextValue {
+  level: usize,
+  parent: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+struct TreeBranchContextValue {
+  value: StoredValue<String>,
+  is_expanded: Signal<bool>,
+  load_state: RwSignal<TreeLoadState>,
+}
+
+#[component]
+pub fn TreeRoot(
+  kind: TreeKind,
+
+  #[prop(optional, into)] expanded: MaybeProp<Vec<String>>,
+  #[prop(optional, into)] default_expanded: MaybeProp<Vec<String>>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_expanded_change: Callback<Vec<String>>,
+  #[prop(default=(|_|{}).into(), into)] on_key_down: Callback<KeyboardEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let (value, set_value, multiple) = match kind {
+    TreeKind::Single {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      (
+        Signal::derive(move || value.get().into_iter().collect::<Vec<_>>()),
+        Callback::new(move |values: Vec<String>| {
+          set_value.set(values.into_iter().next().unwrap_or_default());
+        }),
+        false,
+      )
+    }
+    TreeKind::Multiple {
+      value,
+      default_value,
+      on_value_change,
+    } => {
+      let (value, set_value) = create_controllable_signal(CreateControllableSignalProps {
+        value: Signal::derive(move || value.get()),
+        default_value: Signal::derive(move || default_value.get()),
+        on_change: on_value_change.unwrap_or((|_| {}).into()),
+      });
+
+      (
+        Signal::derive(move || value.get().unwrap_or_default()),
+        Callback::new(move |values: Vec<String>| {
+          set_value.set(values);
+        }),
+        true,
+      )
+    }
+  };
+
+  let (expanded, set_expanded) = create_controllable_signal(CreateControllableSignalProps {
+    value: Signal::derive(move || expanded.get()),
+    default_value: Signal::derive(move || default_expanded.get()),
+    on_change: on_expanded_change,
+  });
+
+  let expanded = Signal::derive(move || expanded.get().unwrap_or_default());
+  let on_expanded_change = Callback::new(move |(item_value, is_expanded): (String, bool)| {
+    let mut values = expanded.get_untracked();
+    let is_current = values.contains(&item_value);
+
+    if is_expanded && !is_current {
+      values.push(item_value);
+    } else if !is_expanded && is_current {
+      values.retain(|value| *value != item_value);
+    } else {
+      return;
+    }
+
+    set_expanded.set(values);
+  });
+
+  provide_context(CollectionContextValue::<TreeItemData, AnyElement> {
+    collection_ref: node_ref,
+    item_map: RwSignal::new(HashMap::new()),
+  });
+
+  let get_items = use_collection_context::<TreeItemData, AnyElement>();
+  let get_enabled_items = move || {
+    get_items
+      .get_untracked()
+      .into_iter()
+      .filter(|(_, data)| !data.disabled.get_untracked())
+      .collect::<Vec<_>>()
+  };
+
+  let focused = RwSignal::new(None::<String>);
+  let disabled = Signal::derive(move || disabled.get());
+
+  let tab_stop = Memo::new(move |_| {
+    let items = get_items.get();
+    let is_enabled = |value: &String| {
+      items
+        .iter()
+        .any(|(_, data)| data.value == *value && !data.disabled.get())
+    };
+
+    focused
+      .get()
+      .filter(is_enabled)
+      .or_else(|| value.with(|value| value.iter().find(|value| is_enabled(value)).cloned()))
+      .or_else(|| {
+        items
+          .iter()
+          .find(|(_, data)| !data.disabled.get())
+          .map(|(_, data)| data.value.clone())
+      })
+  });
+
+  let positions = Memo::new(move |_| {
+    let items = get_items.get();
+
+    items
+      .iter()
+      .map(|(_, data)| {
+        let siblings = items
+          .iter()
+          .filter(|(_, sibling)| sibling.parent == data.parent)
+          .collect::<Vec<_>>();
+        let position = siblings
+          .iter()
+          .position(|(_, sibling)| sibling.value == data.value)
+          .unwrap_or(0);
+
+        (data.value.clone(), (position + 1, siblings.len()))
+      })
+      .collect::<HashMap<_, _>>()
+  });
+
+  let on_item_select = Callback::new(move |item_value: String| {
+    if !multiple {
+      set_value.call(vec![item_value]);
+      return;
+    }
+
+    let mut values = value.get_untracked();
+
+    if let Some(index) = values.iter().position(|value| *value == item_value) {
+      values.remove(index);
+    } else {
+      values.push(item_value);
+    }
+
+    set_value.call(values);
+  });
+
+  let focus_item = move |(item_ref, _): &(NodeRef<AnyElement>, TreeItemData)| {
+    if let Some(node) = item_ref.get_untracked() {
+      _ = node.focus();
+    }
+  };
+
+  let search = StoredValue::new(String::new());
+  let search_timer = StoredValue::<Option<TimeoutHandle>>::new(None);
+
+  let handle_typeahead_search = move |key: String| {
+    let search_value = format!("{}{key}", search.get_value());
+    search.set_value(search_value.clone());
+
+    if let Some(handle) = search_timer.get_value() {
+      handle.clear();
+    }
+
+    if let Ok(handle) = set_timeout_with_handle(
+      move || _ = search.try_set_value(String::new()),
+      Duration::from_millis(1000),
+    ) {
+      search_timer.set_value(Some(handle));
+    }
+
+    let items = get_enabled_items();
+    let text_values = items
+      .iter()
+      .map(|(node, data)| get_item_text_value(*node, data))
+      .collect::<Vec<_>>();
+
+    let current_value = focused.get_untracked();
+    let current_index = items
+      .iter()
+      .position(|(_, data)| Some(&data.value) == current_value.as_ref());
+
+    if let Some(next_index) = get_next_match(&text_values, &search_value, current_index) {
+      focus_item(&items[next_index]);
+    }
+  };
+
+  provide_context(TreeContextValue {
+    value,
+    on_item_select,
+    expanded,
+    on_expanded_change,
+    focused,
+    tab_stop,
+    positions,
+    disabled,
+  });
+
+  provide_context(TreeLevelContextValue {
+    level: 1,
+    parent: None,
+  });
+
+  let mut merged_attrs = vec![
+    ("role", "tree".into_attribute()),
+    (
+      "aria-multiselectable",
+      multiple.then_some("true").into_attribute(),
+    ),
+    (
+      "aria-disabled",
+      (move || disabled.get().then_some("true")).into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <Primitive
+      element=html::div
+      on:keydown=move |ev: KeyboardEvent| {
+        on_key_down.call(ev.clone());
+
+        if ev.default_prevented() || disabled.get_untracked() {
+          return;
+        }
+
+        let items = get_enabled_items();
+        let Some(current_index) = focused.get_untracked().and_then(|focused| {
+          items.iter().position(|(_, data)| data.value == focused)
+        }) else {
+          return;
+        };
+
+        let key = ev.key();
+        let is_modifier_key = ev.ctrl_key() || ev.meta_key();
+        let current = &items[current_index].1;
+        let is_expanded = expanded.with_untracked(|expanded| expanded.contains(&current.value));
+
+        let next_index = match key.as_str() {
+          "ArrowDown" => Some((current_index + 1).min(items.len() - 1)),
+          "ArrowUp" => Some(current_index.saturating_sub(1)),
+          "Home" => Some(0),
+          "End" => Some(items.len() - 1),
+          "ArrowRight" if current.is_branch && is_expanded => items
+            .iter()
+            .position(|(_, data)| data.parent.as_ref() == Some(&current.value)),
+          "ArrowLeft" if !(current.is_branch && is_expanded) => current
+            .parent
+            .as_ref()
+            .and_then(|parent| items.iter().position(|(_, data)| data.value == *parent)),
+          _ => None,
+        };
+
+        if let Some(next_index) = next_index {
+          ev.prevent_default();
+          focus_item(&items[next_index]);
+          return;
+        }
+
+        match key.as_str() {
+          "ArrowRight" if current.is_branch => {
+            ev.prevent_default();
+            on_expanded_change.call((current.value.clone(), true));
+          }
+          "ArrowLeft" if current.is_branch => {
+            ev.prevent_default();
+            on_expanded_change.call((current.value.clone(), false));
+          }
+          "ArrowRight" | "ArrowLeft" => {}
+          " " | "Enter" => {
+            ev.prevent_default();
+            on_item_select.call(current.value.clone());
+          }
+          "*" => {
+            ev.prevent_default();
+
+            for (_, data) in items.iter().filter(|(_, data)| data.is_branch && data.parent == current.parent) {
+              on_expanded_change.call((data.value.clone(), true));
+            }
+          }
+          "a" | "A" if multiple && is_modifier_key => {
+            ev.prevent_default();
+
+            let enabled_values = items
+              .iter()
+              .map(|(_, data)| data.value.clone())
+              .collect::<Vec<_>>();
+            let is_all_selected = value.with_untracked(|value| {
+              enabled_values.iter().all(|enabled| value.contains(enabled))
+            });
+
+            set_value.call(if is_all_selected { vec![] } else { enabled_values });
+          }
+          key if key.chars().count() == 1 && !is_modifier_key && !ev.alt_key() => {
+            handle_typeahead_search(key.to_string());
+          }
+          _ => {}
+        }
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn TreeItem(
+  #[prop(into)] value: String,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  view! {
+    <TreeItemImpl
+      value=value
+      text_value=text_value
+      disabled=disabled
+      on_click=on_click
+      on_focus=on_focus
+      node_ref=node_ref
+      attrs=attrs
+      as_child=as_child
+    >
+      {children()}
+    </TreeItemImpl>
+  }
+}
+
+#[component]
+pub fn TreeBranch(
+  #[prop(into)] value: String,
+  #[prop(optional, into)] text_value: MaybeProp<String>,
+  #[prop(optional, into)] disabled: MaybeSignal<bool>,
+  #[prop(optional, into)] loader: Option<TreeLoader>,
+
+  #[prop(default=(|_|{}).into(), into)] on_click: Callback<MouseEvent>,
+  #[prop(default=(|_|{}).into(), into)] on_focus: Callback<FocusEvent>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let TreeContextValue {
+    expanded,
+    on_expanded_change,
+    ..
+  } = use_context().expect("TreeBranch must be used in a TreeRoot component");
+
+  let value = StoredValue::new(value);
+  let is_expanded = Signal::derive(move || {
+    value.with_value(|value| expanded.with(|expanded| expanded.contains(value)))
+  });
+  let load_state = RwSignal::new(if loader.is_some() {
+    TreeLoadState::Idle
+  } else {
+    TreeLoadState::Loaded
+  });
+
+  Effect::new(move |_| {
+    if !is_expanded.get() || load_state.get_untracked() != TreeLoadState::Idle {
+      return;
+    }
+
+    let Some(loader) = loader.clone() else {
+      return;
+    };
+
+    load_state.set(TreeLoadState::Loading);
+
+    spawn_local(async move {
+      let is_loaded = (loader.0)(value.get_value()).await;
+
+      _ = load_state.try_set(if is_loaded {
+        TreeLoadState::Loaded
+      } else {
+        TreeLoadState::Idle
+      });
+    });
+  });
+
+  provide_context(TreeBranchContextValue {
+    value,
+    is_expanded,
+    load_state,
+  });
+
+  let mut merged_attrs = vec![
+    (
+      "aria-expanded",
+      (move || is_expanded.get()).into_attribute(),
+    ),
+    (
+      "aria-busy",
+      (move || (load_state.get() == TreeLoadState::Loading).then_some("true")).into_attribute(),
+    ),
+    (
+      "data-expanded",
+      (move || is_expanded.get().then_some("")).into_attribute(),
+    ),
+    (
+      "data-loading",
+      (move || (load_state.get() == TreeLoadState::Loading).then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  view! {
+    <TreeItemImpl
+      value=value.get_value()
+      text_value=text_value
+      disabled=disabled
+      is_branch=true
+      on_click=Callback::new(move |ev: MouseEvent| {
+        on_click.call(ev.clone());
+
+        if !ev.default_prevented() {
+          on_expanded_change.call((value.get_value(), !is_expanded.get_untracked()));
+        }
+      })
+      on_focus=on_focus
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </TreeItemImpl>
+  }
+}
+
+#[component]
+fn TreeItemImpl(
+  value: String,
+  text_value: MaybeProp<String>,
+  disabled: MaybeSignal<bool>,
+  #[prop(optional)] is_branch: bool,
+
+  on_click: Callback<MouseEvent>,
+  on_focus: Callback<FocusEvent>,
+
+  node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let TreeContextValue {
+    value: selected_values,
+    on_item_select,
+    focused,
+    tab_stop,
+    positions,
+    disabled: root_disabled,
+    ..
+  } = use_context().expect("TreeItem must be used in a TreeRoot component");
+  let TreeLevelContextValue { level, parent } =
+    use_context().expect("TreeItem must be used in a TreeRoot component");
+
+  let disabled = Signal::derive(move || disabled.get() || root_disabled.get());
+  let value = StoredValue::new(value);
+
+  use_collection_item_ref::<AnyElement, TreeItemData>(
+    node_ref,
+    TreeItemData {
+      value: value.get_value(),
+      parent,
+      is_branch,
+      disabled,
+      text_value: text_value.get_untracked(),
+    },
+  );
+
+  let is_selected = Signal::derive(move || {
+    value.with_value(|value| selected_values.with(|values| values.contains(value)))
+  });
+  let position = Signal::derive(move || {
+    value.with_value(|value| positions.with(|positions| positions.get(value).copied()))
+  });
+
+  let mut merged_attrs = vec![
+    ("role", "treeitem".into_attribute()),
+    ("aria-level", level.into_attribute()),
+    (
+      "aria-posinset",
+      (move || position.get().map(|(position, _)| position)).into_attribute(),
+    ),
+    (
+      "aria-setsize",
+      (move || position.get().map(|(_, size)| size)).into_attribute(),
+    ),
+    (
+      "aria-selected",
+      (move || is_selected.get()).into_attribute(),
+    ),
+    (
+      "aria-disabled",
+      (move || disabled.get().then_some("true")).into_attribute(),
+    ),
+    (
+      "tabindex",
+      (move || {
+        if value.with_value(|value| tab_stop.get().as_ref() == Some(value)) {
+          0
+        } else {
+          -1
+        }
+      })
+      .into_attribute(),
+    ),
+    (
+      "data-state",
+      (move || {
+        if is_selected.get() {
+          "checked"
+        } else {
+          "unchecked"
+        }
+      })
+      .into_attribute(),
+    ),
+    (
+      "data-disabled",
+      (move || disabled.get().then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let is_own_event = move |target: Option<web_sys::EventTarget>| {
+    let closest_item = target
+      .and_then(|target| target.dyn_into::<Element>().ok())
+      .and_then(|target| target.closest("[role=treeitem]").ok().flatten());
+
+    match (closest_item, node_ref.get_untracked()) {
+      (Some(closest_item), Some(node)) => {
+        let node: &Node = &node;
+        closest_item.is_same_node(Some(node))
+      }
+      _ => false,
+    }
+  };
+
+  view! {
+    <Primitive
+      element=html::div
+      on:focus=move |ev: FocusEvent| {
+        on_focus.call(ev);
+
+        if !disabled.get_untracked() {
+          focused.set(Some(value.get_value()));
+        }
+      }
+      on:click=move |ev: MouseEvent| {
+        if !is_own_event(ev.target()) {
+          return;
+        }
+
+        on_click.call(ev.clone());
+
+        if ev.default_prevented() || disabled.get_untracked() {
+          return;
+        }
+
+        if let Some(node) = node_ref.get_untracked() {
+          _ = node.focus();
+        }
+
+        on_item_select.call(value.get_value());
+      }
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children()}
+    </Primitive>
+  }
+}
+
+#[component]
+pub fn TreeGroup(
+  #[prop(optional, into)] force_mount: MaybeSignal<bool>,
+
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  children: ChildrenFn,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let TreeBranchContextValue {
+    value,
+    is_expanded,
+    load_state,
+  } = use_context().expect("TreeGroup must be used in a TreeBranch component");
+  let TreeLevelContextValue { level, .. } =
+    use_context().expect("TreeGroup must be used in a TreeRoot component");
+
+  let mut merged_attrs = vec![
+    ("role", "group".into_attribute()),
+    (
+      "aria-busy",
+      (move || (load_state.get() == TreeLoadState::Loading).then_some("true")).into_attribute(),
+    ),
+    ("hidden", (move || !is_expanded.get()).into_attribute()),
+    (
+      "data-state",
+      (move || if is_expanded.get() { "open" } else { "closed" }).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let merged_attrs = StoredValue::new(merged_attrs);
+  let children = StoredValue::new(children);
+
+  view! {
+    <Show when=move || force_mount.get() || is_expanded.get()>
+      <Primitive
+        element=html::div
+        node_ref=node_ref
+        attrs=merged_attrs.get_value()
+        as_child=as_child
+      >
+        {
+          provide_context(TreeLevelContextValue {
+            level: level + 1,
+            parent: Some(value.get_value()),
+          });
+
+          children.with_value(|children| children())
+        }
+      </Primitive>
+    </Show>
+  }
+}
+
+#[component]
+pub fn TreeIndicator(
+  #[prop(optional)] node_ref: NodeRef<AnyElement>,
+  #[prop(attrs)] attrs: Attributes,
+  #[prop(optional)] children: Option<ChildrenFn>,
+
+  #[prop(optional, into)] as_child: MaybeProp<bool>,
+) -> impl IntoView {
+  let TreeBranchContextValue {
+    is_expanded,
+    load_state,
+    ..
+  } = use_context().expect("TreeIndicator must be used in a TreeBranch component");
+
+  let mut merged_attrs = vec![
+    ("aria-hidden", "true".into_attribute()),
+    (
+      "data-state",
+      (move || if is_expanded.get() { "open" } else { "closed" }).into_attribute(),
+    ),
+    (
+      "data-loading",
+      (move || (load_state.get() == TreeLoadState::Loading).then_some("")).into_attribute(),
+    ),
+  ];
+
+  merged_attrs.extend(attrs);
+
+  let children = StoredValue::new(children);
+
+  view! {
+    <Primitive
+      element=html::span
+      node_ref=node_ref
+      attrs=merged_attrs
+      as_child=as_child
+    >
+      {children.with_value(|children| children.as_ref().map(|children| children()))}
+    </Primitive>
+  }
+}
+
+fn get_item_text_value(node: NodeRef<AnyElement>, data: &TreeItemData) -> String {
+  data.text_value.clone().unwrap_or_else(|| {
+    node
+      .get_untracked()
+      .and_then(|node| node.text_content())
+      .unwrap_or_default()
+  })
+}
@@ -1,5 +1,5 @@
 pub mod components;
 
-pub(crate) mod util;
+pub mod util;
 
 pub use components::*;
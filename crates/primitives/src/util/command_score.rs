@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+const SCORE_CONTINUE_MATCH: f64 = 1.0;
+const SCORE_SPACE_WORD_JUMP: f64 = 0.9;
+const SCORE_NON_SPACE_WORD_JUMP: f64 = 0.8;
+const SCORE_CHARACTER_JUMP: f64 = 0.17;
+const SCORE_TRANSPOSITION: f64 = 0.1;
+const PENALTY_SKIPPED: f64 = 0.999;
+const PENALTY_CASE_MISMATCH: f64 = 0.9999;
+const PENALTY_NOT_COMPLETE: f64 = 0.99;
+
+pub fn command_score(value: &str, search: &str, keywords: &[String]) -> f64 {
+  let value = if keywords.is_empty() {
+    value.to_string()
+  } else {
+    format!("{value} {}", keywords.join(" "))
+  };
+
+  let string = value.chars().collect::<Vec<_>>();
+  let abbreviation = search.chars().collect::<Vec<_>>();
+
+  CommandScorer {
+    lower_string: format_input(&string),
+    lower_abbreviation: format_input(&abbreviation),
+    string,
+    abbreviation,
+    memo: HashMap::new(),
+  }
+  .score(0, 0)
+}
+
+struct CommandScorer {
+  string: Vec<char>,
+  abbreviation: Vec<char>,
+  lower_string: Vec<char>,
+  lower_abbreviation: Vec<char>,
+  memo: HashMap<(usize, usize), f64>,
+}
+
+impl CommandScorer {
+  fn score(&mut self, string_index: usize, abbreviation_index: usize) -> f64 {
+    if abbreviation_index == self.abbreviation.len() {
+      return if string_index == self.string.len() {
+        SCORE_CONTINUE_MATCH
+      } else {
+        PENALTY_NOT_COMPLETE
+      };
+    }
+
+    if let Some(score) = self.memo.get(&(string_index, abbreviation_index)) {
+      return *score;
+    }
+
+    let abbreviation_char = self.lower_abbreviation[abbreviation_index];
+    let next_abbreviation_char = self.lower_abbreviation.get(abbreviation_index + 1).copied();
+    let mut high_score = 0.0;
+    let mut index = self.find(abbreviation_char, string_index);
+
+    while let Some(current) = index {
+      let previous_char = current.checked_sub(1).map(|previous| self.string[previous]);
+      let previous_lower_char = current
+        .checked_sub(1)
+        .map(|previous| self.lower_string[previous]);
+
+      let mut score = self.score(current + 1, abbreviation_index + 1);
+
+      if score > high_score {
+        if current == string_index {
+          score *= SCORE_CONTINUE_MATCH;
+        } else if previous_char.is_some_and(is_gap) {
+          score *= SCORE_NON_SPACE_WORD_JUMP;
+
+          let word_breaks = self.count_between(string_index, current, is_gap);
+          if word_breaks > 0 && string_index > 0 {
+            score *= PENALTY_SKIPPED.powi(word_breaks);
+          }
+        } else if previous_char.is_some_and(is_space) {
+          score *= SCORE_SPACE_WORD_JUMP;
+
+          let space_breaks = self.count_between(string_index, current, is_space);
+          if space_breaks > 0 && string_index > 0 {
+            score *= PENALTY_SKIPPED.powi(space_breaks);
+          }
+        } else {
+          score *= SCORE_CHARACTER_JUMP;
+
+          if string_index > 0 {
+            score *= PENALTY_SKIPPED.powi((current - string_index) as i32);
+          }
+        }
+
+        if self.string[current] != self.abbreviation[abbreviation_index] {
+          score *= PENALTY_CASE_MISMATCH;
+        }
+      }
+
+      let is_transposed = (score < SCORE_TRANSPOSITION
+        && previous_lower_char.is_some()
+        && previous_lower_char == next_abbreviation_char)
+        || (next_abbreviation_char == Some(abbreviation_char)
+          && previous_lower_char != Some(abbreviation_char));
+
+      if is_transposed {
+        let transposed_score = self.score(current + 1, abbreviation_index + 2);
+
+        if transposed_score * SCORE_TRANSPOSITION > score {
+          score = transposed_score * SCORE_TRANSPOSITION;
+        }
+      }
+
+      if score > high_score {
+        high_score = score;
+      }
+
+      index = self.find(abbreviation_char, current + 1);
+    }
+
+    self
+      .memo
+      .insert((string_index, abbreviation_index), high_score);
+
+    high_score
+  }
+
+  fn find(&self, char: char, from: usize) -> Option<usize> {
+    self
+      .lower_string
+      .get(from..)?
+      .iter()
+      .position(|current| *current == char)
+      .map(|position| position + from)
+  }
+
+  fn count_between(&self, start: usize, end: usize, predicate: fn(char) -> bool) -> i32 {
+    self
+      .string
+      .get(start..end.saturating_sub(1))
+      .map(|chars| chars.iter().filter(|char| predicate(**char)).count() as i32)
+      .unwrap_or(0)
+  }
+}
+
+fn is_gap(char: char) -> bool {
+  matches!(
+    char,
+    '\\' | '/' | '_' | '+' | '.' | '#' | '"' | '@' | '[' | '(' | '{' | '&'
+  )
+}
+
+fn is_space(char: char) -> bool {
+  char.is_whitespace() || char == '-'
+}
+
+fn format_input(chars: &[char]) -> Vec<char> {
+  chars
+    .iter()
+    .map(|char| char.to_lowercase().next().unwrap_or(*char))
+    .map(|char| if is_space(char) { ' ' } else { char })
+    .collect()
+}
+
+#[test]
+fn command_score_ranks_contiguous_and_word_start_matches_higher() {
+  let exact = command_score("settings", "settings", &[]);
+  let prefix = command_score("Settings", "set", &[]);
+  let word_start = command_score("Open Settings", "set", &[]);
+  let scattered = command_score("Reset theme", "set", &[]);
+
+  assert_eq!(exact, 1.0);
+  assert!(prefix > word_start);
+  assert!(word_start > scattered);
+  assert!(scattered > 0.0);
+
+  assert_eq!(command_score("Settings", "xyz", &[]), 0.0);
+  assert!(command_score("Preferences", "settings", &["settings".into()]) > 0.0);
+}
@@ -1,7 +0,0 @@
-use leptos::*;
-
-pub fn create_id() -> Signal<String> {
-  let (id, _) = create_signal(nanoid::nanoid!());
-
-  id.into()
-}
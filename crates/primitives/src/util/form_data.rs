@@ -0,0 +1,128 @@
+use std::{error::Error, fmt, str::FromStr};
+
+const MULTIPLE_VALUES_SUFFIX: &str = "[]";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormDataError {
+  Missing { name: String },
+  Invalid { name: String, value: String },
+}
+
+impl fmt::Display for FormDataError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Missing { name } => write!(f, "missing form value `{name}`"),
+      Self::Invalid { name, value } => write!(f, "invalid form value `{value}` for `{name}`"),
+    }
+  }
+}
+
+impl Error for FormDataError {}
+
+pub trait FromFormData: Sized {
+  fn from_form_values(name: &str, values: &[String]) -> Result<Self, FormDataError>;
+}
+
+pub fn from_form_data<T, K, V>(
+  name: &str,
+  entries: impl IntoIterator<Item = (K, V)>,
+) -> Result<T, FormDataError>
+where
+  T: FromFormData,
+  K: AsRef<str>,
+  V: AsRef<str>,
+{
+  let multiple_name = get_form_field_name(name, true);
+
+  let values = entries
+    .into_iter()
+    .filter(|(key, _)| key.as_ref() == name || key.as_ref() == multiple_name)
+    .map(|(_, value)| value.as_ref().to_string())
+    .collect::<Vec<_>>();
+
+  T::from_form_values(name, &values)
+}
+
+pub(crate) fn get_form_field_name(name: &str, multiple: bool) -> String {
+  if multiple {
+    format!("{name}{MULTIPLE_VALUES_SUFFIX}")
+  } else {
+    name.to_string()
+  }
+}
+
+fn parse_form_value<T: FromStr>(name: &str, value: &str) -> Result<T, FormDataError> {
+  value.parse().map_err(|_| FormDataError::Invalid {
+    name: name.to_string(),
+    value: value.to_string(),
+  })
+}
+
+impl FromFormData for bool {
+  fn from_form_values(_: &str, values: &[String]) -> Result<Self, FormDataError> {
+    Ok(!values.is_empty())
+  }
+}
+
+impl FromFormData for String {
+  fn from_form_values(name: &str, values: &[String]) -> Result<Self, FormDataError> {
+    values
+      .first()
+      .cloned()
+      .ok_or_else(|| FormDataError::Missing {
+        name: name.to_string(),
+      })
+  }
+}
+
+impl FromFormData for f64 {
+  fn from_form_values(name: &str, values: &[String]) -> Result<Self, FormDataError> {
+    parse_form_value(name, &String::from_form_values(name, values)?)
+  }
+}
+
+impl FromFormData for Vec<String> {
+  fn from_form_values(_: &str, values: &[String]) -> Result<Self, FormDataError> {
+    Ok(values.to_vec())
+  }
+}
+
+impl FromFormData for Vec<f64> {
+  fn from_form_values(name: &str, values: &[String]) -> Result<Self, FormDataError> {
+    values
+      .iter()
+      .map(|value| parse_form_value(name, value))
+      .collect()
+  }
+}
+
+impl<T: FromFormData> FromFormData for Option<T> {
+  fn from_form_values(name: &str, values: &[String]) -> Result<Self, FormDataError> {
+    if values.is_empty() {
+      Ok(None)
+    } else {
+      T::from_form_values(name, values).map(Some)
+    }
+  }
+}
+
+#[test]
+fn multiple_values_are_collected_from_suffixed_names() {
+  let entries = [("volume[]", "10"), ("volume[]", "42.5"), ("other", "1")];
+
+  assert_eq!(
+    from_form_data::<Vec<f64>, _, _>("volume", entries),
+    Ok(vec![10.0, 42.5])
+  );
+  assert_eq!(
+    from_form_data::<Option<String>, _, _>("missing", entries),
+    Ok(None)
+  );
+  assert_eq!(
+    from_form_data::<f64, _, _>("other", [("other", "x")]),
+    Err(FormDataError::Invalid {
+      name: "other".into(),
+      value: "x".into()
+    })
+  );
+}
@@ -0,0 +1,160 @@
+use std::cell::RefCell;
+
+use leptos::{html::AnyElement, *};
+use web_sys::Element;
+
+struct HiddenElement {
+  element: Element,
+  mode: HideOthersMode,
+  count: usize,
+  original_aria_hidden: Option<String>,
+  original_inert: bool,
+}
+
+thread_local! {
+  static HIDDEN_ELEMENTS: RefCell<Vec<HiddenElement>> = const { RefCell::new(vec![]) };
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum HideOthersMode {
+  #[default]
+  AriaHidden,
+  Inert,
+}
+
+pub fn create_hide_others(
+  node_ref: NodeRef<AnyElement>,
+  enabled: Signal<bool>,
+  mode: HideOthersMode,
+) {
+  let hidden = StoredValue::<Vec<Element>>::new(vec![]);
+
+  let restore = move || {
+    if let Some(elements) = hidden.try_get_value() {
+      show_elements(&elements, mode);
+      hidden.set_value(vec![]);
+    }
+  };
+
+  Effect::new(move |_| {
+    let enabled = enabled.get();
+    let node = node_ref.get();
+
+    restore();
+
+    if let (true, Some(node)) = (enabled, node) {
+      hidden.set_value(hide_others(&node, mode));
+    }
+  });
+
+  on_cleanup(restore);
+}
+
+pub fn hide_others(target: &Element, mode: HideOthersMode) -> Vec<Element> {
+  let mut elements = vec![];
+  let mut current = target.clone();
+
+  while let Some(parent) = current.parent_element() {
+    let children = parent.children();
+
+    for index in 0..children.length() {
+      let Some(sibling) = children.item(index) else {
+        continue;
+      };
+
+      if sibling.is_same_node(Some(&current)) || should_keep_visible(&sibling) {
+        continue;
+      }
+
+      elements.push(sibling);
+    }
+
+    if parent.tag_name() == "BODY" {
+      break;
+    }
+
+    current = parent;
+  }
+
+  hide_elements(&elements, mode);
+
+  elements
+}
+
+pub fn show_elements(elements: &[Element], mode: HideOthersMode) {
+  HIDDEN_ELEMENTS.with(|hidden_elements| {
+    let mut hidden_elements = hidden_elements.borrow_mut();
+
+    for element in elements {
+      let Some(index) = hidden_elements
+        .iter()
+        .position(|hidden| hidden.mode == mode && hidden.element.is_same_node(Some(element)))
+      else {
+        continue;
+      };
+
+      hidden_elements[index].count -= 1;
+
+      if hidden_elements[index].count > 0 {
+        continue;
+      }
+
+      let hidden = hidden_elements.remove(index);
+
+      match mode {
+        HideOthersMode::AriaHidden => match hidden.original_aria_hidden {
+          Some(aria_hidden) => _ = element.set_attribute("aria-hidden", &aria_hidden),
+          None => _ = element.remove_attribute("aria-hidden"),
+        },
+        HideOthersMode::Inert => {
+          if !hidden.original_inert {
+            _ = element.remove_attribute("inert");
+          }
+        }
+      }
+    }
+  });
+}
+
+fn hide_elements(elements: &[Element], mode: HideOthersMode) {
+  HIDDEN_ELEMENTS.with(|hidden_elements| {
+    let mut hidden_elements = hidden_elements.borrow_mut();
+
+    for element in elements {
+      if let Some(hidden) = hidden_elements
+        .iter_mut()
+        .find(|hidden| hidden.mode == mode && hidden.element.is_same_node(Some(element)))
+      {
+        hidden.count += 1;
+        continue;
+      }
+
+      hidden_elements.push(HiddenElement {
+        element: element.clone(),
+        mode,
+        count: 1,
+        original_aria_hidden: element.get_attribute("aria-hidden"),
+        original_inert: element.has_attribute("inert"),
+      });
+
+      match mode {
+        HideOthersMode::AriaHidden => _ = element.set_attribute("aria-hidden", "true"),
+        HideOthersMode::Inert => _ = element.set_attribute("inert", ""),
+      }
+    }
+  });
+}
+
+fn should_keep_visible(element: &Element) -> bool {
+  matches!(
+    element.tag_name().as_str(),
+    "SCRIPT" | "STYLE" | "LINK" | "TEMPLATE"
+  ) || element
+    .get_attribute("aria-live")
+    .is_some_and(|aria_live| aria_live != "off")
+    || element
+      .query_selector("[aria-live]:not([aria-live=\"off\"])")
+      .ok()
+      .flatten()
+      .is_some()
+}
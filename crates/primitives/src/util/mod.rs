@@ -1,7 +1,12 @@
+pub mod command_score;
 pub(crate) mod create_controllable_signal;
-pub(crate) mod create_id;
 pub(crate) mod create_previous;
 pub(crate) mod create_state_machine;
+pub mod form_data;
+pub mod hide_others;
+pub(crate) mod polygon;
+pub mod scroll_lock;
+pub mod use_id;
 
 pub(crate) fn linear_scale(
   (input_start, input_end): (f64, f64),
@@ -0,0 +1,73 @@
+use std::cmp::Ordering;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Point {
+  pub(crate) x: f64,
+  pub(crate) y: f64,
+}
+
+impl Point {
+  pub(crate) fn new(x: f64, y: f64) -> Self {
+    Self { x, y }
+  }
+}
+
+pub(crate) fn is_point_in_polygon(point: Point, polygon: &[Point]) -> bool {
+  let mut inside = false;
+  let mut j = polygon.len().wrapping_sub(1);
+
+  for (i, current) in polygon.iter().enumerate() {
+    let previous = polygon[j];
+
+    if (current.y > point.y) != (previous.y > point.y)
+      && point.x
+        < (previous.x - current.x) * (point.y - current.y) / (previous.y - current.y) + current.x
+    {
+      inside = !inside;
+    }
+
+    j = i;
+  }
+
+  inside
+}
+
+pub(crate) fn get_hull(mut points: Vec<Point>) -> Vec<Point> {
+  points.sort_by(|a, b| {
+    a.x
+      .partial_cmp(&b.x)
+      .unwrap_or(Ordering::Equal)
+      .then(a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal))
+  });
+
+  if points.len() <= 1 {
+    return points;
+  }
+
+  let cross =
+    |o: &Point, a: &Point, b: &Point| (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x);
+
+  let mut lower: Vec<Point> = vec![];
+  for point in points.iter() {
+    while lower.len() >= 2 && cross(&lower[lower.len() - 2], &lower[lower.len() - 1], point) <= 0. {
+      lower.pop();
+    }
+
+    lower.push(*point);
+  }
+
+  let mut upper: Vec<Point> = vec![];
+  for point in points.iter().rev() {
+    while upper.len() >= 2 && cross(&upper[upper.len() - 2], &upper[upper.len() - 1], point) <= 0. {
+      upper.pop();
+    }
+
+    upper.push(*point);
+  }
+
+  lower.pop();
+  upper.pop();
+  lower.extend(upper);
+
+  lower
+}
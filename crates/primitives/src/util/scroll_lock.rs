@@ -0,0 +1,125 @@
+use std::cell::RefCell;
+
+use leptos::*;
+
+#[derive(Default)]
+struct ScrollLockState {
+  count: usize,
+  original_overflow: String,
+  original_padding_right: String,
+}
+
+thread_local! {
+  static SCROLL_LOCK: RefCell<ScrollLockState> = RefCell::new(ScrollLockState::default());
+}
+
+pub fn create_scroll_lock(locked: Signal<bool>) {
+  let is_locked = StoredValue::new(false);
+
+  Effect::new(move |_| {
+    let locked = locked.get();
+
+    if locked && !is_locked.get_value() {
+      lock_scroll();
+      is_locked.set_value(true);
+    } else if !locked && is_locked.get_value() {
+      unlock_scroll();
+      is_locked.set_value(false);
+    }
+  });
+
+  on_cleanup(move || {
+    if is_locked.try_get_value() == Some(true) {
+      unlock_scroll();
+    }
+  });
+}
+
+pub fn lock_scroll() {
+  SCROLL_LOCK.with(|state| {
+    let mut state = state.borrow_mut();
+    state.count += 1;
+
+    if state.count > 1 {
+      return;
+    }
+
+    let Some(body) = document().body() else {
+      return;
+    };
+
+    let style = body.style();
+
+    state.original_overflow = style.get_property_value("overflow").unwrap_or_default();
+    state.original_padding_right = style
+      .get_property_value("padding-right")
+      .unwrap_or_default();
+
+    let scrollbar_width = get_scrollbar_width();
+
+    if scrollbar_width > 0. {
+      let padding_right = window()
+        .get_computed_style(&body)
+        .ok()
+        .flatten()
+        .and_then(|styles| styles.get_property_value("padding-right").ok())
+        .map(|padding_right| parse_px(&padding_right))
+        .unwrap_or_default();
+
+      _ = style.set_property(
+        "padding-right",
+        &format!("{}px", padding_right + scrollbar_width),
+      );
+    }
+
+    _ = style.set_property("overflow", "hidden");
+    _ = body.set_attribute("data-leptix-scroll-locked", "");
+  });
+}
+
+pub fn unlock_scroll() {
+  SCROLL_LOCK.with(|state| {
+    let mut state = state.borrow_mut();
+    state.count = state.count.saturating_sub(1);
+
+    if state.count > 0 {
+      return;
+    }
+
+    let Some(body) = document().body() else {
+      return;
+    };
+
+    let style = body.style();
+
+    restore_property(&style, "overflow", &state.original_overflow);
+    restore_property(&style, "padding-right", &state.original_padding_right);
+    _ = body.remove_attribute("data-leptix-scroll-locked");
+  });
+}
+
+fn get_scrollbar_width() -> f64 {
+  let inner_width = window()
+    .inner_width()
+    .ok()
+    .and_then(|width| width.as_f64())
+    .unwrap_or_default();
+  let client_width = document()
+    .document_element()
+    .map(|root| root.client_width() as f64)
+    .unwrap_or(inner_width);
+
+  (inner_width - client_width).max(0.)
+}
+
+fn restore_property(style: &web_sys::CssStyleDeclaration, property: &str, value: &str) {
+  if value.is_empty() {
+    _ = style.remove_property(property);
+  } else {
+    _ = style.set_property(property, value);
+  }
+}
+
+fn parse_px(value: &str) -> f64 {
+  value.trim_end_matches("px").parse().unwrap_or_default()
+}
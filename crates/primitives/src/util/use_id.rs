@@ -0,0 +1,148 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use leptos::{leptos_dom::HydrationCtx, *};
+
+const DEFAULT_ID_PREFIX: &str = "leptix";
+
+#[derive(Clone)]
+pub(crate) struct IdContextValue {
+  pub(crate) prefix: String,
+}
+
+#[derive(Default)]
+struct IdCounter {
+  runtime: Option<RuntimeId>,
+  indices: HashMap<String, IdIndex>,
+}
+
+#[derive(Default)]
+struct IdIndex {
+  next: usize,
+  live: usize,
+}
+
+thread_local! {
+  static ID_COUNTER: RefCell<IdCounter> = RefCell::new(IdCounter::default());
+}
+
+pub fn use_id() -> String {
+  let prefix = use_context::<IdContextValue>()
+    .map(|context| context.prefix)
+    .unwrap_or_else(|| DEFAULT_ID_PREFIX.to_string());
+
+  let runtime = current_runtime();
+  let key = HydrationCtx::peek_always().to_string();
+
+  let index = ID_COUNTER.with(|counter| {
+    let mut counter = counter.borrow_mut();
+
+    if counter.runtime != Some(runtime) {
+      *counter = IdCounter {
+        runtime: Some(runtime),
+        indices: HashMap::new(),
+      };
+    }
+
+    next_index(&mut counter.indices, &key)
+  });
+
+  // drop the key once every id generated for it is gone, so long-lived runtimes don't grow the map
+  on_cleanup({
+    let key = key.clone();
+
+    move || {
+      ID_COUNTER.with(|counter| {
+        let mut counter = counter.borrow_mut();
+
+        if counter.runtime == Some(runtime) {
+          release_index(&mut counter.indices, &key);
+        }
+      });
+    }
+  });
+
+  format_id(&prefix, &key, index)
+}
+
+pub(crate) fn create_id(id: MaybeProp<String>) -> Signal<String> {
+  let generated_id = use_id();
+
+  Signal::derive(move || id.get().unwrap_or_else(|| generated_id.clone()))
+}
+
+pub(crate) fn create_part_id(base_id: Signal<String>, part: &'static str) -> Signal<String> {
+  Signal::derive(move || format!("{}-{part}", base_id.get()))
+}
+
+fn next_index(indices: &mut HashMap<String, IdIndex>, key: &str) -> usize {
+  let index = indices.entry(key.to_string()).or_default();
+  index.next += 1;
+  index.live += 1;
+
+  index.next - 1
+}
+
+fn release_index(indices: &mut HashMap<String, IdIndex>, key: &str) {
+  let Some(index) = indices.get_mut(key) else {
+    return;
+  };
+
+  index.live -= 1;
+
+  if index.live == 0 {
+    indices.remove(key);
+  }
+}
+
+fn format_id(prefix: &str, key: &str, index: usize) -> String {
+  format!("{prefix}-{key}-{index}")
+}
+
+#[test]
+fn ids_are_prefixed_and_indexed_by_hydration_key() {
+  assert_eq!(format_id("leptix", "0-0-0-4", 0), "leptix-0-0-0-4-0");
+  assert_eq!(format_id("app", "0-1-0-2", 3), "app-0-1-0-2-3");
+}
+
+#[test]
+fn interleaved_hydration_keys_never_reuse_an_index() {
+  let mut indices = HashMap::new();
+
+  assert_eq!(next_index(&mut indices, "0-0-0-4"), 0);
+  assert_eq!(next_index(&mut indices, "0-0-0-7"), 0);
+  assert_eq!(next_index(&mut indices, "0-0-0-4"), 1);
+  assert_eq!(next_index(&mut indices, "0-0-0-7"), 1);
+}
+
+#[test]
+fn released_hydration_keys_are_removed_once_unused() {
+  let mut indices = HashMap::new();
+
+  assert_eq!(next_index(&mut indices, "0-0-0-4"), 0);
+  assert_eq!(next_index(&mut indices, "0-0-0-4"), 1);
+
+  release_index(&mut indices, "0-0-0-4");
+  assert!(indices.contains_key("0-0-0-4"));
+
+  release_index(&mut indices, "0-0-0-4");
+  assert!(indices.is_empty());
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn counter_resets_for_a_new_runtime() {
+  let runtime = create_runtime();
+  let key = HydrationCtx::peek_always().to_string();
+
+  assert_eq!(use_id(), format_id(DEFAULT_ID_PREFIX, &key, 0));
+  assert_eq!(use_id(), format_id(DEFAULT_ID_PREFIX, &key, 1));
+
+  runtime.dispose();
+
+  let runtime = create_runtime();
+  let key = HydrationCtx::peek_always().to_string();
+
+  assert_eq!(use_id(), format_id(DEFAULT_ID_PREFIX, &key, 0));
+
+  runtime.dispose();
+}